@@ -70,6 +70,13 @@ impl<'a> String<'a> {
     }
 }
 
+/// 以字符切片的形式使用字符串，便于作为字典树等容器的键
+impl AsRef<[char]> for String<'_> {
+    fn as_ref(&self) -> &[char] {
+        &self.ch[..self.len]
+    }
+}
+
 fn next<T>(pattern: &[T]) -> Vec<usize>
 where
     T: PartialEq + Eq,
@@ -0,0 +1,638 @@
+//! # B+ 树模块
+//!
+//! 最小度数为 `t` 的 B+ 树：
+//! - 所有键值对都存放在叶子中，内部节点只保存用于导航的分隔键
+//! - 叶子按键的顺序用 `next` 链接成单链表，范围扫描只需定位起点后沿链表前进
+//! - 除根以外的节点至少有 `t - 1` 个键，所有节点至多有 `2t - 1` 个键
+//!
+//! 节点存放在以页号为下标的数组中，子节点与叶子链接都用页号表示，
+//! 和磁盘索引中用页号互相引用的方式一致；删除节点后页号会被回收复用。
+//!
+//! ## 示例
+//! ```
+//! use data_structure::non_linear::bplus_tree::BPlusTree;
+//!
+//! let mut tree = BPlusTree::new(3);
+//! for i in 0..20 {
+//!     tree.insert(i, i * i);
+//! }
+//! assert_eq!(tree.get(&7), Some(&49));
+//! assert_eq!(tree.range(15..18).map(|(_, v)| *v).collect::<Vec<_>>(), vec![225, 256, 289]);
+//! assert_eq!(tree.remove(&7), Some(49));
+//! assert!(tree.check().is_ok());
+//! ```
+
+use std::mem;
+use std::ops::{Bound, RangeBounds};
+
+/// B+ 树节点
+#[derive(Clone, Debug)]
+enum Node<K, V> {
+    /// 内部节点：`children[i]` 中的键都在 `[keys[i - 1], keys[i])` 内
+    Internal { keys: Vec<K>, children: Vec<usize> },
+    /// 叶子节点：`next` 为右边相邻叶子的页号
+    Leaf {
+        keys: Vec<K>,
+        values: Vec<V>,
+        next: Option<usize>,
+    },
+}
+
+impl<K, V> Node<K, V> {
+    fn keys(&self) -> &Vec<K> {
+        match self {
+            Node::Internal { keys, .. } | Node::Leaf { keys, .. } => keys,
+        }
+    }
+}
+
+/// B+ 树有序映射
+#[derive(Clone, Debug)]
+pub struct BPlusTree<K, V> {
+    /// 页表，`None` 表示空闲页
+    nodes: Vec<Option<Node<K, V>>>,
+    /// 空闲页号
+    free: Vec<usize>,
+    root: usize,
+    min_degree: usize,
+    len: usize,
+}
+
+impl<K, V> BPlusTree<K, V>
+where
+    K: Ord + Clone,
+{
+    /// 创建空的 B+ 树
+    ///
+    /// # 参数
+    /// * `min_degree` - 最小度数 `t`，至少为 2
+    ///
+    /// # Panics
+    /// `min_degree < 2` 时 panic
+    pub fn new(min_degree: usize) -> Self {
+        assert!(min_degree >= 2, "B+ 树的最小度数至少为 2");
+        let root = Node::Leaf {
+            keys: Vec::new(),
+            values: Vec::new(),
+            next: None,
+        };
+        Self {
+            nodes: vec![Some(root)],
+            free: Vec::new(),
+            root: 0,
+            min_degree,
+            len: 0,
+        }
+    }
+
+    /// 获取键值对的数量
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// 检查是否为空
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// 正在使用的页数
+    pub fn page_count(&self) -> usize {
+        self.nodes.len() - self.free.len()
+    }
+
+    fn node(&self, id: usize) -> &Node<K, V> {
+        self.nodes[id].as_ref().expect("访问了空闲页")
+    }
+
+    fn node_mut(&mut self, id: usize) -> &mut Node<K, V> {
+        self.nodes[id].as_mut().expect("访问了空闲页")
+    }
+
+    /// 分配新页
+    fn alloc(&mut self, node: Node<K, V>) -> usize {
+        match self.free.pop() {
+            Some(id) => {
+                self.nodes[id] = Some(node);
+                id
+            }
+            None => {
+                self.nodes.push(Some(node));
+                self.nodes.len() - 1
+            }
+        }
+    }
+
+    /// 释放页并取出其中的节点
+    fn dealloc(&mut self, id: usize) -> Node<K, V> {
+        self.free.push(id);
+        self.nodes[id].take().expect("重复释放页")
+    }
+
+    /// 从根向下找到可能包含 `key` 的叶子
+    fn find_leaf(&self, key: &K) -> usize {
+        let mut id = self.root;
+        while let Node::Internal { keys, children } = self.node(id) {
+            id = children[keys.partition_point(|k| k <= key)];
+        }
+        id
+    }
+
+    /// 最左边的叶子
+    fn first_leaf(&self) -> usize {
+        let mut id = self.root;
+        while let Node::Internal { children, .. } = self.node(id) {
+            id = children[0];
+        }
+        id
+    }
+
+    /// 查找键对应的值
+    pub fn get(&self, key: &K) -> Option<&V> {
+        let Node::Leaf { keys, values, .. } = self.node(self.find_leaf(key)) else {
+            unreachable!()
+        };
+        keys.binary_search(key).ok().map(|i| &values[i])
+    }
+
+    /// 查找键对应的值的可变引用
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        let leaf = self.find_leaf(key);
+        let Node::Leaf { keys, values, .. } = self.node_mut(leaf) else {
+            unreachable!()
+        };
+        keys.binary_search(key).ok().map(|i| &mut values[i])
+    }
+
+    /// 检查是否包含指定的键
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// 插入键值对
+    ///
+    /// # 返回值
+    /// 键已存在时替换旧值并返回 `Some(旧值)`，否则返回 `None`
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let (old, split) = self.insert_at(self.root, key, value);
+        if let Some((separator, right)) = split {
+            // 根分裂，树长高一层
+            let left = self.root;
+            self.root = self.alloc(Node::Internal {
+                keys: vec![separator],
+                children: vec![left, right],
+            });
+        }
+        if old.is_none() {
+            self.len += 1;
+        }
+        old
+    }
+
+    /// 递归插入，节点溢出时分裂并返回 `(分隔键, 右半节点页号)`
+    fn insert_at(&mut self, id: usize, key: K, value: V) -> (Option<V>, Option<(K, usize)>) {
+        let t = self.min_degree;
+        let split = match self.node_mut(id) {
+            Node::Leaf { keys, values, next } => {
+                match keys.binary_search(&key) {
+                    Ok(i) => return (Some(mem::replace(&mut values[i], value)), None),
+                    Err(i) => {
+                        keys.insert(i, key);
+                        values.insert(i, value);
+                    }
+                }
+                if keys.len() < 2 * t {
+                    return (None, None);
+                }
+                // 叶子分裂成各 t 个键，右半的第一个键复制到父节点
+                let right = Node::Leaf {
+                    keys: keys.split_off(t),
+                    values: values.split_off(t),
+                    next: next.take(),
+                };
+                let separator = right.keys()[0].clone();
+                let right = self.alloc(right);
+                let Node::Leaf { next, .. } = self.node_mut(id) else {
+                    unreachable!()
+                };
+                *next = Some(right);
+                (separator, right)
+            }
+            Node::Internal { keys, children } => {
+                let i = keys.partition_point(|k| *k <= key);
+                let child = children[i];
+                let (old, split) = self.insert_at(child, key, value);
+                let Some((separator, right)) = split else {
+                    return (old, None);
+                };
+                let Node::Internal { keys, children } = self.node_mut(id) else {
+                    unreachable!()
+                };
+                keys.insert(i, separator);
+                children.insert(i + 1, right);
+                if keys.len() < 2 * t {
+                    return (None, None);
+                }
+                // 内部节点分裂：第 t 个键移动到父节点
+                let right_keys = keys.split_off(t + 1);
+                let separator = keys.pop().unwrap();
+                let right_children = children.split_off(t + 1);
+                let right = self.alloc(Node::Internal {
+                    keys: right_keys,
+                    children: right_children,
+                });
+                (separator, right)
+            }
+        };
+        (None, Some(split))
+    }
+
+    /// 删除键值对
+    ///
+    /// # 返回值
+    /// 键存在时返回 `Some(值)`，否则返回 `None`
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let value = self.remove_at(self.root, key)?;
+        if let Node::Internal { keys, children } = self.node(self.root)
+            && keys.is_empty()
+        {
+            // 根只剩一个子节点，树变矮一层
+            let child = children[0];
+            self.dealloc(self.root);
+            self.root = child;
+        }
+        self.len -= 1;
+        Some(value)
+    }
+
+    fn remove_at(&mut self, id: usize, key: &K) -> Option<V> {
+        match self.node_mut(id) {
+            Node::Leaf { keys, values, .. } => {
+                let i = keys.binary_search(key).ok()?;
+                keys.remove(i);
+                Some(values.remove(i))
+            }
+            Node::Internal { keys, children } => {
+                let i = keys.partition_point(|k| k <= key);
+                let child = children[i];
+                let value = self.remove_at(child, key)?;
+                self.fix_child(id, i);
+                Some(value)
+            }
+        }
+    }
+
+    /// 内部节点 `parent` 的第 `i` 个子节点的键不足 `t - 1` 个时，向兄弟借键或与兄弟合并
+    fn fix_child(&mut self, parent: usize, i: usize) {
+        let t = self.min_degree;
+        let Node::Internal { children, .. } = self.node(parent) else {
+            unreachable!()
+        };
+        let children = children.clone();
+        if self.node(children[i]).keys().len() >= t - 1 {
+            return;
+        }
+        let left = i.checked_sub(1).map(|j| children[j]);
+        let right = children.get(i + 1).copied();
+        if left.is_some_and(|left| self.node(left).keys().len() >= t) {
+            self.borrow_from_left(parent, i, children[i - 1], children[i]);
+        } else if right.is_some_and(|right| self.node(right).keys().len() >= t) {
+            self.borrow_from_right(parent, i, children[i], children[i + 1]);
+        } else if i > 0 {
+            self.merge(parent, i - 1, children[i - 1], children[i]);
+        } else {
+            self.merge(parent, i, children[i], children[i + 1]);
+        }
+    }
+
+    /// 同时取出父节点与相邻两个子节点，处理完后放回
+    fn with_nodes(
+        &mut self,
+        ids: [usize; 3],
+        f: impl FnOnce(&mut Vec<K>, &mut Node<K, V>, &mut Node<K, V>),
+    ) {
+        let [parent, left, right] = ids.map(|id| self.nodes[id].take().unwrap());
+        let (mut parent, mut left, mut right) = (parent, left, right);
+        let Node::Internal { keys, .. } = &mut parent else {
+            unreachable!()
+        };
+        f(keys, &mut left, &mut right);
+        for (id, node) in ids.into_iter().zip([parent, left, right]) {
+            self.nodes[id] = Some(node);
+        }
+    }
+
+    fn borrow_from_left(&mut self, parent: usize, i: usize, left: usize, child: usize) {
+        self.with_nodes([parent, left, child], |separators, left, child| {
+            match (left, child) {
+                (
+                    Node::Leaf { keys, values, .. },
+                    Node::Leaf {
+                        keys: child_keys,
+                        values: child_values,
+                        ..
+                    },
+                ) => {
+                    child_keys.insert(0, keys.pop().unwrap());
+                    child_values.insert(0, values.pop().unwrap());
+                    separators[i - 1] = child_keys[0].clone();
+                }
+                (
+                    Node::Internal { keys, children },
+                    Node::Internal {
+                        keys: child_keys,
+                        children: child_children,
+                    },
+                ) => {
+                    let key = mem::replace(&mut separators[i - 1], keys.pop().unwrap());
+                    child_keys.insert(0, key);
+                    child_children.insert(0, children.pop().unwrap());
+                }
+                _ => unreachable!("兄弟节点不在同一层"),
+            }
+        });
+    }
+
+    fn borrow_from_right(&mut self, parent: usize, i: usize, child: usize, right: usize) {
+        self.with_nodes([parent, child, right], |separators, child, right| {
+            match (child, right) {
+                (
+                    Node::Leaf {
+                        keys: child_keys,
+                        values: child_values,
+                        ..
+                    },
+                    Node::Leaf { keys, values, .. },
+                ) => {
+                    child_keys.push(keys.remove(0));
+                    child_values.push(values.remove(0));
+                    separators[i] = keys[0].clone();
+                }
+                (
+                    Node::Internal {
+                        keys: child_keys,
+                        children: child_children,
+                    },
+                    Node::Internal { keys, children },
+                ) => {
+                    let key = mem::replace(&mut separators[i], keys.remove(0));
+                    child_keys.push(key);
+                    child_children.push(children.remove(0));
+                }
+                _ => unreachable!("兄弟节点不在同一层"),
+            }
+        });
+    }
+
+    /// 把父节点的第 `j + 1` 个子节点 `right` 合并进第 `j` 个子节点 `left`
+    fn merge(&mut self, parent: usize, j: usize, left: usize, right: usize) {
+        let right_node = self.dealloc(right);
+        let Node::Internal { keys, children } = self.node_mut(parent) else {
+            unreachable!()
+        };
+        let separator = keys.remove(j);
+        children.remove(j + 1);
+        match (self.node_mut(left), right_node) {
+            (
+                Node::Leaf { keys, values, next },
+                Node::Leaf {
+                    keys: right_keys,
+                    values: right_values,
+                    next: right_next,
+                },
+            ) => {
+                keys.extend(right_keys);
+                values.extend(right_values);
+                *next = right_next;
+            }
+            (
+                Node::Internal { keys, children },
+                Node::Internal {
+                    keys: right_keys,
+                    children: right_children,
+                },
+            ) => {
+                keys.push(separator);
+                keys.extend(right_keys);
+                children.extend(right_children);
+            }
+            _ => unreachable!("兄弟节点不在同一层"),
+        }
+    }
+
+    /// 按键从小到大遍历所有键值对
+    pub fn iter(&self) -> Range<'_, K, V> {
+        Range {
+            tree: self,
+            leaf: Some(self.first_leaf()),
+            index: 0,
+            end: Bound::Unbounded,
+        }
+    }
+
+    /// 按键从小到大遍历落在 `range` 内的键值对
+    ///
+    /// 先从根向下定位起点所在的叶子，之后只沿叶子链表前进。
+    pub fn range<R>(&self, range: R) -> Range<'_, K, V>
+    where
+        R: RangeBounds<K>,
+    {
+        let (leaf, index) = match range.start_bound() {
+            Bound::Unbounded => (self.first_leaf(), 0),
+            Bound::Included(start) | Bound::Excluded(start) => {
+                let leaf = self.find_leaf(start);
+                let keys = self.node(leaf).keys();
+                let index = match range.start_bound() {
+                    Bound::Included(_) => keys.partition_point(|k| k < start),
+                    _ => keys.partition_point(|k| k <= start),
+                };
+                (leaf, index)
+            }
+        };
+        Range {
+            tree: self,
+            leaf: Some(leaf),
+            index,
+            end: range.end_bound().cloned(),
+        }
+    }
+
+    /// 检查 B+ 树的不变量
+    ///
+    /// 依次检查：每个节点的键数量在范围内、内部节点的子节点数量等于键数量加一、
+    /// 分隔键正确划分子树、所有叶子在同一层、叶子链表按序覆盖全部键、键的数量与 `len` 一致。
+    ///
+    /// # 返回值
+    /// 全部满足时返回 `Ok(())`，否则返回描述第一个被破坏的不变量的错误
+    pub fn check(&self) -> Result<(), &'static str> {
+        let mut leaves = Vec::new();
+        self.check_node(self.root, None, None, &mut leaves)?;
+        // 叶子链表必须与从左到右的叶子顺序一致
+        let mut leaf = Some(self.first_leaf());
+        for &expected in &leaves {
+            if leaf != Some(expected) {
+                return Err("叶子链表与叶子顺序不一致");
+            }
+            let Node::Leaf { next, .. } = self.node(expected) else {
+                unreachable!()
+            };
+            leaf = *next;
+        }
+        if leaf.is_some() {
+            return Err("叶子链表没有在最后一个叶子结束");
+        }
+        let mut count = 0;
+        let mut previous: Option<&K> = None;
+        for (key, _) in self.iter() {
+            if previous.is_some_and(|previous| previous >= key) {
+                return Err("键不是严格递增的");
+            }
+            previous = Some(key);
+            count += 1;
+        }
+        if count != self.len {
+            return Err("键的数量与长度不一致");
+        }
+        Ok(())
+    }
+
+    /// 递归检查节点，键必须落在 `[lower, upper)` 内，返回子树高度
+    fn check_node(
+        &self,
+        id: usize,
+        lower: Option<&K>,
+        upper: Option<&K>,
+        leaves: &mut Vec<usize>,
+    ) -> Result<usize, &'static str> {
+        let t = self.min_degree;
+        let node = self.node(id);
+        let keys = node.keys();
+        if keys.len() > 2 * t - 1 || (id != self.root && keys.len() < t - 1) {
+            return Err("节点的键数量超出范围");
+        }
+        if keys.windows(2).any(|pair| pair[0] >= pair[1]) {
+            return Err("节点内的键不是严格递增的");
+        }
+        let in_bounds = |key: &K| {
+            lower.is_none_or(|lower| key >= lower) && upper.is_none_or(|upper| key < upper)
+        };
+        if !keys.iter().all(in_bounds) {
+            return Err("分隔键没有正确划分子树");
+        }
+        let Node::Internal { children, .. } = node else {
+            if let Node::Leaf { values, .. } = node
+                && values.len() != keys.len()
+            {
+                return Err("叶子中键与值的数量不一致");
+            }
+            leaves.push(id);
+            return Ok(1);
+        };
+        if children.len() != keys.len() + 1 {
+            return Err("子节点数量不等于键数量加一");
+        }
+        let mut height = None;
+        for (i, &child) in children.iter().enumerate() {
+            let lower = if i == 0 { lower } else { Some(&keys[i - 1]) };
+            let upper = keys.get(i).or(upper);
+            let child_height = self.check_node(child, lower, upper, leaves)?;
+            if *height.get_or_insert(child_height) != child_height {
+                return Err("叶子不在同一层");
+            }
+        }
+        Ok(height.unwrap() + 1)
+    }
+}
+
+/// 沿叶子链表按键的顺序遍历 B+ 树的迭代器
+pub struct Range<'a, K, V> {
+    tree: &'a BPlusTree<K, V>,
+    leaf: Option<usize>,
+    index: usize,
+    end: Bound<K>,
+}
+
+impl<'a, K: Ord + Clone, V> Iterator for Range<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let Node::Leaf { keys, values, next } = self.tree.node(self.leaf?) else {
+                unreachable!()
+            };
+            if self.index == keys.len() {
+                self.leaf = *next;
+                self.index = 0;
+                continue;
+            }
+            let key = &keys[self.index];
+            let below_end = match &self.end {
+                Bound::Included(end) => key <= end,
+                Bound::Excluded(end) => key < end,
+                Bound::Unbounded => true,
+            };
+            if !below_end {
+                self.leaf = None;
+                return None;
+            }
+            self.index += 1;
+            return Some((key, &values[self.index - 1]));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bplus_tree_insert_range() {
+        let mut tree = BPlusTree::new(2);
+        for i in 0..100 {
+            assert_eq!(tree.insert(i * 31 % 100, i), None);
+            assert!(tree.check().is_ok());
+        }
+        assert_eq!(tree.len(), 100);
+        assert_eq!(tree.insert(31, 0), Some(1));
+        *tree.get_mut(&31).unwrap() += 2;
+        assert_eq!(tree.get(&31), Some(&2));
+        assert_eq!(tree.get(&100), None);
+
+        let keys = |range: Range<i32, i32>| range.map(|(k, _)| *k).collect::<Vec<_>>();
+        assert_eq!(keys(tree.iter()), (0..100).collect::<Vec<_>>());
+        assert_eq!(keys(tree.range(40..45)), vec![40, 41, 42, 43, 44]);
+        assert_eq!(
+            keys(tree.range((Bound::Excluded(95), Bound::Unbounded))),
+            vec![96, 97, 98, 99]
+        );
+        assert_eq!(keys(tree.range(..=2)), vec![0, 1, 2]);
+        assert!(keys(tree.range(100..)).is_empty());
+    }
+
+    #[test]
+    fn test_bplus_tree_remove() {
+        for t in 2..5 {
+            let mut tree = BPlusTree::new(t);
+            for i in 0..200 {
+                tree.insert(i, i.to_string());
+            }
+            // 交替从两端删除，覆盖借左、借右与合并
+            for i in 0..100 {
+                let key = if i % 2 == 0 { i } else { 199 - i };
+                assert_eq!(tree.remove(&key), Some(key.to_string()));
+                assert!(tree.check().is_ok(), "t = {t}, key = {key}");
+            }
+            assert_eq!(tree.remove(&0), None);
+            for key in (0..200).step_by(3) {
+                tree.remove(&key);
+                assert!(tree.check().is_ok());
+            }
+            let pages = tree.page_count();
+            while let Some((&key, _)) = tree.iter().next() {
+                tree.remove(&key);
+            }
+            assert!(tree.is_empty());
+            assert!(tree.page_count() < pages);
+            assert_eq!(tree.page_count(), 1);
+        }
+    }
+}
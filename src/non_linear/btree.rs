@@ -0,0 +1,404 @@
+//! # B 树模块
+//!
+//! 最小度数为 `t` 的 B 树（多路平衡查找树）：
+//! - 除根以外的节点至少有 `t - 1` 个键，所有节点至多有 `2t - 1` 个键
+//! - 有 `k` 个键的内部节点恰有 `k + 1` 个子节点
+//! - 所有叶子位于同一层
+//!
+//! 插入时节点溢出则分裂并把中间键提升到父节点；删除后节点不足则先向兄弟借键，
+//! 兄弟也不够时与兄弟及父节点中的分隔键合并。
+//!
+//! ## 示例
+//! ```
+//! use data_structure::non_linear::btree::BTree;
+//!
+//! let mut tree = BTree::new(2);
+//! for i in [5, 1, 9, 3, 7] {
+//!     tree.insert(i, i * 10);
+//! }
+//! assert_eq!(tree.get(&3), Some(&30));
+//! assert_eq!(tree.remove(&5), Some(50));
+//! assert_eq!(tree.iter().map(|(k, _)| *k).collect::<Vec<_>>(), vec![1, 3, 7, 9]);
+//! assert!(tree.check().is_ok());
+//! ```
+
+use std::mem;
+
+/// B 树节点，`children` 为空时是叶子
+#[derive(Clone, Debug)]
+struct Node<K, V> {
+    entries: Vec<(K, V)>,
+    children: Vec<Node<K, V>>,
+}
+
+impl<K: Ord, V> Node<K, V> {
+    fn leaf() -> Self {
+        Self {
+            entries: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    fn is_leaf(&self) -> bool {
+        self.children.is_empty()
+    }
+
+    /// 在节点内二分查找键
+    fn search(&self, key: &K) -> Result<usize, usize> {
+        self.entries.binary_search_by(|(k, _)| k.cmp(key))
+    }
+}
+
+/// 节点分裂的结果：提升到父节点的中间键值对与新的右半节点
+type Split<K, V> = Option<((K, V), Node<K, V>)>;
+
+/// B 树有序映射
+#[derive(Clone, Debug)]
+pub struct BTree<K, V> {
+    root: Node<K, V>,
+    min_degree: usize,
+    len: usize,
+}
+
+impl<K, V> BTree<K, V>
+where
+    K: Ord,
+{
+    /// 创建空的 B 树
+    ///
+    /// # 参数
+    /// * `min_degree` - 最小度数 `t`，至少为 2（`t = 2` 即 2-3-4 树）
+    ///
+    /// # Panics
+    /// `min_degree < 2` 时 panic
+    pub fn new(min_degree: usize) -> Self {
+        assert!(min_degree >= 2, "B 树的最小度数至少为 2");
+        Self {
+            root: Node::leaf(),
+            min_degree,
+            len: 0,
+        }
+    }
+
+    /// 最小度数
+    pub fn min_degree(&self) -> usize {
+        self.min_degree
+    }
+
+    /// 获取键值对的数量
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// 检查是否为空
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// 树的高度，只有根节点时为 1
+    pub fn height(&self) -> usize {
+        let mut height = 1;
+        let mut node = &self.root;
+        while let Some(child) = node.children.first() {
+            height += 1;
+            node = child;
+        }
+        height
+    }
+
+    /// 查找键对应的值
+    pub fn get(&self, key: &K) -> Option<&V> {
+        let mut node = &self.root;
+        loop {
+            match node.search(key) {
+                Ok(i) => return Some(&node.entries[i].1),
+                Err(i) => node = node.children.get(i)?,
+            }
+        }
+    }
+
+    /// 查找键对应的值的可变引用
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        let mut node = &mut self.root;
+        loop {
+            match node.search(key) {
+                Ok(i) => return Some(&mut node.entries[i].1),
+                Err(i) => node = node.children.get_mut(i)?,
+            }
+        }
+    }
+
+    /// 检查是否包含指定的键
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// 插入键值对
+    ///
+    /// # 返回值
+    /// 键已存在时替换旧值并返回 `Some(旧值)`，否则返回 `None`
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let (old, split) = Self::insert_at(&mut self.root, key, value, self.min_degree);
+        if let Some((median, right)) = split {
+            // 根分裂，树长高一层
+            let left = mem::replace(&mut self.root, Node::leaf());
+            self.root.entries.push(median);
+            self.root.children = vec![left, right];
+        }
+        if old.is_none() {
+            self.len += 1;
+        }
+        old
+    }
+
+    /// 递归插入，节点溢出时分裂并返回 `(中间键值对, 右半节点)`
+    fn insert_at(node: &mut Node<K, V>, key: K, value: V, t: usize) -> (Option<V>, Split<K, V>) {
+        let i = match node.search(&key) {
+            Ok(i) => return (Some(mem::replace(&mut node.entries[i].1, value)), None),
+            Err(i) => i,
+        };
+        if node.is_leaf() {
+            node.entries.insert(i, (key, value));
+        } else {
+            let (old, split) = Self::insert_at(&mut node.children[i], key, value, t);
+            let Some((median, right)) = split else {
+                return (old, None);
+            };
+            node.entries.insert(i, median);
+            node.children.insert(i + 1, right);
+        }
+        if node.entries.len() < 2 * t {
+            return (None, None);
+        }
+        // 2t 个键：左边保留 t 个，第 t 个提升，右边 t - 1 个
+        let right = Node {
+            entries: node.entries.split_off(t + 1),
+            children: if node.is_leaf() {
+                Vec::new()
+            } else {
+                node.children.split_off(t + 1)
+            },
+        };
+        let median = node.entries.pop().unwrap();
+        (None, Some((median, right)))
+    }
+
+    /// 删除键值对
+    ///
+    /// # 返回值
+    /// 键存在时返回 `Some(值)`，否则返回 `None`
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let value = Self::remove_at(&mut self.root, key, self.min_degree)?;
+        if self.root.entries.is_empty() && !self.root.is_leaf() {
+            // 根的最后一个键被合并下去，树变矮一层
+            self.root = self.root.children.pop().unwrap();
+        }
+        self.len -= 1;
+        Some(value)
+    }
+
+    fn remove_at(node: &mut Node<K, V>, key: &K, t: usize) -> Option<V> {
+        let (value, child) = match node.search(key) {
+            Ok(i) if node.is_leaf() => return Some(node.entries.remove(i).1),
+            Err(_) if node.is_leaf() => return None,
+            Ok(i) => {
+                // 内部节点的键用前驱顶替，前驱一定在左子树的最右叶子里
+                let predecessor = Self::remove_max(&mut node.children[i], t);
+                (mem::replace(&mut node.entries[i], predecessor).1, i)
+            }
+            Err(i) => (Self::remove_at(&mut node.children[i], key, t)?, i),
+        };
+        Self::fix_child(node, child, t);
+        Some(value)
+    }
+
+    fn remove_max(node: &mut Node<K, V>, t: usize) -> (K, V) {
+        if node.is_leaf() {
+            return node.entries.pop().unwrap();
+        }
+        let last = node.children.len() - 1;
+        let max = Self::remove_max(&mut node.children[last], t);
+        Self::fix_child(node, last, t);
+        max
+    }
+
+    /// 第 `i` 个子节点的键不足 `t - 1` 个时，向兄弟借键或与兄弟合并
+    fn fix_child(node: &mut Node<K, V>, i: usize, t: usize) {
+        if node.children[i].entries.len() >= t - 1 {
+            return;
+        }
+        if i > 0 && node.children[i - 1].entries.len() >= t {
+            // 左兄弟的最大键上移，父节点的分隔键下移
+            let (left, right) = node.children.split_at_mut(i);
+            let (left, child) = (&mut left[i - 1], &mut right[0]);
+            let entry = left.entries.pop().unwrap();
+            child
+                .entries
+                .insert(0, mem::replace(&mut node.entries[i - 1], entry));
+            if let Some(grandchild) = left.children.pop() {
+                child.children.insert(0, grandchild);
+            }
+        } else if i + 1 < node.children.len() && node.children[i + 1].entries.len() >= t {
+            // 右兄弟的最小键上移，父节点的分隔键下移
+            let (left, right) = node.children.split_at_mut(i + 1);
+            let (child, right) = (&mut left[i], &mut right[0]);
+            let entry = right.entries.remove(0);
+            child
+                .entries
+                .push(mem::replace(&mut node.entries[i], entry));
+            if !right.is_leaf() {
+                child.children.push(right.children.remove(0));
+            }
+        } else {
+            // 与相邻兄弟及分隔键合并成一个节点
+            let j = if i > 0 { i - 1 } else { i };
+            let right = node.children.remove(j + 1);
+            let separator = node.entries.remove(j);
+            let left = &mut node.children[j];
+            left.entries.push(separator);
+            left.entries.extend(right.entries);
+            left.children.extend(right.children);
+        }
+    }
+
+    /// 按键从小到大遍历所有键值对
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        let mut iter = Iter { stack: Vec::new() };
+        iter.push_left(&self.root);
+        iter
+    }
+
+    /// 检查 B 树的不变量
+    ///
+    /// 依次检查：键严格递增、每个节点的键数量在范围内、子节点数量等于键数量加一、
+    /// 所有叶子在同一层、键的数量与 `len` 一致。
+    ///
+    /// # 返回值
+    /// 全部满足时返回 `Ok(())`，否则返回描述第一个被破坏的不变量的错误
+    pub fn check(&self) -> Result<(), &'static str> {
+        let mut count = 0;
+        let mut previous: Option<&K> = None;
+        for (key, _) in self.iter() {
+            if previous.is_some_and(|previous| previous >= key) {
+                return Err("键不是严格递增的");
+            }
+            previous = Some(key);
+            count += 1;
+        }
+        if count != self.len {
+            return Err("键的数量与长度不一致");
+        }
+        self.check_node(&self.root, true).map(|_| ())
+    }
+
+    /// 递归检查节点的大小约束，返回子树高度
+    fn check_node(&self, node: &Node<K, V>, is_root: bool) -> Result<usize, &'static str> {
+        let t = self.min_degree;
+        if node.entries.len() > 2 * t - 1 || (!is_root && node.entries.len() < t - 1) {
+            return Err("节点的键数量超出范围");
+        }
+        if node.is_leaf() {
+            return Ok(1);
+        }
+        if node.children.len() != node.entries.len() + 1 {
+            return Err("子节点数量不等于键数量加一");
+        }
+        let mut height = None;
+        for child in &node.children {
+            let child_height = self.check_node(child, false)?;
+            if *height.get_or_insert(child_height) != child_height {
+                return Err("叶子不在同一层");
+            }
+        }
+        Ok(height.unwrap() + 1)
+    }
+}
+
+/// 按键的顺序惰性遍历 B 树的迭代器
+pub struct Iter<'a, K, V> {
+    /// 节点及下一个要访问的键的下标
+    stack: Vec<(&'a Node<K, V>, usize)>,
+}
+
+impl<'a, K, V> Iter<'a, K, V> {
+    fn push_left(&mut self, mut node: &'a Node<K, V>) {
+        loop {
+            self.stack.push((node, 0));
+            match node.children.first() {
+                Some(child) => node = child,
+                None => break,
+            }
+        }
+    }
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (node, index) = self.stack.last_mut()?;
+            let node: &'a Node<K, V> = node;
+            if *index == node.entries.len() {
+                self.stack.pop();
+                continue;
+            }
+            let i = *index;
+            *index += 1;
+            if let Some(child) = node.children.get(i + 1) {
+                self.push_left(child);
+            }
+            let (key, value) = &node.entries[i];
+            return Some((key, value));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_btree_insert() {
+        let mut tree = BTree::new(2);
+        for i in 0..100 {
+            assert_eq!(tree.insert(i * 13 % 100, i), None);
+            assert!(tree.check().is_ok());
+        }
+        assert_eq!(tree.len(), 100);
+        // 2-3-4 树的高度不超过 log2(n + 1)
+        assert!(tree.height() <= 6);
+        assert_eq!(tree.insert(13, 0), Some(1));
+        *tree.get_mut(&13).unwrap() += 5;
+        assert_eq!(tree.get(&13), Some(&5));
+        assert_eq!(tree.get(&100), None);
+        let keys: Vec<i32> = tree.iter().map(|(k, _)| *k).collect();
+        assert_eq!(keys, (0..100).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_btree_remove() {
+        for t in 2..5 {
+            let mut tree = BTree::new(t);
+            for i in 0..200 {
+                tree.insert(i, i.to_string());
+            }
+            // 交替从两端删除，覆盖借左、借右与合并
+            for i in 0..100 {
+                let key = if i % 2 == 0 { i } else { 199 - i };
+                assert_eq!(tree.remove(&key), Some(key.to_string()));
+                assert!(tree.check().is_ok(), "t = {t}, key = {key}");
+            }
+            assert_eq!(tree.remove(&0), None);
+            for key in (0..200).step_by(3) {
+                tree.remove(&key);
+                assert!(tree.check().is_ok());
+            }
+            while let Some((&key, _)) = tree.iter().next() {
+                tree.remove(&key);
+            }
+            assert!(tree.is_empty());
+            assert_eq!(tree.height(), 1);
+        }
+    }
+}
@@ -0,0 +1,340 @@
+//! # 并查集模块
+//!
+//! 并查集（不相交集合森林）维护元素 `0..n` 的一个划分，每个集合是一棵以代表元为根的树。
+//!
+//! - [`DisjointSet`]：按秩或按大小合并，查找时做路径压缩，单次操作的均摊复杂度为 O(α(n))，
+//!   可用于 Kruskal 最小生成树、连通性判断和聚类；
+//! - [`RollbackDisjointSet`]：只按大小合并、不做路径压缩，每次合并都记入历史，
+//!   可以撤销到任意快照，适合离线算法（如线段树分治）中的回溯。单次操作 O(log n)。
+//!
+//! 元素下标越界时 panic。
+//!
+//! ## 示例
+//! ```
+//! use data_structure::non_linear::disjoint_set::DisjointSet;
+//!
+//! // Kruskal：按权值从小到大加边，跳过会成环的边
+//! let mut edges = vec![(4, 0, 1), (1, 1, 2), (3, 0, 2), (2, 2, 3), (5, 1, 3)];
+//! edges.sort();
+//! let mut set = DisjointSet::new(4);
+//! let weight: i32 = edges
+//!     .iter()
+//!     .filter(|&&(_, a, b)| set.union(a, b))
+//!     .map(|&(w, _, _)| w)
+//!     .sum();
+//! assert_eq!(weight, 6);
+//! assert_eq!(set.count(), 1);
+//! ```
+
+/// 合并两棵树时选择新根的依据
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum UnionBy {
+    /// 秩（树高的上界）较大的一棵作为根
+    Rank,
+    /// 元素较多的一棵作为根
+    #[default]
+    Size,
+}
+
+/// 按秩/大小合并并带路径压缩的并查集
+#[derive(Clone, Debug)]
+pub struct DisjointSet {
+    parent: Vec<usize>,
+    /// 只对根有意义：按秩合并时为秩，两种策略下都维护集合大小
+    rank: Vec<u32>,
+    size: Vec<usize>,
+    count: usize,
+    union_by: UnionBy,
+}
+
+impl DisjointSet {
+    /// 创建`n`个单元素集合，按大小合并
+    pub fn new(n: usize) -> Self {
+        Self::with_union_by(n, UnionBy::Size)
+    }
+
+    /// 创建`n`个单元素集合，并指定合并策略
+    ///
+    /// # 参数
+    /// * `n` - 元素个数
+    /// * `union_by` - 按秩还是按大小合并
+    pub fn with_union_by(n: usize, union_by: UnionBy) -> Self {
+        Self {
+            parent: (0..n).collect(),
+            rank: vec![0; n],
+            size: vec![1; n],
+            count: n,
+            union_by,
+        }
+    }
+
+    /// 元素个数
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    /// 检查是否没有元素
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// 集合（连通分量）个数
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// 新增一个单元素集合
+    ///
+    /// # 返回值
+    /// 新元素的下标
+    pub fn push(&mut self) -> usize {
+        let x = self.parent.len();
+        self.parent.push(x);
+        self.rank.push(0);
+        self.size.push(1);
+        self.count += 1;
+        x
+    }
+
+    /// 查找元素所在集合的代表元，并把路径上的节点直接挂到根下
+    pub fn find(&mut self, x: usize) -> usize {
+        let root = self.root(x);
+        let mut x = x;
+        while self.parent[x] != root {
+            x = std::mem::replace(&mut self.parent[x], root);
+        }
+        root
+    }
+
+    /// 查找代表元，不修改树的结构
+    pub fn root(&self, mut x: usize) -> usize {
+        while self.parent[x] != x {
+            x = self.parent[x];
+        }
+        x
+    }
+
+    /// 合并两个元素所在的集合
+    ///
+    /// # 返回值
+    /// 两个元素原本不在同一集合时返回true
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        let a_first = match self.union_by {
+            UnionBy::Rank => self.rank[a] >= self.rank[b],
+            UnionBy::Size => self.size[a] >= self.size[b],
+        };
+        let (root, child) = if a_first { (a, b) } else { (b, a) };
+        self.parent[child] = root;
+        self.size[root] += self.size[child];
+        if self.rank[root] == self.rank[child] {
+            self.rank[root] += 1;
+        }
+        self.count -= 1;
+        true
+    }
+
+    /// 检查两个元素是否在同一集合
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// 元素所在集合的大小
+    pub fn size_of(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.size[root]
+    }
+
+    /// 列出所有集合，每个集合内的元素升序，集合按最小元素排序
+    pub fn groups(&mut self) -> Vec<Vec<usize>> {
+        let mut index = vec![usize::MAX; self.len()];
+        let mut groups: Vec<Vec<usize>> = Vec::with_capacity(self.count);
+        for x in 0..self.len() {
+            let root = self.find(x);
+            if index[root] == usize::MAX {
+                index[root] = groups.len();
+                groups.push(Vec::new());
+            }
+            groups[index[root]].push(x);
+        }
+        groups
+    }
+}
+
+/// 可撤销的并查集
+#[derive(Clone, Debug)]
+pub struct RollbackDisjointSet {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    count: usize,
+    /// 每次`union`的记录：被挂到另一棵树下的根，未发生合并时为None
+    history: Vec<Option<usize>>,
+}
+
+impl RollbackDisjointSet {
+    /// 创建`n`个单元素集合
+    pub fn new(n: usize) -> Self {
+        Self {
+            parent: (0..n).collect(),
+            size: vec![1; n],
+            count: n,
+            history: Vec::new(),
+        }
+    }
+
+    /// 元素个数
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    /// 检查是否没有元素
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// 集合（连通分量）个数
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// 查找代表元；不做路径压缩，以便撤销
+    pub fn find(&self, mut x: usize) -> usize {
+        while self.parent[x] != x {
+            x = self.parent[x];
+        }
+        x
+    }
+
+    /// 合并两个元素所在的集合，并记入历史
+    ///
+    /// # 返回值
+    /// 两个元素原本不在同一集合时返回true
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            self.history.push(None);
+            return false;
+        }
+        let (root, child) = if self.size[a] >= self.size[b] {
+            (a, b)
+        } else {
+            (b, a)
+        };
+        self.parent[child] = root;
+        self.size[root] += self.size[child];
+        self.count -= 1;
+        self.history.push(Some(child));
+        true
+    }
+
+    /// 检查两个元素是否在同一集合
+    pub fn connected(&self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// 元素所在集合的大小
+    pub fn size_of(&self, x: usize) -> usize {
+        self.size[self.find(x)]
+    }
+
+    /// 当前快照，即已执行的`union`次数，可传给[`rollback`](Self::rollback)
+    pub fn snapshot(&self) -> usize {
+        self.history.len()
+    }
+
+    /// 撤销最近一次`union`
+    ///
+    /// # 返回值
+    /// 没有可撤销的操作时返回false
+    pub fn undo(&mut self) -> bool {
+        let Some(record) = self.history.pop() else {
+            return false;
+        };
+        if let Some(child) = record {
+            let root = self.parent[child];
+            self.parent[child] = child;
+            self.size[root] -= self.size[child];
+            self.count += 1;
+        }
+        true
+    }
+
+    /// 撤销到指定快照
+    ///
+    /// # 参数
+    /// * `snapshot` - 由[`snapshot`](Self::snapshot)得到的快照
+    ///
+    /// # Panics
+    /// 快照晚于当前状态时panic
+    pub fn rollback(&mut self, snapshot: usize) {
+        assert!(
+            snapshot <= self.history.len(),
+            "快照 {snapshot} 晚于当前状态"
+        );
+        while self.history.len() > snapshot {
+            self.undo();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_disjoint_set() {
+        for union_by in [UnionBy::Rank, UnionBy::Size] {
+            let mut set = DisjointSet::with_union_by(10, union_by);
+            for (a, b) in [(0, 1), (2, 3), (1, 3), (5, 6), (7, 8), (8, 9), (9, 7)] {
+                set.union(a, b);
+            }
+            assert_eq!(set.count(), 4);
+            assert!(set.connected(0, 2));
+            assert!(!set.connected(4, 5));
+            assert_eq!(set.size_of(3), 4);
+            assert_eq!(set.size_of(4), 1);
+            assert!(!set.union(9, 8));
+            let x = set.push();
+            set.union(x, 4);
+            assert_eq!(
+                set.groups(),
+                vec![vec![0, 1, 2, 3], vec![4, 10], vec![5, 6], vec![7, 8, 9]]
+            );
+        }
+
+        // 长链合并后一次查找即把路径压平
+        let mut set = DisjointSet::with_union_by(1000, UnionBy::Rank);
+        for i in 1..1000 {
+            set.union(i - 1, i);
+        }
+        let root = set.find(999);
+        assert!((0..1000).all(|x| set.parent[x] == root || set.parent[set.parent[x]] == root));
+        assert!(set.rank[root] <= 10);
+    }
+
+    #[test]
+    fn test_rollback() {
+        let mut set = RollbackDisjointSet::new(6);
+        set.union(0, 1);
+        set.union(2, 3);
+        let snapshot = set.snapshot();
+        set.union(1, 2);
+        set.union(0, 3);
+        set.union(4, 5);
+        assert_eq!(set.count(), 2);
+        assert_eq!(set.size_of(3), 4);
+        assert!(set.undo());
+        assert!(!set.connected(4, 5));
+        set.rollback(snapshot);
+        assert_eq!(set.count(), 4);
+        assert!(set.connected(0, 1) && set.connected(2, 3));
+        assert!(!set.connected(1, 2));
+        assert_eq!(set.size_of(0), 2);
+        set.rollback(0);
+        assert_eq!(set.count(), 6);
+        assert!(!set.undo());
+    }
+}
@@ -0,0 +1,282 @@
+//! # 树状数组模块
+//!
+//! 树状数组（Fenwick 树、二叉索引树）用一个数组隐式表示一棵树：下标 `i`（从 1 开始）
+//! 存放区间 `(i - lowbit(i), i]` 的和，其中 `lowbit(i)` 是 `i` 最低位的 1。
+//! 单点修改沿 `i += lowbit(i)` 向上，前缀查询沿 `i -= lowbit(i)` 向下，都是 O(log n)，
+//! 常数和空间都比线段树小，但只适用于可以做减法的运算（如加法）。
+//!
+//! - [`FenwickTree`]：单点修改、前缀和与区间和；
+//! - [`RangeFenwickTree`]：用两个树状数组维护差分，支持区间加、区间和与单点查询。
+//!
+//! 对外的下标都从 0 开始，区间参数的写法与 [`segment_tree`](super::segment_tree) 相同。
+//!
+//! ## 示例
+//! ```
+//! use data_structure::non_linear::fenwick_tree::{FenwickTree, RangeFenwickTree};
+//!
+//! let mut tree = FenwickTree::from(vec![3, 1, 4, 1, 5]);
+//! tree.add(2, 10);
+//! assert_eq!(tree.prefix_sum(3), 18);
+//! assert_eq!(tree.sum(2..=4), 20);
+//!
+//! let mut range = RangeFenwickTree::new(6);
+//! range.add(1..4, 2);
+//! range.add(3.., 1);
+//! assert_eq!(range.sum(..), 9);
+//! assert_eq!(range.get(3), 3);
+//! ```
+
+use std::ops::{Add, RangeBounds, Sub};
+
+use super::segment_tree::{times, to_range};
+
+fn lowbit(i: usize) -> usize {
+    i & i.wrapping_neg()
+}
+
+/// 单点修改、区间求和的树状数组
+#[derive(Clone, Debug)]
+pub struct FenwickTree<T> {
+    /// `tree[i]`（`i >= 1`）为原数组第`i - lowbit(i) + 1`到第`i`个元素之和，`tree[0]`不使用
+    tree: Vec<T>,
+}
+
+impl<T: Add<Output = T> + Sub<Output = T> + Copy + Default> FenwickTree<T> {
+    /// 创建长度为`len`、元素全为零的树状数组
+    pub fn new(len: usize) -> Self {
+        Self {
+            tree: vec![T::default(); len + 1],
+        }
+    }
+
+    /// 元素个数
+    pub fn len(&self) -> usize {
+        self.tree.len() - 1
+    }
+
+    /// 检查是否为空
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// 给第`i`个元素加上`delta`
+    ///
+    /// # Panics
+    /// `i`越界时panic
+    pub fn add(&mut self, i: usize, delta: T) {
+        assert!(i < self.len(), "下标 {i} 超出长度 {}", self.len());
+        let mut i = i + 1;
+        while i < self.tree.len() {
+            self.tree[i] = self.tree[i] + delta;
+            i += lowbit(i);
+        }
+    }
+
+    /// 前`n`个元素之和
+    ///
+    /// 中间结果的溢出范围见[`RangeFenwickTree`]。
+    ///
+    /// # Panics
+    /// `n`超过长度时panic
+    pub fn prefix_sum(&self, n: usize) -> T {
+        assert!(n <= self.len(), "前缀长度 {n} 超出长度 {}", self.len());
+        let (mut i, mut sum) = (n, T::default());
+        while i > 0 {
+            sum = sum + self.tree[i];
+            i -= lowbit(i);
+        }
+        sum
+    }
+
+    /// 区间和
+    ///
+    /// # Panics
+    /// 区间越界时panic
+    pub fn sum(&self, range: impl RangeBounds<usize>) -> T {
+        let (start, end) = to_range(range, self.len());
+        self.prefix_sum(end) - self.prefix_sum(start)
+    }
+
+    /// 获取第`i`个元素
+    pub fn get(&self, i: usize) -> T {
+        self.sum(i..=i)
+    }
+
+    /// 把第`i`个元素设为`value`
+    pub fn set(&mut self, i: usize, value: T) {
+        let delta = value - self.get(i);
+        self.add(i, delta);
+    }
+}
+
+impl<T: Add<Output = T> + Sub<Output = T> + Copy + Default> From<Vec<T>> for FenwickTree<T> {
+    /// 由初始数组建树，O(n)：每个节点只把自己的值加到直接父节点上
+    fn from(values: Vec<T>) -> Self {
+        let mut tree = Vec::with_capacity(values.len() + 1);
+        tree.push(T::default());
+        tree.extend(values);
+        for i in 1..tree.len() {
+            let parent = i + lowbit(i);
+            if parent < tree.len() {
+                tree[parent] = tree[parent] + tree[i];
+            }
+        }
+        Self { tree }
+    }
+}
+
+/// 区间加、区间求和的树状数组
+///
+/// 设差分数组`d[i] = a[i] - a[i - 1]`，则前`n`个元素之和为
+/// `n * Σd[i] - Σ(i * d[i])`（`i`从0到`n - 1`），分别用两个树状数组维护`d[i]`和`i * d[i]`。
+///
+/// 这两项都比区间和本身大得多，区间和不溢出时它们仍可能溢出：若元素的绝对值始终不超过`M`，
+/// 整数类型需要`2 * len * M`不溢出才能保证求和过程不溢出。[`get`](Self::get)只用到差分，
+/// 只需`2 * M`不溢出。
+#[derive(Clone, Debug)]
+pub struct RangeFenwickTree<T> {
+    diff: FenwickTree<T>,
+    weighted: FenwickTree<T>,
+}
+
+impl<T: Add<Output = T> + Sub<Output = T> + Copy + Default> RangeFenwickTree<T> {
+    /// 创建长度为`len`、元素全为零的树状数组
+    pub fn new(len: usize) -> Self {
+        Self {
+            diff: FenwickTree::new(len),
+            weighted: FenwickTree::new(len),
+        }
+    }
+
+    /// 元素个数
+    pub fn len(&self) -> usize {
+        self.diff.len()
+    }
+
+    /// 检查是否为空
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// 差分数组第`i`项加上`delta`
+    fn add_diff(&mut self, i: usize, delta: T) {
+        if i < self.len() {
+            self.diff.add(i, delta);
+            self.weighted.add(i, times(delta, i));
+        }
+    }
+
+    /// 给区间内每个元素加上`delta`
+    ///
+    /// # Panics
+    /// 区间越界时panic
+    pub fn add(&mut self, range: impl RangeBounds<usize>, delta: T) {
+        let (start, end) = to_range(range, self.len());
+        if start < end {
+            self.add_diff(start, delta);
+            self.add_diff(end, T::default() - delta);
+        }
+    }
+
+    /// 前`n`个元素之和
+    ///
+    /// 中间结果的溢出范围见[`RangeFenwickTree`]。
+    ///
+    /// # Panics
+    /// `n`超过长度时panic
+    pub fn prefix_sum(&self, n: usize) -> T {
+        times(self.diff.prefix_sum(n), n) - self.weighted.prefix_sum(n)
+    }
+
+    /// 区间和
+    ///
+    /// # Panics
+    /// 区间越界时panic
+    pub fn sum(&self, range: impl RangeBounds<usize>) -> T {
+        let (start, end) = to_range(range, self.len());
+        self.prefix_sum(end) - self.prefix_sum(start)
+    }
+
+    /// 获取第`i`个元素，即差分数组的前缀和
+    ///
+    /// # Panics
+    /// `i`越界时panic
+    pub fn get(&self, i: usize) -> T {
+        assert!(i < self.len(), "下标 {i} 超出长度 {}", self.len());
+        self.diff.prefix_sum(i + 1)
+    }
+}
+
+impl<T: Add<Output = T> + Sub<Output = T> + Copy + Default> From<Vec<T>> for RangeFenwickTree<T> {
+    fn from(values: Vec<T>) -> Self {
+        let mut tree = Self::new(values.len());
+        let mut prev = T::default();
+        for (i, value) in values.into_iter().enumerate() {
+            tree.add_diff(i, value - prev);
+            prev = value;
+        }
+        tree
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fenwick_tree() {
+        let values: Vec<i64> = (0..20).map(|i| (i * 7 % 11) - 5).collect();
+        let mut tree = FenwickTree::from(values.clone());
+        let mut naive = values.clone();
+        for start in 0..=20 {
+            for end in start..=20 {
+                assert_eq!(tree.sum(start..end), naive[start..end].iter().sum::<i64>());
+            }
+        }
+        tree.add(4, 100);
+        tree.set(19, -1);
+        naive[4] += 100;
+        naive[19] = -1;
+        assert_eq!(tree.prefix_sum(20), naive.iter().sum::<i64>());
+        assert_eq!(tree.get(4), naive[4]);
+        assert!(FenwickTree::<i32>::new(0).is_empty());
+
+        let floats = FenwickTree::from(vec![0.5, 0.25, 0.125]);
+        assert_eq!(floats.sum(1..), 0.375);
+    }
+
+    #[test]
+    fn test_range_fenwick_tree() {
+        let mut naive: Vec<i64> = (0..15).map(|i| i * i % 13).collect();
+        let mut tree = RangeFenwickTree::from(naive.clone());
+        let updates = [(0, 15, 3), (2, 9, -4), (7, 8, 10), (14, 15, 1), (5, 5, 99)];
+        for (start, end, delta) in updates {
+            tree.add(start..end, delta);
+            naive[start..end].iter_mut().for_each(|x| *x += delta);
+            for s in 0..=15 {
+                for e in s..=15 {
+                    assert_eq!(tree.sum(s..e), naive[s..e].iter().sum::<i64>());
+                }
+            }
+        }
+        let values: Vec<i64> = (0..15).map(|i| tree.get(i)).collect();
+        assert_eq!(values, naive);
+
+        // 单点查询只用到差分，元素接近上限也不会溢出
+        let half = i32::MAX / 2;
+        let mut tree = RangeFenwickTree::<i32>::new(3);
+        tree.add(2.., half);
+        assert_eq!(tree.get(2), half);
+        assert_eq!(tree.get(1), 0);
+
+        // 求和要求`2 * len * M`不溢出，取`M`为允许的最大值，`Σ(i * d[i])`达到`4 * M`
+        let m = i32::MAX / 6;
+        let mut tree = RangeFenwickTree::<i32>::new(3);
+        tree.add(.., -m);
+        tree.add(2.., 2 * m);
+        assert_eq!(tree.sum(..), -m);
+        assert_eq!(tree.sum(1..), 0);
+        assert_eq!(tree.sum(2..), m);
+        assert_eq!(tree.prefix_sum(2), -2 * m);
+    }
+}
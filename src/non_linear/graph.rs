@@ -1,4 +1,12 @@
-﻿use std::io;
+﻿pub mod centrality;
+pub mod cycle;
+pub mod euler;
+pub mod generator;
+pub mod grid;
+pub mod hamilton;
+pub mod search;
+
+use std::io;
 
 /// 邻接矩阵表示的图结构
 /// 
@@ -148,3 +156,171 @@ where
         Self { vexs, arcs, arc_num }
     }
 }
+
+/// 带权图的统一访问接口
+///
+/// 顶点统一用下标 `0..vex_num()` 表示，搜索类算法只通过此 trait 访问图，
+/// 因此既可以作用于 [`AMGraph`]，也可以作用于网格等不需要显式存储邻接矩阵的图。
+pub trait WeightedGraph {
+    /// 获取顶点数量
+    fn vex_num(&self) -> usize;
+
+    /// 获取从顶点 `v` 出发的所有弧
+    ///
+    /// # 返回值
+    /// `(终点, 权重)` 组成的向量
+    fn adjacent(&self, v: usize) -> Vec<(usize, usize)>;
+
+    /// 获取所有指向顶点 `v` 的弧
+    ///
+    /// 默认实现会扫描所有顶点，无向图或能直接求出入弧的图应当重写此方法。
+    ///
+    /// # 返回值
+    /// `(起点, 权重)` 组成的向量
+    fn reverse_adjacent(&self, v: usize) -> Vec<(usize, usize)> {
+        let mut result = Vec::new();
+        for u in 0..self.vex_num() {
+            for (w, weight) in self.adjacent(u) {
+                if w == v {
+                    result.push((u, weight));
+                }
+            }
+        }
+        result
+    }
+}
+
+/// 邻接矩阵中权重为 0 的位置视为没有弧
+impl<T, const N: usize> WeightedGraph for AMGraph<T, N>
+where
+    T: Copy + Default + std::str::FromStr,
+    <T as std::str::FromStr>::Err: std::fmt::Debug,
+{
+    fn vex_num(&self) -> usize {
+        N
+    }
+
+    fn adjacent(&self, v: usize) -> Vec<(usize, usize)> {
+        self.arcs[v]
+            .iter()
+            .enumerate()
+            .filter(|(_, weight)| **weight != 0)
+            .map(|(w, weight)| (w, *weight))
+            .collect()
+    }
+
+    fn reverse_adjacent(&self, v: usize) -> Vec<(usize, usize)> {
+        (0..N)
+            .filter(|u| self.arcs[*u][v] != 0)
+            .map(|u| (u, self.arcs[u][v]))
+            .collect()
+    }
+}
+
+
+/// 邻接表表示的图结构
+///
+/// 顶点用下标 `0..vex_num` 表示，适合顶点数较多或在运行时才确定顶点数的图。
+///
+/// # 示例
+/// ```
+/// use data_structure::non_linear::graph::{ALGraph, WeightedGraph};
+///
+/// let mut graph = ALGraph::new(3);
+/// graph.add_arc(0, 1, 5);
+/// graph.add_edge(1, 2, 1);
+/// assert_eq!(graph.arc_num(), 3);
+/// assert_eq!(graph.adjacent(1), vec![(2, 1)]);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ALGraph {
+    adj: Vec<Vec<(usize, usize)>>,
+    arc_num: usize,
+}
+
+impl ALGraph {
+    /// 创建一个没有弧的邻接表图
+    ///
+    /// # 参数
+    /// - `vex_num`: 顶点数量
+    pub fn new(vex_num: usize) -> Self {
+        Self {
+            adj: vec![Vec::new(); vex_num],
+            arc_num: 0,
+        }
+    }
+
+    /// 获取弧的数量，无向边按两条弧计算
+    pub fn arc_num(&self) -> usize {
+        self.arc_num
+    }
+
+    /// 添加一条有向弧
+    ///
+    /// # 参数
+    /// - `from`: 起点下标
+    /// - `to`: 终点下标
+    /// - `weight`: 权重
+    pub fn add_arc(&mut self, from: usize, to: usize, weight: usize) {
+        self.adj[from].push((to, weight));
+        self.arc_num += 1;
+    }
+
+    /// 添加一条无向边，即方向相反的两条弧
+    ///
+    /// # 参数
+    /// - `u`: 一个端点下标
+    /// - `v`: 另一个端点下标
+    /// - `weight`: 权重
+    pub fn add_edge(&mut self, u: usize, v: usize, weight: usize) {
+        self.add_arc(u, v, weight);
+        if u != v {
+            self.add_arc(v, u, weight);
+        }
+    }
+
+    /// 检查是否存在从 `from` 到 `to` 的弧
+    pub fn has_arc(&self, from: usize, to: usize) -> bool {
+        self.adj[from].iter().any(|(w, _)| *w == to)
+    }
+
+    /// 转换为邻接矩阵表示，顶点数据为顶点下标
+    ///
+    /// # 返回值
+    /// 顶点数与 `N` 不一致时返回 `None`；重复的弧只保留最后一条
+    pub fn to_am_graph<const N: usize>(&self) -> Option<AMGraph<usize, N>> {
+        if self.adj.len() != N {
+            return None;
+        }
+        let mut arcs = [[0; N]; N];
+        for (u, list) in self.adj.iter().enumerate() {
+            for &(w, weight) in list {
+                arcs[u][w] = weight;
+            }
+        }
+        let arc_num = arcs.iter().flatten().filter(|w| **w != 0).count();
+        Some(AMGraph::new(std::array::from_fn(|i| i), arcs, arc_num))
+    }
+}
+
+impl<T, const N: usize> From<&AMGraph<T, N>> for ALGraph
+where
+    T: Copy + Default + std::str::FromStr,
+    <T as std::str::FromStr>::Err: std::fmt::Debug,
+{
+    fn from(graph: &AMGraph<T, N>) -> Self {
+        let adj: Vec<Vec<(usize, usize)>> = (0..N).map(|v| graph.adjacent(v)).collect();
+        let arc_num = adj.iter().map(Vec::len).sum();
+        Self { adj, arc_num }
+    }
+}
+
+impl WeightedGraph for ALGraph {
+    fn vex_num(&self) -> usize {
+        self.adj.len()
+    }
+
+    fn adjacent(&self, v: usize) -> Vec<(usize, usize)> {
+        self.adj[v].clone()
+    }
+}
@@ -0,0 +1,343 @@
+//! # 中心性模块
+//!
+//! 计算顶点重要程度的常用指标：
+//! - 度中心性：[`degree_centrality`]、[`in_degree_centrality`]
+//! - 接近中心性：[`closeness_centrality`]
+//! - 介数中心性：[`betweenness_centrality`]（Brandes 算法）
+//! - PageRank：[`pagerank`]
+//! - 离心率、直径与半径：[`eccentricity`]、[`diameter`]、[`radius`]
+//!
+//! 涉及距离的函数都带有 `weighted` 参数：为 `true` 时按弧的权重求最短路径，
+//! 为 `false` 时每条弧的长度都视为 1。
+//!
+//! ## 示例
+//! ```
+//! use data_structure::non_linear::graph::generator::Generator;
+//! use data_structure::non_linear::graph::centrality::{betweenness_centrality, diameter};
+//!
+//! // 路径 0 - 1 - 2：中间顶点位于唯一的一对最短路径上（正反各计一次）
+//! let path = Generator::new(0).grid(1, 3);
+//! assert_eq!(betweenness_centrality(&path, false, false), vec![0.0, 2.0, 0.0]);
+//! assert_eq!(diameter(&path, false), Some(2));
+//! ```
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+
+use super::WeightedGraph;
+
+/// 出度中心性，即出度除以 `n - 1`
+///
+/// 无向图中出度就是度数。
+pub fn degree_centrality<G: WeightedGraph>(graph: &G) -> Vec<f64> {
+    let n = graph.vex_num();
+    let scale = normalizer(n);
+    (0..n)
+        .map(|v| graph.adjacent(v).len() as f64 * scale)
+        .collect()
+}
+
+/// 入度中心性，即入度除以 `n - 1`
+pub fn in_degree_centrality<G: WeightedGraph>(graph: &G) -> Vec<f64> {
+    let n = graph.vex_num();
+    let scale = normalizer(n);
+    (0..n)
+        .map(|v| graph.reverse_adjacent(v).len() as f64 * scale)
+        .collect()
+}
+
+/// 接近中心性
+///
+/// 按顶点出发的最短距离计算。对于不连通的图使用 Wasserman–Faust 修正：
+/// 设从 `v` 可达 `r` 个其他顶点、距离之和为 `s`，则结果为 `(r / s) * (r / (n - 1))`。
+/// 不可达任何顶点时结果为 0。
+///
+/// # 参数
+/// - `graph`: 要计算的图
+/// - `weighted`: 是否按权重计算距离
+pub fn closeness_centrality<G: WeightedGraph>(graph: &G, weighted: bool) -> Vec<f64> {
+    let n = graph.vex_num();
+    (0..n)
+        .map(|v| {
+            let dist = distances(graph, v, weighted);
+            let reachable: Vec<usize> = dist.iter().flatten().copied().collect();
+            let total: usize = reachable.iter().sum();
+            let r = (reachable.len() - 1) as f64;
+            if total == 0 {
+                0.0
+            } else {
+                (r / total as f64) * r * normalizer(n)
+            }
+        })
+        .collect()
+}
+
+/// 介数中心性（Brandes 算法）
+///
+/// 顶点的介数为所有有序顶点对 `(s, t)` 之间经过它的最短路径所占比例之和，
+/// 因此无向图中每对顶点会被正反计算两次。
+///
+/// # 参数
+/// - `graph`: 要计算的图
+/// - `weighted`: 是否按权重计算最短路径
+/// - `normalized`: 是否除以 `(n - 1)(n - 2)`，使结果落在 `[0, 1]`
+pub fn betweenness_centrality<G: WeightedGraph>(
+    graph: &G,
+    weighted: bool,
+    normalized: bool,
+) -> Vec<f64> {
+    let n = graph.vex_num();
+    let mut centrality = vec![0.0; n];
+    for s in 0..n {
+        // order 按距离从近到远记录出栈顺序，pred 记录最短路径上的前驱
+        let mut order = Vec::with_capacity(n);
+        let mut pred: Vec<Vec<usize>> = vec![Vec::new(); n];
+        let mut sigma = vec![0.0f64; n];
+        let mut dist = vec![usize::MAX; n];
+        sigma[s] = 1.0;
+        dist[s] = 0;
+        if weighted {
+            let mut heap = BinaryHeap::from([Reverse((0, s))]);
+            let mut done = vec![false; n];
+            while let Some(Reverse((d, u))) = heap.pop() {
+                if done[u] {
+                    continue;
+                }
+                done[u] = true;
+                order.push(u);
+                for (w, weight) in graph.adjacent(u) {
+                    let next = d + weight;
+                    if next < dist[w] {
+                        dist[w] = next;
+                        sigma[w] = sigma[u];
+                        pred[w] = vec![u];
+                        heap.push(Reverse((next, w)));
+                    } else if next == dist[w] {
+                        sigma[w] += sigma[u];
+                        pred[w].push(u);
+                    }
+                }
+            }
+        } else {
+            let mut queue = VecDeque::from([s]);
+            while let Some(u) = queue.pop_front() {
+                order.push(u);
+                for (w, _) in graph.adjacent(u) {
+                    if dist[w] == usize::MAX {
+                        dist[w] = dist[u] + 1;
+                        queue.push_back(w);
+                    }
+                    if dist[w] == dist[u] + 1 {
+                        sigma[w] += sigma[u];
+                        pred[w].push(u);
+                    }
+                }
+            }
+        }
+        let mut delta = vec![0.0; n];
+        while let Some(w) = order.pop() {
+            for &u in &pred[w] {
+                delta[u] += sigma[u] / sigma[w] * (1.0 + delta[w]);
+            }
+            if w != s {
+                centrality[w] += delta[w];
+            }
+        }
+    }
+    if normalized && n > 2 {
+        let scale = 1.0 / ((n - 1) * (n - 2)) as f64;
+        centrality.iter_mut().for_each(|c| *c *= scale);
+    }
+    centrality
+}
+
+/// PageRank
+///
+/// 每一步以概率 `damping` 沿出弧随机游走，否则随机跳到任意顶点；
+/// 没有出弧的顶点把自己的分数平均分给所有顶点。
+/// 相邻两轮分数的 L1 距离小于 `tolerance` 时停止，最多迭代 1000 轮。
+///
+/// # 参数
+/// - `graph`: 要计算的图
+/// - `damping`: 阻尼系数，通常取 0.85
+/// - `tolerance`: 收敛阈值
+///
+/// # 返回值
+/// 每个顶点的分数，总和为 1
+pub fn pagerank<G: WeightedGraph>(graph: &G, damping: f64, tolerance: f64) -> Vec<f64> {
+    let n = graph.vex_num();
+    if n == 0 {
+        return Vec::new();
+    }
+    let adj: Vec<Vec<usize>> = (0..n)
+        .map(|v| graph.adjacent(v).into_iter().map(|(w, _)| w).collect())
+        .collect();
+    let mut rank = vec![1.0 / n as f64; n];
+    for _ in 0..1000 {
+        let dangling: f64 = (0..n).filter(|v| adj[*v].is_empty()).map(|v| rank[v]).sum();
+        let base = (1.0 - damping + damping * dangling) / n as f64;
+        let mut next = vec![base; n];
+        for (u, list) in adj.iter().enumerate() {
+            let share = damping * rank[u] / list.len() as f64;
+            for &w in list {
+                next[w] += share;
+            }
+        }
+        let change: f64 = next.iter().zip(&rank).map(|(a, b)| (a - b).abs()).sum();
+        rank = next;
+        if change < tolerance {
+            break;
+        }
+    }
+    rank
+}
+
+/// 每个顶点的离心率，即到其他顶点最短距离的最大值
+///
+/// # 返回值
+/// 存在不可达顶点时对应位置为 `None`
+pub fn eccentricity<G: WeightedGraph>(graph: &G, weighted: bool) -> Vec<Option<usize>> {
+    (0..graph.vex_num())
+        .map(|v| {
+            let dist = distances(graph, v, weighted);
+            dist.iter().try_fold(0, |max, d| d.map(|d| max.max(d)))
+        })
+        .collect()
+}
+
+/// 图的直径，即离心率的最大值
+///
+/// # 返回值
+/// 图为空或不是强连通时返回 `None`
+pub fn diameter<G: WeightedGraph>(graph: &G, weighted: bool) -> Option<usize> {
+    let ecc = eccentricity(graph, weighted);
+    ecc.iter().try_fold(None, |max: Option<usize>, e| {
+        e.map(|e| Some(max.map_or(e, |m| m.max(e))))
+    })?
+}
+
+/// 图的半径，即离心率的最小值
+///
+/// # 返回值
+/// 图为空或不是强连通时返回 `None`
+pub fn radius<G: WeightedGraph>(graph: &G, weighted: bool) -> Option<usize> {
+    let ecc = eccentricity(graph, weighted);
+    ecc.iter().try_fold(None, |min: Option<usize>, e| {
+        e.map(|e| Some(min.map_or(e, |m| m.min(e))))
+    })?
+}
+
+/// 单源最短距离，不可达的顶点为 `None`
+fn distances<G: WeightedGraph>(graph: &G, source: usize, weighted: bool) -> Vec<Option<usize>> {
+    let n = graph.vex_num();
+    let mut dist = vec![None; n];
+    dist[source] = Some(0);
+    if weighted {
+        let mut heap = BinaryHeap::from([Reverse((0, source))]);
+        while let Some(Reverse((d, u))) = heap.pop() {
+            if dist[u].is_some_and(|best| d > best) {
+                continue;
+            }
+            for (w, weight) in graph.adjacent(u) {
+                let next = d + weight;
+                if dist[w].is_none_or(|best| next < best) {
+                    dist[w] = Some(next);
+                    heap.push(Reverse((next, w)));
+                }
+            }
+        }
+    } else {
+        let mut queue = VecDeque::from([source]);
+        while let Some(u) = queue.pop_front() {
+            let d = dist[u].unwrap();
+            for (w, _) in graph.adjacent(u) {
+                if dist[w].is_none() {
+                    dist[w] = Some(d + 1);
+                    queue.push_back(w);
+                }
+            }
+        }
+    }
+    dist
+}
+
+/// `1 / (n - 1)`，顶点数不超过 1 时为 0
+fn normalizer(n: usize) -> f64 {
+    if n > 1 { 1.0 / (n - 1) as f64 } else { 0.0 }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::non_linear::graph::ALGraph;
+    use crate::non_linear::graph::generator::Generator;
+
+    fn close(a: &[f64], b: &[f64]) -> bool {
+        a.len() == b.len() && a.iter().zip(b).all(|(x, y)| (x - y).abs() < 1e-6)
+    }
+
+    #[test]
+    fn test_star_centrality() {
+        // 星形图：中心 0 连接其余四个顶点
+        let mut star = ALGraph::new(5);
+        (1..5).for_each(|v| star.add_edge(0, v, 1));
+        assert!(close(
+            &degree_centrality(&star),
+            &[1.0, 0.25, 0.25, 0.25, 0.25]
+        ));
+        assert!(close(
+            &in_degree_centrality(&star),
+            &[1.0, 0.25, 0.25, 0.25, 0.25]
+        ));
+        let closeness = closeness_centrality(&star, false);
+        assert!(close(
+            &closeness,
+            &[1.0, 4.0 / 7.0, 4.0 / 7.0, 4.0 / 7.0, 4.0 / 7.0]
+        ));
+        let betweenness = betweenness_centrality(&star, false, true);
+        assert!(close(&betweenness, &[1.0, 0.0, 0.0, 0.0, 0.0]));
+        assert_eq!(
+            eccentricity(&star, false),
+            vec![Some(1), Some(2), Some(2), Some(2), Some(2)]
+        );
+        assert_eq!(
+            (radius(&star, false), diameter(&star, false)),
+            (Some(1), Some(2))
+        );
+
+        let rank = pagerank(&star, 0.85, 1e-10);
+        assert!((rank.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+        assert!(rank[1..].iter().all(|r| *r < rank[0]));
+    }
+
+    #[test]
+    fn test_weighted_centrality() {
+        // 0 -> 1 -> 3 与 0 -> 2 -> 3 两条路径，按权重只有经过 2 的路径最短
+        let mut graph = ALGraph::new(4);
+        graph.add_arc(0, 1, 5);
+        graph.add_arc(1, 3, 5);
+        graph.add_arc(0, 2, 1);
+        graph.add_arc(2, 3, 1);
+        assert!(close(
+            &betweenness_centrality(&graph, false, false),
+            &[0.0, 0.5, 0.5, 0.0]
+        ));
+        assert!(close(
+            &betweenness_centrality(&graph, true, false),
+            &[0.0, 0.0, 1.0, 0.0]
+        ));
+        assert_eq!(eccentricity(&graph, true)[0], Some(5));
+        // 有向图不是强连通的
+        assert_eq!(diameter(&graph, true), None);
+
+        // 有向环上的 PageRank 是均匀分布
+        let mut cycle = ALGraph::new(4);
+        (0..4).for_each(|v| cycle.add_arc(v, (v + 1) % 4, 1));
+        assert!(close(&pagerank(&cycle, 0.85, 1e-10), &[0.25; 4]));
+        let grid = Generator::new(3).grid(3, 3);
+        assert_eq!(
+            (radius(&grid, false), diameter(&grid, false)),
+            (Some(2), Some(4))
+        );
+    }
+}
@@ -0,0 +1,453 @@
+//! # 环检测模块
+//!
+//! - [`find_cycle_directed`]、[`find_cycle_undirected`]：找出一个具体的环
+//! - [`topological_sort`]：拓扑排序，失败时返回导致失败的环
+//! - [`elementary_cycles`]：Johnson 算法枚举有向图中所有的简单环
+//! - [`negative_cycle`]：Bellman-Ford 算法寻找负权环
+//!
+//! 返回的环都是首尾相同的顶点序列，例如 `[0, 1, 2, 0]`。
+//!
+//! ## 示例
+//! ```
+//! use data_structure::non_linear::graph::ALGraph;
+//! use data_structure::non_linear::graph::cycle::topological_sort;
+//!
+//! let mut deps = ALGraph::new(3);
+//! deps.add_arc(0, 1, 1);
+//! deps.add_arc(1, 2, 1);
+//! assert_eq!(topological_sort(&deps), Ok(vec![0, 1, 2]));
+//! deps.add_arc(2, 1, 1);
+//! assert_eq!(topological_sort(&deps), Err(vec![1, 2, 1]));
+//! ```
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use super::WeightedGraph;
+
+/// 在有向图中寻找一个环
+///
+/// # 返回值
+/// 找到时返回首尾相同的顶点序列，图中无环时返回 `None`
+pub fn find_cycle_directed<G: WeightedGraph>(graph: &G) -> Option<Vec<usize>> {
+    let n = graph.vex_num();
+    // 0 未访问，1 在当前 DFS 路径上，2 已完成
+    let mut color = vec![0u8; n];
+    for root in 0..n {
+        if color[root] != 0 {
+            continue;
+        }
+        let mut stack = vec![(root, graph.adjacent(root), 0)];
+        color[root] = 1;
+        while let Some((v, arcs, next)) = stack.last_mut() {
+            let v = *v;
+            if let Some(&(w, _)) = arcs.get(*next) {
+                *next += 1;
+                match color[w] {
+                    0 => {
+                        color[w] = 1;
+                        stack.push((w, graph.adjacent(w), 0));
+                    }
+                    1 => {
+                        // 回边 v -> w：路径上从 w 到 v 的部分加上这条弧构成环
+                        let start = stack.iter().position(|(u, _, _)| *u == w).unwrap();
+                        let mut cycle: Vec<usize> =
+                            stack[start..].iter().map(|(u, _, _)| *u).collect();
+                        cycle.push(w);
+                        return Some(cycle);
+                    }
+                    _ => {}
+                }
+            } else {
+                color[v] = 2;
+                stack.pop();
+            }
+        }
+    }
+    None
+}
+
+/// 在无向图中寻找一个环
+///
+/// 要求邻接矩阵对称；自环也视为环。
+///
+/// # 返回值
+/// 找到时返回首尾相同的顶点序列，图中无环时返回 `None`
+pub fn find_cycle_undirected<G: WeightedGraph>(graph: &G) -> Option<Vec<usize>> {
+    let n = graph.vex_num();
+    let mut parent: Vec<Option<usize>> = vec![None; n];
+    let mut visited = vec![false; n];
+    for root in 0..n {
+        if visited[root] {
+            continue;
+        }
+        visited[root] = true;
+        let mut stack = vec![root];
+        while let Some(v) = stack.pop() {
+            for (w, _) in graph.adjacent(v) {
+                if w == v {
+                    return Some(vec![v, v]);
+                }
+                if !visited[w] {
+                    visited[w] = true;
+                    parent[w] = Some(v);
+                    stack.push(w);
+                } else if parent[v] != Some(w) {
+                    return Some(tree_cycle(&parent, v, w));
+                }
+            }
+        }
+    }
+    None
+}
+
+/// 由生成树上的两个顶点和一条非树边 `v - w` 还原出环
+fn tree_cycle(parent: &[Option<usize>], v: usize, w: usize) -> Vec<usize> {
+    let ancestors = |mut x: usize| {
+        let mut path = vec![x];
+        while let Some(p) = parent[x] {
+            path.push(p);
+            x = p;
+        }
+        path
+    };
+    let (from_v, from_w) = (ancestors(v), ancestors(w));
+    // 找到两条到根路径上的最近公共祖先
+    let lca = *from_v.iter().find(|x| from_w.contains(x)).unwrap();
+    let mut cycle: Vec<usize> = from_v.iter().copied().take_while(|x| *x != lca).collect();
+    cycle.push(lca);
+    let back: Vec<usize> = from_w.iter().copied().take_while(|x| *x != lca).collect();
+    cycle.extend(back.into_iter().rev());
+    cycle.push(v);
+    cycle
+}
+
+/// 拓扑排序（Kahn 算法）
+///
+/// 用小根堆保存入度为 0 的顶点，每次取出编号最小的一个，O((n + e) log n)。
+///
+/// # 返回值
+/// - `Ok(拓扑序列)`，是所有拓扑序列中字典序最小的一个
+/// - 图中有环时返回 `Err(环)`，便于给出具体的循环依赖
+pub fn topological_sort<G: WeightedGraph>(graph: &G) -> Result<Vec<usize>, Vec<usize>> {
+    let n = graph.vex_num();
+    let mut in_degree = vec![0; n];
+    for v in 0..n {
+        for (w, _) in graph.adjacent(v) {
+            in_degree[w] += 1;
+        }
+    }
+    let mut ready: BinaryHeap<Reverse<usize>> =
+        (0..n).filter(|v| in_degree[*v] == 0).map(Reverse).collect();
+    let mut order = Vec::with_capacity(n);
+    while let Some(Reverse(v)) = ready.pop() {
+        order.push(v);
+        for (w, _) in graph.adjacent(v) {
+            in_degree[w] -= 1;
+            if in_degree[w] == 0 {
+                ready.push(Reverse(w));
+            }
+        }
+    }
+    if order.len() == n {
+        Ok(order)
+    } else {
+        Err(find_cycle_directed(graph).unwrap())
+    }
+}
+
+/// Johnson 算法枚举有向图中所有的简单环
+///
+/// 每个环只出现一次，且以环上编号最小的顶点开始和结束；自环记为 `[v, v]`。
+/// 环的数量可能随顶点数指数增长，只适用于规模较小的图。
+pub fn elementary_cycles<G: WeightedGraph>(graph: &G) -> Vec<Vec<usize>> {
+    let n = graph.vex_num();
+    let adj: Vec<Vec<usize>> = (0..n)
+        .map(|v| {
+            let mut list: Vec<usize> = graph.adjacent(v).into_iter().map(|(w, _)| w).collect();
+            list.sort();
+            list.dedup();
+            list
+        })
+        .collect();
+    let mut johnson = Johnson {
+        adj,
+        start: 0,
+        in_component: vec![false; n],
+        blocked: vec![false; n],
+        blocked_by: vec![Vec::new(); n],
+        stack: Vec::new(),
+        cycles: Vec::new(),
+    };
+    for start in 0..n {
+        johnson.start = start;
+        johnson.in_component = johnson.component(start);
+        if !johnson.in_component.iter().skip(start + 1).any(|x| *x)
+            && !johnson.adj[start].contains(&start)
+        {
+            continue;
+        }
+        johnson.blocked.iter_mut().for_each(|b| *b = false);
+        johnson.blocked_by.iter_mut().for_each(Vec::clear);
+        johnson.circuit(start);
+    }
+    johnson.cycles
+}
+
+/// Johnson 算法的搜索状态
+struct Johnson {
+    adj: Vec<Vec<usize>>,
+    start: usize,
+    /// 包含 start 的强连通分量（只考虑编号不小于 start 的顶点）
+    in_component: Vec<bool>,
+    blocked: Vec<bool>,
+    blocked_by: Vec<Vec<usize>>,
+    stack: Vec<usize>,
+    cycles: Vec<Vec<usize>>,
+}
+
+impl Johnson {
+    /// 求编号不小于 `s` 的顶点导出子图中包含 `s` 的强连通分量
+    fn component(&self, s: usize) -> Vec<bool> {
+        let n = self.adj.len();
+        let mut forward = vec![false; n];
+        let mut stack = vec![s];
+        forward[s] = true;
+        while let Some(u) = stack.pop() {
+            for &w in &self.adj[u] {
+                if w >= s && !forward[w] {
+                    forward[w] = true;
+                    stack.push(w);
+                }
+            }
+        }
+        let mut reverse: Vec<Vec<usize>> = vec![Vec::new(); n];
+        for (u, list) in self.adj.iter().enumerate() {
+            for &w in list {
+                reverse[w].push(u);
+            }
+        }
+        let mut backward = vec![false; n];
+        stack.push(s);
+        backward[s] = true;
+        while let Some(u) = stack.pop() {
+            for &w in &reverse[u] {
+                if w >= s && !backward[w] {
+                    backward[w] = true;
+                    stack.push(w);
+                }
+            }
+        }
+        forward
+            .iter()
+            .zip(&backward)
+            .map(|(f, b)| *f && *b)
+            .collect()
+    }
+
+    fn circuit(&mut self, v: usize) -> bool {
+        let mut found = false;
+        self.stack.push(v);
+        self.blocked[v] = true;
+        for i in 0..self.adj[v].len() {
+            let w = self.adj[v][i];
+            if !self.in_component[w] {
+                continue;
+            }
+            if w == self.start {
+                let mut cycle = self.stack.clone();
+                cycle.push(self.start);
+                self.cycles.push(cycle);
+                found = true;
+            } else if !self.blocked[w] && self.circuit(w) {
+                found = true;
+            }
+        }
+        if found {
+            self.unblock(v);
+        } else {
+            for i in 0..self.adj[v].len() {
+                let w = self.adj[v][i];
+                if self.in_component[w] && !self.blocked_by[w].contains(&v) {
+                    self.blocked_by[w].push(v);
+                }
+            }
+        }
+        self.stack.pop();
+        found
+    }
+
+    fn unblock(&mut self, u: usize) {
+        self.blocked[u] = false;
+        while let Some(w) = self.blocked_by[u].pop() {
+            if self.blocked[w] {
+                self.unblock(w);
+            }
+        }
+    }
+}
+
+/// 寻找负权环（Bellman-Ford 算法）
+///
+/// 邻接矩阵只能保存非负权重，因此由 `cost(起点, 终点, 权重)` 把每条弧映射为有符号的代价，
+/// 例如汇率套利问题中可取 `-ln(汇率)`（需先放大为整数）。
+/// 所有顶点同时作为起点，因此能找到图中任意位置的负权环。
+///
+/// # 参数
+/// - `graph`: 要检查的图
+/// - `cost`: 弧的代价函数
+///
+/// # 返回值
+/// 找到时返回以环上编号最小的顶点开始和结束的顶点序列，不存在负权环时返回 `None`
+///
+/// # 示例
+/// ```
+/// use data_structure::non_linear::graph::ALGraph;
+/// use data_structure::non_linear::graph::cycle::negative_cycle;
+///
+/// let mut graph = ALGraph::new(3);
+/// graph.add_arc(0, 1, 1);
+/// graph.add_arc(1, 2, 1);
+/// graph.add_arc(2, 1, 3);
+/// // 每条弧的代价为权重减 3：环 1 -> 2 -> 1 的总代价为 -2
+/// assert_eq!(negative_cycle(&graph, |_, _, w| w as i64 - 3), Some(vec![1, 2, 1]));
+/// assert_eq!(negative_cycle(&graph, |_, _, w| w as i64), None);
+/// ```
+pub fn negative_cycle<G, F>(graph: &G, cost: F) -> Option<Vec<usize>>
+where
+    G: WeightedGraph,
+    F: Fn(usize, usize, usize) -> i64,
+{
+    let n = graph.vex_num();
+    let arcs: Vec<(usize, usize, i64)> = (0..n)
+        .flat_map(|u| {
+            graph
+                .adjacent(u)
+                .into_iter()
+                .map(move |(w, weight)| (u, w, weight))
+        })
+        .map(|(u, w, weight)| (u, w, cost(u, w, weight)))
+        .collect();
+    let mut dist = vec![0i64; n];
+    let mut prev: Vec<Option<usize>> = vec![None; n];
+    let mut relaxed = None;
+    for _ in 0..n {
+        relaxed = None;
+        for &(u, w, c) in &arcs {
+            if dist[u] + c < dist[w] {
+                dist[w] = dist[u] + c;
+                prev[w] = Some(u);
+                relaxed = Some(w);
+            }
+        }
+        // 某一轮没有任何松弛时距离已经收敛，不存在负权环
+        relaxed?;
+    }
+    // 第 n 轮仍有松弛，沿前驱回退 n 步后一定落在环上
+    let mut x = relaxed?;
+    for _ in 0..n {
+        x = prev[x].unwrap();
+    }
+    let mut cycle = vec![x];
+    let mut current = prev[x].unwrap();
+    while current != x {
+        cycle.push(current);
+        current = prev[current].unwrap();
+    }
+    cycle.reverse();
+    let min = cycle.iter().enumerate().min_by_key(|(_, v)| **v).unwrap().0;
+    cycle.rotate_left(min);
+    cycle.push(cycle[0]);
+    Some(cycle)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::non_linear::graph::ALGraph;
+    use crate::non_linear::graph::AMGraph;
+    use crate::non_linear::graph::generator::Generator;
+
+    fn is_cycle<G: WeightedGraph>(graph: &G, cycle: &[usize]) -> bool {
+        cycle.len() >= 2
+            && cycle.first() == cycle.last()
+            && cycle
+                .windows(2)
+                .all(|w| graph.adjacent(w[0]).iter().any(|(x, _)| *x == w[1]))
+    }
+
+    #[test]
+    fn test_find_cycle() {
+        let dag = Generator::new(5).random_dag(30, 0.3);
+        assert_eq!(find_cycle_directed(&dag), None);
+        assert_eq!(topological_sort(&dag).map(|order| order.len()), Ok(30));
+        // 入度为 0 的顶点中总是先取编号最小的
+        let mut deps = ALGraph::new(4);
+        deps.add_arc(0, 3, 1);
+        assert_eq!(topological_sort(&deps), Ok(vec![0, 1, 2, 3]));
+        deps.add_arc(2, 1, 1);
+        assert_eq!(topological_sort(&deps), Ok(vec![0, 2, 1, 3]));
+
+        let mut graph = ALGraph::new(5);
+        graph.add_arc(0, 1, 1);
+        graph.add_arc(1, 2, 1);
+        graph.add_arc(2, 3, 1);
+        graph.add_arc(3, 1, 1);
+        graph.add_arc(3, 4, 1);
+        assert_eq!(find_cycle_directed(&graph), Some(vec![1, 2, 3, 1]));
+        assert_eq!(topological_sort(&graph), Err(vec![1, 2, 3, 1]));
+
+        let tree = Generator::new(5).random_tree(20);
+        assert_eq!(find_cycle_undirected(&tree), None);
+        let mut grid = Generator::new(5).grid(3, 3);
+        let cycle = find_cycle_undirected(&grid).unwrap();
+        assert!(is_cycle(&grid, &cycle));
+        assert!(cycle.len() >= 5);
+        grid.add_edge(8, 8, 1);
+        assert!(is_cycle(&grid, &find_cycle_undirected(&grid).unwrap()));
+    }
+
+    #[test]
+    fn test_elementary_cycles() {
+        // 完全有向图 K3 共有 3 个二元环和 2 个三元环
+        let complete = Generator::new(0).directed(true).complete(3);
+        let mut cycles = elementary_cycles(&complete);
+        cycles.sort();
+        assert_eq!(
+            cycles,
+            vec![
+                vec![0, 1, 0],
+                vec![0, 1, 2, 0],
+                vec![0, 2, 0],
+                vec![0, 2, 1, 0],
+                vec![1, 2, 1]
+            ]
+        );
+        // 完全有向图 K5 的简单环数为 sum C(5,k)(k-1)! = 10 + 20 + 30 + 24 = 84
+        let complete = Generator::new(0).directed(true).complete(5);
+        assert_eq!(elementary_cycles(&complete).len(), 84);
+        let dag = Generator::new(1).random_dag(15, 0.5);
+        assert!(elementary_cycles(&dag).is_empty());
+
+        let mut arcs = [[0; 3]; 3];
+        arcs[0][0] = 1;
+        arcs[1][2] = 1;
+        let graph: AMGraph<usize, 3> = AMGraph::new([0, 1, 2], arcs, 2);
+        assert_eq!(elementary_cycles(&graph), vec![vec![0, 0]]);
+    }
+
+    #[test]
+    fn test_negative_cycle() {
+        let mut graph = ALGraph::new(4);
+        graph.add_arc(0, 1, 4);
+        graph.add_arc(1, 2, 1);
+        graph.add_arc(2, 3, 1);
+        graph.add_arc(3, 1, 1);
+        // 权重为 1 的弧代价记为 -1，环 1 -> 2 -> 3 -> 1 的总代价为 -3
+        let cost = |_, _, w: usize| if w == 1 { -1 } else { w as i64 };
+        let cycle = negative_cycle(&graph, cost).unwrap();
+        assert!(is_cycle(&graph, &cycle));
+        assert_eq!(cycle.len(), 4);
+        assert_eq!(negative_cycle(&graph, |_, _, w| w as i64), None);
+    }
+}
@@ -0,0 +1,290 @@
+//! # 欧拉路径模块
+//!
+//! 判断有向图/无向图是否存在欧拉路径或欧拉回路，并用 Hierholzer 算法构造。
+//! 无向图要求邻接矩阵对称，即每条边同时以 `(u, v)` 和 `(v, u)` 两条弧出现。
+//!
+//! ## 示例
+//! ```
+//! use data_structure::non_linear::graph::AMGraph;
+//! use data_structure::non_linear::graph::euler::{euler_circuit, euler_kind, EulerKind};
+//!
+//! // 有向三角形 0 -> 1 -> 2 -> 0
+//! let arcs = [[0, 1, 0], [0, 0, 1], [1, 0, 0]];
+//! let graph: AMGraph<i32, 3> = AMGraph::new([0, 1, 2], arcs, 3);
+//! assert_eq!(euler_kind(&graph, true), EulerKind::Circuit);
+//! assert_eq!(euler_circuit(&graph, true), Some(vec![0, 1, 2, 0]));
+//! ```
+
+use super::WeightedGraph;
+
+/// 图的欧拉性质
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EulerKind {
+    /// 存在欧拉回路（没有边的图也视为存在回路）
+    Circuit,
+    /// 只存在欧拉路径，`start` 和 `end` 为路径的两个端点
+    Path { start: usize, end: usize },
+    /// 不存在欧拉路径
+    NotEulerian,
+}
+
+/// 判断图的欧拉性质
+///
+/// # 参数
+/// - `graph`: 要判断的图
+/// - `directed`: 是否按有向图处理
+///
+/// # 返回值
+/// 图的 [`EulerKind`]
+pub fn euler_kind<G>(graph: &G, directed: bool) -> EulerKind
+where
+    G: WeightedGraph,
+{
+    if !edges_connected(graph) {
+        return EulerKind::NotEulerian;
+    }
+    let n = graph.vex_num();
+    if directed {
+        let (mut start, mut end) = (None, None);
+        for v in 0..n {
+            let out_degree = graph.adjacent(v).len() as isize;
+            let in_degree = graph.reverse_adjacent(v).len() as isize;
+            match out_degree - in_degree {
+                0 => {}
+                1 if start.is_none() => start = Some(v),
+                -1 if end.is_none() => end = Some(v),
+                _ => return EulerKind::NotEulerian,
+            }
+        }
+        match (start, end) {
+            (None, None) => EulerKind::Circuit,
+            (Some(start), Some(end)) => EulerKind::Path { start, end },
+            _ => EulerKind::NotEulerian,
+        }
+    } else {
+        let odd: Vec<usize> = (0..n)
+            .filter(|v| undirected_degree(graph, *v) % 2 == 1)
+            .collect();
+        match odd.len() {
+            0 => EulerKind::Circuit,
+            2 => EulerKind::Path {
+                start: odd[0],
+                end: odd[1],
+            },
+            _ => EulerKind::NotEulerian,
+        }
+    }
+}
+
+/// 构造欧拉路径
+///
+/// 图存在欧拉回路时返回一条回路（首尾顶点相同），只存在欧拉路径时返回一条路径。
+///
+/// # 参数
+/// - `graph`: 要处理的图
+/// - `directed`: 是否按有向图处理
+///
+/// # 返回值
+/// 按经过顺序排列的顶点序列，不存在时返回 `None`
+pub fn euler_path<G>(graph: &G, directed: bool) -> Option<Vec<usize>>
+where
+    G: WeightedGraph,
+{
+    let start = match euler_kind(graph, directed) {
+        EulerKind::NotEulerian => return None,
+        EulerKind::Path { start, .. } => start,
+        EulerKind::Circuit => {
+            let n = graph.vex_num();
+            (0..n)
+                .find(|v| !graph.adjacent(*v).is_empty())
+                .or((n > 0).then_some(0))?
+        }
+    };
+    if directed {
+        Some(hierholzer_directed(graph, start))
+    } else {
+        Some(hierholzer_undirected(graph, start))
+    }
+}
+
+/// 构造欧拉回路
+///
+/// # 参数
+/// - `graph`: 要处理的图
+/// - `directed`: 是否按有向图处理
+///
+/// # 返回值
+/// 首尾顶点相同的顶点序列，不存在欧拉回路时返回 `None`
+pub fn euler_circuit<G>(graph: &G, directed: bool) -> Option<Vec<usize>>
+where
+    G: WeightedGraph,
+{
+    match euler_kind(graph, directed) {
+        EulerKind::Circuit => euler_path(graph, directed),
+        _ => None,
+    }
+}
+
+/// 无向图中顶点的度，自环计两次
+fn undirected_degree<G: WeightedGraph>(graph: &G, v: usize) -> usize {
+    graph
+        .adjacent(v)
+        .iter()
+        .map(|(w, _)| if *w == v { 2 } else { 1 })
+        .sum()
+}
+
+/// 检查所有带边的顶点是否位于同一个弱连通分量中
+fn edges_connected<G: WeightedGraph>(graph: &G) -> bool {
+    let n = graph.vex_num();
+    let has_edge: Vec<bool> = (0..n)
+        .map(|v| !graph.adjacent(v).is_empty() || !graph.reverse_adjacent(v).is_empty())
+        .collect();
+    let Some(first) = (0..n).find(|v| has_edge[*v]) else {
+        return true;
+    };
+    let mut visited = vec![false; n];
+    let mut stack = vec![first];
+    visited[first] = true;
+    while let Some(u) = stack.pop() {
+        for (w, _) in graph
+            .adjacent(u)
+            .into_iter()
+            .chain(graph.reverse_adjacent(u))
+        {
+            if !visited[w] {
+                visited[w] = true;
+                stack.push(w);
+            }
+        }
+    }
+    (0..n).all(|v| !has_edge[v] || visited[v])
+}
+
+fn hierholzer_directed<G: WeightedGraph>(graph: &G, start: usize) -> Vec<usize> {
+    // 逆序保存出弧，使得 pop 时按下标从小到大访问
+    let mut adj: Vec<Vec<usize>> = (0..graph.vex_num())
+        .map(|v| {
+            graph
+                .adjacent(v)
+                .into_iter()
+                .rev()
+                .map(|(w, _)| w)
+                .collect()
+        })
+        .collect();
+    let mut stack = vec![start];
+    let mut path = Vec::new();
+    while let Some(&v) = stack.last() {
+        match adj[v].pop() {
+            Some(w) => stack.push(w),
+            None => path.push(stack.pop().unwrap()),
+        }
+    }
+    path.reverse();
+    path
+}
+
+fn hierholzer_undirected<G: WeightedGraph>(graph: &G, start: usize) -> Vec<usize> {
+    let n = graph.vex_num();
+    // 每条边只编号一次，两个端点共享同一个编号
+    let mut adj: Vec<Vec<(usize, usize)>> = vec![Vec::new(); n];
+    let mut edge_num = 0;
+    for u in 0..n {
+        for (w, _) in graph.adjacent(u) {
+            if u <= w {
+                adj[u].push((w, edge_num));
+                if u != w {
+                    adj[w].push((u, edge_num));
+                }
+                edge_num += 1;
+            }
+        }
+    }
+    for list in adj.iter_mut() {
+        list.sort_by(|a, b| b.cmp(a));
+    }
+    let mut used = vec![false; edge_num];
+    let mut stack = vec![start];
+    let mut path = Vec::new();
+    while let Some(&v) = stack.last() {
+        while adj[v].last().is_some_and(|(_, id)| used[*id]) {
+            adj[v].pop();
+        }
+        match adj[v].pop() {
+            Some((w, id)) => {
+                used[id] = true;
+                stack.push(w);
+            }
+            None => path.push(stack.pop().unwrap()),
+        }
+    }
+    path.reverse();
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::non_linear::graph::AMGraph;
+
+    fn undirected<const N: usize>(edges: &[(usize, usize)]) -> AMGraph<usize, N> {
+        let mut arcs = [[0; N]; N];
+        for &(u, v) in edges {
+            arcs[u][v] = 1;
+            arcs[v][u] = 1;
+        }
+        AMGraph::new(std::array::from_fn(|i| i), arcs, edges.len())
+    }
+
+    #[test]
+    fn test_euler_undirected() {
+        // 房子形状的图：只有 1 和 3 的度为奇数
+        let house = undirected::<5>(&[(0, 1), (0, 2), (1, 3), (2, 3), (2, 4), (3, 4), (1, 2)]);
+        assert_eq!(
+            euler_kind(&house, false),
+            EulerKind::Path { start: 1, end: 3 }
+        );
+        assert_eq!(euler_circuit(&house, false), None);
+        let path = euler_path(&house, false).unwrap();
+        assert_eq!(path.len(), 8);
+        assert_eq!((path[0], path[7]), (1, 3));
+        let mut edges: Vec<(usize, usize)> = path
+            .windows(2)
+            .map(|w| (w[0].min(w[1]), w[0].max(w[1])))
+            .collect();
+        edges.sort();
+        assert_eq!(
+            edges,
+            vec![(0, 1), (0, 2), (1, 2), (1, 3), (2, 3), (2, 4), (3, 4)]
+        );
+
+        // 两个不相连的三角形
+        let split = undirected::<6>(&[(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3)]);
+        assert_eq!(euler_kind(&split, false), EulerKind::NotEulerian);
+        let triangle = undirected::<4>(&[(1, 2), (2, 3), (3, 1)]);
+        assert_eq!(euler_circuit(&triangle, false), Some(vec![1, 2, 3, 1]));
+    }
+
+    #[test]
+    fn test_euler_directed() {
+        let mut arcs = [[0; 4]; 4];
+        arcs[0][1] = 1;
+        arcs[1][2] = 1;
+        arcs[2][0] = 1;
+        arcs[2][3] = 1;
+        let graph: AMGraph<usize, 4> = AMGraph::new([0, 1, 2, 3], arcs, 4);
+        assert_eq!(
+            euler_kind(&graph, true),
+            EulerKind::Path { start: 2, end: 3 }
+        );
+        assert_eq!(euler_path(&graph, true), Some(vec![2, 0, 1, 2, 3]));
+        arcs[3][2] = 1;
+        let graph: AMGraph<usize, 4> = AMGraph::new([0, 1, 2, 3], arcs, 5);
+        assert_eq!(euler_circuit(&graph, true), Some(vec![0, 1, 2, 3, 2, 0]));
+        arcs[0][3] = 1;
+        arcs[1][3] = 1;
+        let graph: AMGraph<usize, 4> = AMGraph::new([0, 1, 2, 3], arcs, 7);
+        assert_eq!(euler_kind(&graph, true), EulerKind::NotEulerian);
+    }
+}
@@ -0,0 +1,369 @@
+//! # 图生成器模块
+//!
+//! 用固定种子生成可复现的随机图和结构化图，便于对图算法做大规模的性质测试。
+//! 生成结果为 [`ALGraph`]，可用 [`ALGraph::to_am_graph`] 转换为邻接矩阵。
+//!
+//! 无向模式下每条边以两条方向相反的弧存储；有向模式下：
+//! - 随机树的弧由父节点指向子节点（以顶点 0 为根）
+//! - BA 图和网格图的弧由编号较小的顶点指向编号较大的顶点
+//! - 随机 DAG 总是有向的
+//!
+//! ## 示例
+//! ```
+//! use data_structure::non_linear::graph::WeightedGraph;
+//! use data_structure::non_linear::graph::generator::{Generator, Weights};
+//!
+//! let mut generator = Generator::new(42).weights(Weights::Uniform { min: 1, max: 9 });
+//! let a = generator.erdos_renyi(20, 0.3);
+//! let b = Generator::new(42).weights(Weights::Uniform { min: 1, max: 9 }).erdos_renyi(20, 0.3);
+//! assert_eq!(a, b);
+//! assert_eq!(a.vex_num(), 20);
+//! ```
+
+use super::ALGraph;
+
+/// 弧权重的分布
+///
+/// 邻接矩阵中权重 0 表示没有弧，因此所有分布生成的权重都不小于 1。
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Weights {
+    /// 所有弧的权重都相同
+    Constant(usize),
+    /// 在 `[min, max]` 中均匀分布
+    Uniform { min: usize, max: usize },
+    /// 均值为 `mean` 的指数分布，结果向上取整
+    Exponential { mean: f64 },
+}
+
+/// 带种子的图生成器
+#[derive(Clone, Debug)]
+pub struct Generator {
+    state: u64,
+    weights: Weights,
+    directed: bool,
+}
+
+impl Generator {
+    /// 创建生成器，默认生成权重为 1 的无向图
+    ///
+    /// # 参数
+    /// - `seed`: 随机种子，相同种子和相同调用顺序得到相同的图
+    pub fn new(seed: u64) -> Self {
+        Self {
+            state: seed,
+            weights: Weights::Constant(1),
+            directed: false,
+        }
+    }
+
+    /// 设置弧权重的分布
+    pub fn weights(mut self, weights: Weights) -> Self {
+        self.weights = weights;
+        self
+    }
+
+    /// 设置是否生成有向图
+    pub fn directed(mut self, directed: bool) -> Self {
+        self.directed = directed;
+        self
+    }
+
+    /// Erdős–Rényi 随机图 G(n, p)
+    ///
+    /// 无向模式下每对顶点、有向模式下每个有序顶点对以概率 `p` 相连，不含自环。
+    ///
+    /// # 注意
+    /// `p` 不在 `[0, 1]` 内时会 panic
+    pub fn erdos_renyi(&mut self, n: usize, p: f64) -> ALGraph {
+        assert!((0.0..=1.0).contains(&p), "概率必须在 [0, 1] 内");
+        let mut graph = ALGraph::new(n);
+        for u in 0..n {
+            let others = if self.directed { 0..n } else { u + 1..n };
+            for v in others {
+                if u != v && self.next_f64() < p {
+                    self.connect(&mut graph, u, v);
+                }
+            }
+        }
+        graph
+    }
+
+    /// Barabási–Albert 无标度图
+    ///
+    /// 以 `m + 1` 个顶点的完全图开始，之后每个新顶点按度数成比例地选择 `m` 个不同的已有顶点相连。
+    /// `n <= m + 1` 时退化为完全图。
+    ///
+    /// # 注意
+    /// `m` 为 0 时会 panic
+    pub fn barabasi_albert(&mut self, n: usize, m: usize) -> ALGraph {
+        assert!(m > 0, "每个新顶点至少连接一个已有顶点");
+        let seed = n.min(m + 1);
+        let mut graph = self.complete(seed);
+        // 每个顶点在其中出现的次数等于它的度数
+        let mut endpoints: Vec<usize> = Vec::new();
+        for u in 0..seed {
+            for v in u + 1..seed {
+                endpoints.push(u);
+                endpoints.push(v);
+            }
+        }
+        graph.adj.resize(n, Vec::new());
+        for v in seed..n {
+            let mut targets: Vec<usize> = Vec::with_capacity(m);
+            while targets.len() < m {
+                let u = endpoints[self.below(endpoints.len())];
+                if !targets.contains(&u) {
+                    targets.push(u);
+                }
+            }
+            for u in targets {
+                self.connect(&mut graph, u, v);
+                endpoints.push(u);
+                endpoints.push(v);
+            }
+        }
+        graph
+    }
+
+    /// 在所有 `n` 个顶点的带标号树中均匀随机地生成一棵（Prüfer 序列）
+    pub fn random_tree(&mut self, n: usize) -> ALGraph {
+        let mut edges = Vec::with_capacity(n.saturating_sub(1));
+        if n == 2 {
+            edges.push((0, 1));
+        } else if n > 2 {
+            let prufer: Vec<usize> = (0..n - 2).map(|_| self.below(n)).collect();
+            let mut degree = vec![1; n];
+            for v in &prufer {
+                degree[*v] += 1;
+            }
+            for &v in &prufer {
+                let leaf = (0..n).find(|u| degree[*u] == 1).unwrap();
+                edges.push((leaf, v));
+                degree[leaf] -= 1;
+                degree[v] -= 1;
+            }
+            let rest: Vec<usize> = (0..n).filter(|u| degree[*u] == 1).collect();
+            edges.push((rest[0], rest[1]));
+        }
+
+        let mut graph = ALGraph::new(n);
+        if !self.directed {
+            for (u, v) in edges {
+                let weight = self.weight();
+                graph.add_edge(u, v, weight);
+            }
+            return graph;
+        }
+        // 有向模式下从根 0 出发确定父子方向
+        let mut neighbors = vec![Vec::new(); n];
+        for (u, v) in edges {
+            neighbors[u].push(v);
+            neighbors[v].push(u);
+        }
+        let mut visited = vec![false; n];
+        let mut stack = vec![0];
+        while let Some(u) = stack.pop() {
+            visited[u] = true;
+            for &v in &neighbors[u] {
+                if !visited[v] {
+                    let weight = self.weight();
+                    graph.add_arc(u, v, weight);
+                    stack.push(v);
+                }
+            }
+        }
+        graph
+    }
+
+    /// 随机有向无环图
+    ///
+    /// 先随机打乱顶点得到一个拓扑序，再让拓扑序中每对前后顶点以概率 `p` 相连。
+    ///
+    /// # 注意
+    /// `p` 不在 `[0, 1]` 内时会 panic
+    pub fn random_dag(&mut self, n: usize, p: f64) -> ALGraph {
+        assert!((0.0..=1.0).contains(&p), "概率必须在 [0, 1] 内");
+        let mut order: Vec<usize> = (0..n).collect();
+        for i in (1..n).rev() {
+            let j = self.below(i + 1);
+            order.swap(i, j);
+        }
+        let mut graph = ALGraph::new(n);
+        for i in 0..n {
+            for j in i + 1..n {
+                if self.next_f64() < p {
+                    let weight = self.weight();
+                    graph.add_arc(order[i], order[j], weight);
+                }
+            }
+        }
+        graph
+    }
+
+    /// 四连通网格图，顶点下标为 `行 * cols + 列`
+    pub fn grid(&mut self, rows: usize, cols: usize) -> ALGraph {
+        let mut graph = ALGraph::new(rows * cols);
+        for row in 0..rows {
+            for col in 0..cols {
+                let v = row * cols + col;
+                if col + 1 < cols {
+                    self.connect(&mut graph, v, v + 1);
+                }
+                if row + 1 < rows {
+                    self.connect(&mut graph, v, v + cols);
+                }
+            }
+        }
+        graph
+    }
+
+    /// `n` 个顶点的完全图，不含自环
+    pub fn complete(&mut self, n: usize) -> ALGraph {
+        self.erdos_renyi(n, 1.0)
+    }
+
+    /// 完全二部图 K(left, right)
+    ///
+    /// 前 `left` 个顶点为一部，后 `right` 个顶点为另一部，有向模式下弧由左部指向右部。
+    pub fn complete_bipartite(&mut self, left: usize, right: usize) -> ALGraph {
+        self.random_bipartite(left, right, 1.0)
+    }
+
+    /// 随机二部图，左右两部之间的每对顶点以概率 `p` 相连
+    ///
+    /// # 注意
+    /// `p` 不在 `[0, 1]` 内时会 panic
+    pub fn random_bipartite(&mut self, left: usize, right: usize, p: f64) -> ALGraph {
+        assert!((0.0..=1.0).contains(&p), "概率必须在 [0, 1] 内");
+        let mut graph = ALGraph::new(left + right);
+        for u in 0..left {
+            for v in left..left + right {
+                if self.next_f64() < p {
+                    self.connect(&mut graph, u, v);
+                }
+            }
+        }
+        graph
+    }
+
+    /// 按当前模式连接 `u` 和 `v`：有向时添加弧 `u -> v`，无向时添加边
+    fn connect(&mut self, graph: &mut ALGraph, u: usize, v: usize) {
+        let weight = self.weight();
+        if self.directed {
+            graph.add_arc(u, v, weight);
+        } else {
+            graph.add_edge(u, v, weight);
+        }
+    }
+
+    fn weight(&mut self) -> usize {
+        match self.weights {
+            Weights::Constant(w) => w.max(1),
+            Weights::Uniform { min, max } => {
+                let min = min.max(1);
+                min + self.below(max.saturating_sub(min) + 1)
+            }
+            Weights::Exponential { mean } => {
+                let sample = -(1.0 - self.next_f64()).ln() * mean;
+                (sample.ceil() as usize).max(1)
+            }
+        }
+    }
+
+    /// SplitMix64 伪随机数
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// `[0, 1)` 内的均匀随机浮点数
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// `[0, bound)` 内的均匀随机整数
+    fn below(&mut self, bound: usize) -> usize {
+        (self.next_u64() % bound as u64) as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::non_linear::graph::WeightedGraph;
+
+    fn edge_count(graph: &ALGraph) -> usize {
+        graph.arc_num() / 2
+    }
+
+    fn connected(graph: &ALGraph) -> bool {
+        let n = graph.vex_num();
+        let mut visited = vec![false; n];
+        let mut stack = vec![0];
+        while let Some(u) = stack.pop() {
+            if !visited[u] {
+                visited[u] = true;
+                stack.extend(graph.adjacent(u).into_iter().map(|(w, _)| w));
+            }
+        }
+        visited.iter().all(|v| *v)
+    }
+
+    #[test]
+    fn test_structured_graphs() {
+        let mut generator = Generator::new(7);
+        assert_eq!(edge_count(&generator.complete(6)), 15);
+        let grid = generator.grid(3, 4);
+        assert_eq!(edge_count(&grid), 3 * 3 + 2 * 4);
+        let bipartite = generator.complete_bipartite(2, 3);
+        assert_eq!(edge_count(&bipartite), 6);
+        assert!(!bipartite.has_arc(0, 1) && !bipartite.has_arc(2, 4));
+
+        let matrix = generator.complete(3).to_am_graph::<3>().unwrap();
+        assert_eq!(matrix.arcs(), [[0, 1, 1], [1, 0, 1], [1, 1, 0]]);
+        assert_eq!(ALGraph::from(&matrix).arc_num(), 6);
+        assert!(generator.complete(3).to_am_graph::<4>().is_none());
+    }
+
+    #[test]
+    fn test_random_graphs() {
+        let mut generator = Generator::new(2024).weights(Weights::Uniform { min: 3, max: 5 });
+        for n in [1, 2, 10, 50] {
+            let tree = generator.random_tree(n);
+            assert_eq!(edge_count(&tree), n - 1);
+            assert!(connected(&tree));
+        }
+        let ba = generator.barabasi_albert(100, 2);
+        assert_eq!(edge_count(&ba), 3 + 97 * 2);
+        assert!(connected(&ba));
+        let er = generator.erdos_renyi(40, 0.5);
+        assert!((0..40).all(|v| !er.has_arc(v, v)));
+        assert!((0..40).all(|v| er.adjacent(v).iter().all(|(_, w)| (3..=5).contains(w))));
+        assert_eq!(edge_count(&generator.erdos_renyi(40, 0.0)), 0);
+
+        // 有向随机树从根出发能到达所有顶点
+        let tree = Generator::new(1).directed(true).random_tree(30);
+        assert_eq!(tree.arc_num(), 29);
+        assert!(connected(&tree));
+
+        // 随机 DAG 可以完成拓扑排序
+        let dag = Generator::new(9).random_dag(60, 0.2);
+        let mut in_degree = vec![0; 60];
+        (0..60).for_each(|v| dag.adjacent(v).iter().for_each(|(w, _)| in_degree[*w] += 1));
+        let mut ready: Vec<usize> = (0..60).filter(|v| in_degree[*v] == 0).collect();
+        let mut sorted = 0;
+        while let Some(u) = ready.pop() {
+            sorted += 1;
+            for (w, _) in dag.adjacent(u) {
+                in_degree[w] -= 1;
+                if in_degree[w] == 0 {
+                    ready.push(w);
+                }
+            }
+        }
+        assert_eq!(sorted, 60);
+    }
+}
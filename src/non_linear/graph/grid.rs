@@ -0,0 +1,231 @@
+//! # 网格图模块
+//!
+//! 将二维网格（迷宫、地图）看作隐式的带权图，顶点下标为 `行 * 列数 + 列`，
+//! 不需要构造 [`AMGraph`](super::AMGraph) 即可直接交给搜索算法使用。
+//!
+//! ## 示例
+//! ```
+//! use data_structure::non_linear::graph::grid::{Connectivity, Grid};
+//! use data_structure::non_linear::graph::search::a_star;
+//!
+//! let grid = Grid::from_maze(&["..#", "..#", "..."], Connectivity::Four);
+//! let (start, goal) = (grid.index(0, 0), grid.index(0, 2));
+//! assert!(a_star(&grid, start, goal, |v| grid.manhattan(v, goal)).is_none());
+//! ```
+
+use super::WeightedGraph;
+
+/// 网格的连通方式
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Connectivity {
+    /// 四连通：上下左右
+    Four,
+    /// 八连通：上下左右及四个对角方向
+    Eight,
+}
+
+/// 带障碍物的二维网格图
+///
+/// 直行一步的代价为 [`Grid::STRAIGHT`]，斜行一步的代价为 [`Grid::DIAGONAL`]。
+/// 八连通时不允许贴着障碍物的拐角斜穿。
+#[derive(Clone, Debug)]
+pub struct Grid {
+    rows: usize,
+    cols: usize,
+    blocked: Vec<bool>,
+    connectivity: Connectivity,
+}
+
+impl Grid {
+    /// 直行一步的代价
+    pub const STRAIGHT: usize = 10;
+    /// 斜行一步的代价（约为 `10 * √2`）
+    pub const DIAGONAL: usize = 14;
+
+    /// 创建一个没有障碍物的网格
+    ///
+    /// # 参数
+    /// - `rows`: 行数
+    /// - `cols`: 列数
+    /// - `connectivity`: 连通方式
+    pub fn new(rows: usize, cols: usize, connectivity: Connectivity) -> Self {
+        Self {
+            rows,
+            cols,
+            blocked: vec![false; rows * cols],
+            connectivity,
+        }
+    }
+
+    /// 从字符画创建网格，`#` 表示障碍物，其余字符表示可通行
+    ///
+    /// 列数取最长的一行，较短的行在末尾补为可通行。
+    ///
+    /// # 示例
+    /// ```
+    /// use data_structure::non_linear::graph::grid::{Connectivity, Grid};
+    ///
+    /// let grid = Grid::from_maze(&[".#", ".."], Connectivity::Eight);
+    /// assert!(grid.is_blocked(0, 1));
+    /// ```
+    pub fn from_maze(maze: &[&str], connectivity: Connectivity) -> Self {
+        let cols = maze
+            .iter()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0);
+        let mut grid = Self::new(maze.len(), cols, connectivity);
+        for (row, line) in maze.iter().enumerate() {
+            for (col, c) in line.chars().enumerate() {
+                if c == '#' {
+                    grid.set_obstacle(row, col);
+                }
+            }
+        }
+        grid
+    }
+
+    /// 获取行数
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// 获取列数
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// 将坐标转换为顶点下标
+    pub fn index(&self, row: usize, col: usize) -> usize {
+        row * self.cols + col
+    }
+
+    /// 将顶点下标转换为坐标 `(行, 列)`
+    ///
+    /// # Panics
+    /// 下标越界时panic，包括列数为 0、网格中没有顶点的情况
+    pub fn coord(&self, index: usize) -> (usize, usize) {
+        let len = self.rows * self.cols;
+        assert!(index < len, "下标 {index} 超出顶点数 {len}");
+        (index / self.cols, index % self.cols)
+    }
+
+    /// 在指定坐标放置障碍物
+    pub fn set_obstacle(&mut self, row: usize, col: usize) {
+        let index = self.index(row, col);
+        self.blocked[index] = true;
+    }
+
+    /// 移除指定坐标的障碍物
+    pub fn remove_obstacle(&mut self, row: usize, col: usize) {
+        let index = self.index(row, col);
+        self.blocked[index] = false;
+    }
+
+    /// 检查指定坐标是否为障碍物
+    pub fn is_blocked(&self, row: usize, col: usize) -> bool {
+        self.blocked[self.index(row, col)]
+    }
+
+    /// 曼哈顿距离启发函数，四连通网格下是可采纳的
+    ///
+    /// # 参数
+    /// - `from`: 起点下标
+    /// - `to`: 终点下标
+    pub fn manhattan(&self, from: usize, to: usize) -> usize {
+        let (dr, dc) = self.delta(from, to);
+        (dr + dc) * Self::STRAIGHT
+    }
+
+    /// 八方向距离启发函数，八连通网格下是可采纳的
+    ///
+    /// # 参数
+    /// - `from`: 起点下标
+    /// - `to`: 终点下标
+    pub fn octile(&self, from: usize, to: usize) -> usize {
+        let (dr, dc) = self.delta(from, to);
+        let (low, high) = if dr < dc { (dr, dc) } else { (dc, dr) };
+        low * Self::DIAGONAL + (high - low) * Self::STRAIGHT
+    }
+
+    fn delta(&self, from: usize, to: usize) -> (usize, usize) {
+        let (r1, c1) = self.coord(from);
+        let (r2, c2) = self.coord(to);
+        (r1.abs_diff(r2), c1.abs_diff(c2))
+    }
+
+    fn free(&self, row: isize, col: isize) -> bool {
+        row >= 0
+            && col >= 0
+            && (row as usize) < self.rows
+            && (col as usize) < self.cols
+            && !self.is_blocked(row as usize, col as usize)
+    }
+}
+
+impl WeightedGraph for Grid {
+    fn vex_num(&self) -> usize {
+        self.rows * self.cols
+    }
+
+    fn adjacent(&self, v: usize) -> Vec<(usize, usize)> {
+        let mut result = Vec::new();
+        if self.blocked[v] {
+            return result;
+        }
+        let (row, col) = self.coord(v);
+        let (row, col) = (row as isize, col as isize);
+        for (dr, dc) in [(-1, 0), (1, 0), (0, -1), (0, 1)] {
+            if self.free(row + dr, col + dc) {
+                let w = self.index((row + dr) as usize, (col + dc) as usize);
+                result.push((w, Self::STRAIGHT));
+            }
+        }
+        if self.connectivity == Connectivity::Eight {
+            for (dr, dc) in [(-1, -1), (-1, 1), (1, -1), (1, 1)] {
+                // 两侧的直行格子都可通行时才允许斜穿
+                if self.free(row + dr, col + dc)
+                    && self.free(row + dr, col)
+                    && self.free(row, col + dc)
+                {
+                    let w = self.index((row + dr) as usize, (col + dc) as usize);
+                    result.push((w, Self::DIAGONAL));
+                }
+            }
+        }
+        result
+    }
+
+    fn reverse_adjacent(&self, v: usize) -> Vec<(usize, usize)> {
+        self.adjacent(v)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grid_adjacent() {
+        let grid = Grid::from_maze(&["...", ".#.", "..."], Connectivity::Four);
+        assert_eq!(grid.vex_num(), 9);
+        assert_eq!(grid.coord(grid.index(2, 1)), (2, 1));
+        // 中心是障碍物，没有任何弧
+        assert!(grid.adjacent(grid.index(1, 1)).is_empty());
+        let mut around = grid.adjacent(grid.index(0, 1));
+        around.sort();
+        assert_eq!(around, vec![(0, 10), (2, 10)]);
+    }
+
+    #[test]
+    fn test_grid_diagonal_corner() {
+        let mut grid = Grid::new(2, 2, Connectivity::Eight);
+        assert!(grid.adjacent(0).contains(&(3, Grid::DIAGONAL)));
+        grid.set_obstacle(0, 1);
+        // 拐角被挡住后不能斜穿
+        assert!(!grid.adjacent(0).contains(&(3, Grid::DIAGONAL)));
+        grid.remove_obstacle(0, 1);
+        assert_eq!(grid.octile(0, 3), Grid::DIAGONAL);
+        assert_eq!(grid.manhattan(0, 3), 2 * Grid::STRAIGHT);
+    }
+}
@@ -0,0 +1,242 @@
+//! # 哈密顿路径模块
+//!
+//! 用带剪枝的回溯法搜索哈密顿路径与哈密顿回路，复杂度为指数级，只适用于小规模的图。
+//!
+//! 剪枝策略：
+//! - 搜索前检查入度、出度为 0 的顶点数量
+//! - 每走一步都检查剩余顶点能否从当前顶点经未访问顶点到达
+//! - 优先尝试后继选择最少的邻接点
+//!
+//! ## 示例
+//! ```
+//! use data_structure::non_linear::graph::AMGraph;
+//! use data_structure::non_linear::graph::hamilton::{hamiltonian_cycle, hamiltonian_path};
+//!
+//! // 有向路径 2 -> 0 -> 1
+//! let arcs = [[0, 1, 0], [0, 0, 0], [1, 0, 0]];
+//! let graph: AMGraph<i32, 3> = AMGraph::new([0, 1, 2], arcs, 2);
+//! assert_eq!(hamiltonian_path(&graph), Some(vec![2, 0, 1]));
+//! assert_eq!(hamiltonian_cycle(&graph), None);
+//! ```
+
+use super::WeightedGraph;
+
+/// 搜索一条哈密顿路径
+///
+/// # 参数
+/// - `graph`: 要搜索的图
+///
+/// # 返回值
+/// 恰好经过每个顶点一次的顶点序列，不存在时返回 `None`
+pub fn hamiltonian_path<G>(graph: &G) -> Option<Vec<usize>>
+where
+    G: WeightedGraph,
+{
+    let mut search = Search::new(graph);
+    let n = search.adj.len();
+    if n == 0 {
+        return None;
+    }
+    let sources: Vec<usize> = (0..n).filter(|v| search.in_degree[*v] == 0).collect();
+    let sinks = (0..n).filter(|v| search.adj[*v].is_empty()).count();
+    if n > 1 && (sources.len() > 1 || sinks > 1) {
+        return None;
+    }
+    // 存在入度为 0 的顶点时，它只能作为起点
+    let starts: Vec<usize> = if sources.is_empty() {
+        (0..n).collect()
+    } else {
+        sources
+    };
+    for start in starts {
+        if search.run(start, false) {
+            return Some(search.path);
+        }
+    }
+    None
+}
+
+/// 搜索一条哈密顿回路
+///
+/// # 参数
+/// - `graph`: 要搜索的图
+///
+/// # 返回值
+/// 从顶点 0 出发并回到顶点 0 的顶点序列（长度为顶点数加一），不存在时返回 `None`
+pub fn hamiltonian_cycle<G>(graph: &G) -> Option<Vec<usize>>
+where
+    G: WeightedGraph,
+{
+    let mut search = Search::new(graph);
+    let n = search.adj.len();
+    if n == 0 || (0..n).any(|v| search.adj[v].is_empty() || search.in_degree[v] == 0) {
+        return None;
+    }
+    if search.run(0, true) {
+        search.path.push(0);
+        Some(search.path)
+    } else {
+        None
+    }
+}
+
+/// 回溯搜索的状态
+struct Search {
+    /// 去掉自环后的出弧终点
+    adj: Vec<Vec<usize>>,
+    in_degree: Vec<usize>,
+    visited: Vec<bool>,
+    path: Vec<usize>,
+}
+
+impl Search {
+    fn new<G: WeightedGraph>(graph: &G) -> Self {
+        let n = graph.vex_num();
+        let adj: Vec<Vec<usize>> = (0..n)
+            .map(|v| {
+                graph
+                    .adjacent(v)
+                    .into_iter()
+                    .map(|(w, _)| w)
+                    .filter(|w| *w != v)
+                    .collect()
+            })
+            .collect();
+        let mut in_degree = vec![0; n];
+        for list in &adj {
+            for w in list {
+                in_degree[*w] += 1;
+            }
+        }
+        Self {
+            adj,
+            in_degree,
+            visited: vec![false; n],
+            path: Vec::with_capacity(n + 1),
+        }
+    }
+
+    fn run(&mut self, start: usize, cycle: bool) -> bool {
+        self.visited.iter_mut().for_each(|v| *v = false);
+        self.path.clear();
+        self.visited[start] = true;
+        self.path.push(start);
+        self.extend(start, cycle)
+    }
+
+    fn extend(&mut self, current: usize, cycle: bool) -> bool {
+        let n = self.adj.len();
+        if self.path.len() == n {
+            return !cycle || self.adj[current].contains(&self.path[0]);
+        }
+        if !self.rest_reachable(current) {
+            return false;
+        }
+        let mut candidates: Vec<usize> = self.adj[current]
+            .iter()
+            .copied()
+            .filter(|w| !self.visited[*w])
+            .collect();
+        candidates.sort_by_key(|w| self.adj[*w].iter().filter(|x| !self.visited[**x]).count());
+        for w in candidates {
+            self.visited[w] = true;
+            self.path.push(w);
+            if self.extend(w, cycle) {
+                return true;
+            }
+            self.path.pop();
+            self.visited[w] = false;
+        }
+        false
+    }
+
+    /// 检查所有未访问顶点能否从 `current` 出发只经过未访问顶点到达
+    fn rest_reachable(&self, current: usize) -> bool {
+        let mut seen = self.visited.clone();
+        let mut stack = vec![current];
+        while let Some(u) = stack.pop() {
+            for &w in &self.adj[u] {
+                if !seen[w] {
+                    seen[w] = true;
+                    stack.push(w);
+                }
+            }
+        }
+        seen.iter().all(|v| *v)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::non_linear::graph::AMGraph;
+
+    fn undirected<const N: usize>(edges: &[(usize, usize)]) -> AMGraph<usize, N> {
+        let mut arcs = [[0; N]; N];
+        for &(u, v) in edges {
+            arcs[u][v] = 1;
+            arcs[v][u] = 1;
+        }
+        AMGraph::new(std::array::from_fn(|i| i), arcs, edges.len())
+    }
+
+    fn is_hamiltonian<const N: usize>(graph: &AMGraph<usize, N>, path: &[usize]) -> bool {
+        let mut seen = [false; N];
+        path.iter().take(N).for_each(|v| seen[*v] = true);
+        seen.iter().all(|v| *v) && path.windows(2).all(|w| graph.arcs()[w[0]][w[1]] != 0)
+    }
+
+    #[test]
+    fn test_hamiltonian_cycle() {
+        // 立方体的八个顶点存在哈密顿回路
+        let cube = undirected::<8>(&[
+            (0, 1),
+            (1, 2),
+            (2, 3),
+            (3, 0),
+            (4, 5),
+            (5, 6),
+            (6, 7),
+            (7, 4),
+            (0, 4),
+            (1, 5),
+            (2, 6),
+            (3, 7),
+        ]);
+        let cycle = hamiltonian_cycle(&cube).unwrap();
+        assert_eq!(cycle.len(), 9);
+        assert_eq!((cycle[0], cycle[8]), (0, 0));
+        assert!(is_hamiltonian(&cube, &cycle));
+
+        // 星形图既没有哈密顿回路也没有哈密顿路径
+        let star = undirected::<4>(&[(0, 1), (0, 2), (0, 3)]);
+        assert_eq!(hamiltonian_cycle(&star), None);
+        assert_eq!(hamiltonian_path(&star), None);
+    }
+
+    #[test]
+    fn test_hamiltonian_path() {
+        // Petersen 图没有哈密顿回路，但存在哈密顿路径
+        let petersen = undirected::<10>(&[
+            (0, 1),
+            (1, 2),
+            (2, 3),
+            (3, 4),
+            (4, 0),
+            (0, 5),
+            (1, 6),
+            (2, 7),
+            (3, 8),
+            (4, 9),
+            (5, 7),
+            (7, 9),
+            (9, 6),
+            (6, 8),
+            (8, 5),
+        ]);
+        assert_eq!(hamiltonian_cycle(&petersen), None);
+        let path = hamiltonian_path(&petersen).unwrap();
+        assert_eq!(path.len(), 10);
+        assert!(is_hamiltonian(&petersen, &path));
+    }
+}
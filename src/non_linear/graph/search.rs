@@ -0,0 +1,300 @@
+//! # 启发式搜索模块
+//!
+//! 提供面向目标的最短路径搜索：
+//! - [`a_star`]：A* 搜索，使用调用者提供的可采纳启发函数
+//! - [`bidirectional_dijkstra`]：双向 Dijkstra，按权重求最短路径
+//! - [`bidirectional_bfs`]：双向广度优先搜索，求弧数最少的路径
+//!
+//! 所有函数都作用于实现了 [`WeightedGraph`] 的图，返回的路径包含起点和终点。
+//!
+//! ## 示例
+//! ```
+//! use data_structure::non_linear::graph::AMGraph;
+//! use data_structure::non_linear::graph::search::{a_star, bidirectional_dijkstra};
+//!
+//! let arcs = [[0, 1, 4], [0, 0, 1], [0, 0, 0]];
+//! let graph: AMGraph<i32, 3> = AMGraph::new([0, 1, 2], arcs, 3);
+//! assert_eq!(a_star(&graph, 0, 2, |_| 0), Some((2, vec![0, 1, 2])));
+//! assert_eq!(bidirectional_dijkstra(&graph, 0, 2), Some((2, vec![0, 1, 2])));
+//! ```
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+
+use super::WeightedGraph;
+
+/// A* 搜索
+///
+/// 启发函数 `heuristic(v)` 应当给出顶点 `v` 到终点代价的下界（可采纳），
+/// 此时返回的路径一定是最短路径；启发函数恒为 0 时退化为 Dijkstra 算法。
+///
+/// # 参数
+/// - `graph`: 要搜索的图
+/// - `start`: 起点下标
+/// - `goal`: 终点下标
+/// - `heuristic`: 启发函数
+///
+/// # 返回值
+/// 可达时返回 `Some((路径总代价, 路径))`，否则返回 `None`
+pub fn a_star<G, H>(
+    graph: &G,
+    start: usize,
+    goal: usize,
+    heuristic: H,
+) -> Option<(usize, Vec<usize>)>
+where
+    G: WeightedGraph,
+    H: Fn(usize) -> usize,
+{
+    let n = graph.vex_num();
+    if start >= n || goal >= n {
+        return None;
+    }
+    let mut dist = vec![usize::MAX; n];
+    let mut prev = vec![None; n];
+    let mut open = BinaryHeap::new();
+    dist[start] = 0;
+    open.push(Reverse((heuristic(start), 0, start)));
+
+    while let Some(Reverse((_, cost, u))) = open.pop() {
+        // 堆中可能残留已被更新过的旧记录
+        if cost > dist[u] {
+            continue;
+        }
+        if u == goal {
+            return Some((cost, build_path(&prev, goal)));
+        }
+        for (w, weight) in graph.adjacent(u) {
+            let next = cost + weight;
+            if next < dist[w] {
+                dist[w] = next;
+                prev[w] = Some(u);
+                open.push(Reverse((next.saturating_add(heuristic(w)), next, w)));
+            }
+        }
+    }
+    None
+}
+
+/// 双向 Dijkstra 搜索
+///
+/// 同时从起点沿出弧、从终点沿入弧扩展，两侧堆顶距离之和不小于当前最优值时停止。
+///
+/// # 参数
+/// - `graph`: 要搜索的图
+/// - `start`: 起点下标
+/// - `goal`: 终点下标
+///
+/// # 返回值
+/// 可达时返回 `Some((路径总代价, 路径))`，否则返回 `None`
+pub fn bidirectional_dijkstra<G>(
+    graph: &G,
+    start: usize,
+    goal: usize,
+) -> Option<(usize, Vec<usize>)>
+where
+    G: WeightedGraph,
+{
+    let n = graph.vex_num();
+    if start >= n || goal >= n {
+        return None;
+    }
+    if start == goal {
+        return Some((0, vec![start]));
+    }
+    let mut dist = [vec![usize::MAX; n], vec![usize::MAX; n]];
+    let mut prev = [vec![None; n], vec![None; n]];
+    let mut heaps: [BinaryHeap<Reverse<(usize, usize)>>; 2] =
+        [BinaryHeap::new(), BinaryHeap::new()];
+    dist[0][start] = 0;
+    dist[1][goal] = 0;
+    heaps[0].push(Reverse((0, start)));
+    heaps[1].push(Reverse((0, goal)));
+
+    let mut best = usize::MAX;
+    let mut meet = None;
+    while let (Some(Reverse((top_f, _))), Some(Reverse((top_b, _)))) =
+        (heaps[0].peek(), heaps[1].peek())
+    {
+        if top_f.saturating_add(*top_b) >= best {
+            break;
+        }
+        // 0 为正向，1 为反向，每次扩展堆顶较小的一侧
+        let side = if top_f <= top_b { 0 } else { 1 };
+        let Reverse((cost, u)) = heaps[side].pop().unwrap();
+        if cost > dist[side][u] {
+            continue;
+        }
+        let arcs = if side == 0 {
+            graph.adjacent(u)
+        } else {
+            graph.reverse_adjacent(u)
+        };
+        for (w, weight) in arcs {
+            let next = cost + weight;
+            if next < dist[side][w] {
+                dist[side][w] = next;
+                prev[side][w] = Some(u);
+                heaps[side].push(Reverse((next, w)));
+            }
+            let other = dist[1 - side][w];
+            if other != usize::MAX && dist[side][w] + other < best {
+                best = dist[side][w] + other;
+                meet = Some(w);
+            }
+        }
+    }
+
+    let meet = meet?;
+    let mut path = build_path(&prev[0], meet);
+    let mut current = meet;
+    while let Some(next) = prev[1][current] {
+        path.push(next);
+        current = next;
+    }
+    Some((best, path))
+}
+
+/// 双向广度优先搜索
+///
+/// 忽略权重，每次按层扩展当前队列较短的一侧，求经过弧数最少的路径。
+///
+/// # 参数
+/// - `graph`: 要搜索的图
+/// - `start`: 起点下标
+/// - `goal`: 终点下标
+///
+/// # 返回值
+/// 可达时返回 `Some(路径)`，否则返回 `None`
+pub fn bidirectional_bfs<G>(graph: &G, start: usize, goal: usize) -> Option<Vec<usize>>
+where
+    G: WeightedGraph,
+{
+    let n = graph.vex_num();
+    if start >= n || goal >= n {
+        return None;
+    }
+    if start == goal {
+        return Some(vec![start]);
+    }
+    let mut depth = [vec![usize::MAX; n], vec![usize::MAX; n]];
+    let mut prev = [vec![None; n], vec![None; n]];
+    let mut queues = [VecDeque::from([start]), VecDeque::from([goal])];
+    depth[0][start] = 0;
+    depth[1][goal] = 0;
+
+    while !queues[0].is_empty() && !queues[1].is_empty() {
+        let side = if queues[0].len() <= queues[1].len() {
+            0
+        } else {
+            1
+        };
+        // 扩展完整的一层后再比较，保证得到的是最短路径
+        let mut best: Option<(usize, usize)> = None;
+        for _ in 0..queues[side].len() {
+            let u = queues[side].pop_front().unwrap();
+            let arcs = if side == 0 {
+                graph.adjacent(u)
+            } else {
+                graph.reverse_adjacent(u)
+            };
+            for (w, _) in arcs {
+                if depth[side][w] == usize::MAX {
+                    depth[side][w] = depth[side][u] + 1;
+                    prev[side][w] = Some(u);
+                    queues[side].push_back(w);
+                }
+                if depth[1 - side][w] != usize::MAX {
+                    let length = depth[side][w] + depth[1 - side][w];
+                    if best.is_none_or(|(shortest, _)| length < shortest) {
+                        best = Some((length, w));
+                    }
+                }
+            }
+        }
+        if let Some((_, meet)) = best {
+            let mut path = build_path(&prev[0], meet);
+            let mut current = meet;
+            while let Some(next) = prev[1][current] {
+                path.push(next);
+                current = next;
+            }
+            return Some(path);
+        }
+    }
+    None
+}
+
+/// 根据前驱数组回溯出从搜索起点到 `end` 的路径
+fn build_path(prev: &[Option<usize>], end: usize) -> Vec<usize> {
+    let mut path = vec![end];
+    let mut current = end;
+    while let Some(p) = prev[current] {
+        path.push(p);
+        current = p;
+    }
+    path.reverse();
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::non_linear::graph::AMGraph;
+    use crate::non_linear::graph::grid::{Connectivity, Grid};
+
+    fn sample_graph() -> AMGraph<char, 5> {
+        //  A --4--> B --1--> E
+        //  |        ^
+        //  1        1
+        //  v        |
+        //  C --1--> D --7--> E
+        let mut arcs = [[0; 5]; 5];
+        arcs[0][1] = 4;
+        arcs[0][2] = 1;
+        arcs[2][3] = 1;
+        arcs[3][1] = 1;
+        arcs[1][4] = 1;
+        arcs[3][4] = 7;
+        AMGraph::new(['A', 'B', 'C', 'D', 'E'], arcs, 6)
+    }
+
+    #[test]
+    fn test_weighted_search() {
+        let graph = sample_graph();
+        let expected = Some((4, vec![0, 2, 3, 1, 4]));
+        assert_eq!(a_star(&graph, 0, 4, |_| 0), expected);
+        assert_eq!(bidirectional_dijkstra(&graph, 0, 4), expected);
+        assert_eq!(bidirectional_bfs(&graph, 0, 4), Some(vec![0, 1, 4]));
+        // 有向图中反方向不可达
+        assert_eq!(a_star(&graph, 4, 0, |_| 0), None);
+        assert_eq!(bidirectional_dijkstra(&graph, 4, 0), None);
+        assert_eq!(bidirectional_bfs(&graph, 4, 0), None);
+        assert_eq!(bidirectional_dijkstra(&graph, 2, 2), Some((0, vec![2])));
+    }
+
+    #[test]
+    fn test_grid_search() {
+        let maze = [
+            "....#...", ".##.#.#.", ".#..#.#.", ".#.##.#.", "...#..#.", ".#...#..",
+        ];
+        for connectivity in [Connectivity::Four, Connectivity::Eight] {
+            let grid = Grid::from_maze(&maze, connectivity);
+            let (start, goal) = (grid.index(0, 0), grid.index(0, 7));
+            let heuristic = |v| match connectivity {
+                Connectivity::Four => grid.manhattan(v, goal),
+                Connectivity::Eight => grid.octile(v, goal),
+            };
+            let (cost, path) = a_star(&grid, start, goal, heuristic).unwrap();
+            assert_eq!(bidirectional_dijkstra(&grid, start, goal).unwrap().0, cost);
+            assert_eq!(path.first(), Some(&start));
+            assert_eq!(path.last(), Some(&goal));
+            let steps = bidirectional_bfs(&grid, start, goal).unwrap();
+            assert!(steps.len() <= path.len());
+        }
+        let grid = Grid::from_maze(&maze, Connectivity::Four);
+        let (cost, path) = a_star(&grid, 0, grid.index(0, 7), |_| 0).unwrap();
+        assert_eq!(path.len(), 18);
+        assert_eq!(cost, 17 * Grid::STRAIGHT);
+    }
+}
@@ -0,0 +1,561 @@
+//! # 堆模块
+//!
+//! - [`BinaryHeap`]：用数组存储的完全二叉树，可以是小根堆或大根堆
+//! - [`IndexedPriorityQueue`]：元素以 `0..capacity` 的下标（如图的顶点编号）标识的小根堆，
+//!   支持按下标查询、修改和删除优先级，适合 Dijkstra、Prim 等需要 decrease-key 的算法
+//!
+//! 此外还提供实现了统一 [`PriorityQueue`] 特征的可合并堆，便于在基准测试中互相替换：
+//! [`dary::DaryHeap`]、[`leftist::LeftistHeap`]、[`binomial::BinomialHeap`]、
+//! [`pairing::PairingHeap`]、[`fibonacci::FibonacciHeap`]。
+//!
+//! ## 示例
+//! ```
+//! use data_structure::non_linear::heap::{BinaryHeap, HeapKind};
+//!
+//! let mut heap = BinaryHeap::from_vec(vec![3, 1, 4, 1, 5], HeapKind::Max);
+//! heap.push(9);
+//! assert_eq!(heap.pop(), Some(9));
+//! assert_eq!(heap.peek(), Some(&5));
+//! assert_eq!(heap.into_sorted_vec(), vec![1, 1, 3, 4, 5]);
+//! ```
+
+pub mod binomial;
+pub mod dary;
+pub mod fibonacci;
+pub mod leftist;
+pub mod pairing;
+
+/// 堆中元素的句柄，由 [`PriorityQueue::push`] 返回，用于 [`PriorityQueue::decrease_key`]
+///
+/// 句柄是发放它的堆内部使用的不透明标识，不应假定它与元素的存储位置有关，也不能用于其他堆
+/// （[`meld`](PriorityQueue::meld) 后按返回的偏移量换算）。元素弹出后句柄失效，但不会被其他元素复用。
+pub type Handle = usize;
+
+/// 支持合并与 decrease-key 的小根优先队列
+///
+/// # 示例
+/// ```
+/// use data_structure::non_linear::heap::PriorityQueue;
+/// use data_structure::non_linear::heap::pairing::PairingHeap;
+///
+/// let mut heap = PairingHeap::new();
+/// let a = heap.push(10);
+/// heap.push(5);
+/// heap.decrease_key(a, 1).unwrap();
+/// assert_eq!(heap.peek(), Some(&1));
+///
+/// let mut other = PairingHeap::new();
+/// let b = other.push(7);
+/// let offset = heap.meld(other);
+/// heap.decrease_key(b + offset, 0).unwrap();
+/// assert_eq!(heap.pop(), Some(0));
+/// ```
+pub trait PriorityQueue<T: Ord> {
+    /// 插入元素，返回元素的句柄
+    fn push(&mut self, value: T) -> Handle;
+
+    /// 查看最小的元素
+    fn peek(&self) -> Option<&T>;
+
+    /// 弹出最小的元素
+    fn pop(&mut self) -> Option<T>;
+
+    /// 获取元素数量
+    fn len(&self) -> usize;
+
+    /// 检查是否为空
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// 按句柄查看仍在堆中的元素
+    fn get(&self, handle: Handle) -> Option<&T>;
+
+    /// 把句柄对应元素的值降低为 `value`
+    ///
+    /// # 返回值
+    /// 句柄已失效，或新值大于当前值时返回错误
+    fn decrease_key(&mut self, handle: Handle, value: T) -> Result<(), &'static str>;
+
+    /// 把另一个同类型的堆合并进来
+    ///
+    /// # 返回值
+    /// 句柄偏移量：`other` 中句柄为 `h` 的元素合并后的句柄为 `h + 偏移量`
+    fn meld(&mut self, other: Self) -> usize
+    where
+        Self: Sized;
+}
+
+/// 堆的类型
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HeapKind {
+    /// 小根堆：堆顶是最小元素
+    Min,
+    /// 大根堆：堆顶是最大元素
+    Max,
+}
+
+/// 二叉堆
+///
+/// 下标为 `i` 的节点的子节点为 `2i + 1` 和 `2i + 2`，父节点为 `(i - 1) / 2`。
+#[derive(Clone, Debug)]
+pub struct BinaryHeap<T> {
+    data: Vec<T>,
+    kind: HeapKind,
+}
+
+impl<T: Ord> BinaryHeap<T> {
+    /// 创建空堆
+    ///
+    /// # 参数
+    /// * `kind` - 小根堆或大根堆
+    pub fn new(kind: HeapKind) -> Self {
+        Self {
+            data: Vec::new(),
+            kind,
+        }
+    }
+
+    /// 创建空的小根堆
+    pub fn min_heap() -> Self {
+        Self::new(HeapKind::Min)
+    }
+
+    /// 创建空的大根堆
+    pub fn max_heap() -> Self {
+        Self::new(HeapKind::Max)
+    }
+
+    /// 自底向上建堆，时间复杂度 O(n)
+    ///
+    /// # 参数
+    /// * `data` - 任意顺序的元素
+    /// * `kind` - 小根堆或大根堆
+    pub fn from_vec(data: Vec<T>, kind: HeapKind) -> Self {
+        let mut heap = Self { data, kind };
+        for i in (0..heap.data.len() / 2).rev() {
+            heap.sift_down(i, heap.data.len());
+        }
+        heap
+    }
+
+    /// 堆的类型
+    pub fn kind(&self) -> HeapKind {
+        self.kind
+    }
+
+    /// 获取元素数量
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// 检查是否为空
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// 查看堆顶元素
+    pub fn peek(&self) -> Option<&T> {
+        self.data.first()
+    }
+
+    /// 插入元素
+    pub fn push(&mut self, value: T) {
+        self.data.push(value);
+        self.sift_up(self.data.len() - 1);
+    }
+
+    /// 弹出堆顶元素
+    pub fn pop(&mut self) -> Option<T> {
+        if self.data.is_empty() {
+            return None;
+        }
+        let last = self.data.len() - 1;
+        self.data.swap(0, last);
+        let top = self.data.pop();
+        self.sift_down(0, self.data.len());
+        top
+    }
+
+    /// 消耗堆，用堆排序得到升序排列的元素
+    pub fn into_sorted_vec(mut self) -> Vec<T> {
+        // 反复把堆顶换到末尾：大根堆得到升序，小根堆得到降序
+        for end in (1..self.data.len()).rev() {
+            self.data.swap(0, end);
+            self.sift_down(0, end);
+        }
+        if self.kind == HeapKind::Min {
+            self.data.reverse();
+        }
+        self.data
+    }
+
+    /// 消耗堆，按内部数组的顺序返回元素
+    pub fn into_vec(self) -> Vec<T> {
+        self.data
+    }
+
+    /// `a` 是否应该位于 `b` 的上方
+    fn above(&self, a: &T, b: &T) -> bool {
+        match self.kind {
+            HeapKind::Min => a < b,
+            HeapKind::Max => a > b,
+        }
+    }
+
+    fn sift_up(&mut self, mut i: usize) {
+        while i > 0 {
+            let parent = (i - 1) / 2;
+            if !self.above(&self.data[i], &self.data[parent]) {
+                break;
+            }
+            self.data.swap(i, parent);
+            i = parent;
+        }
+    }
+
+    /// 在前 `len` 个元素范围内下沉
+    fn sift_down(&mut self, mut i: usize, len: usize) {
+        loop {
+            let mut top = i;
+            for child in [2 * i + 1, 2 * i + 2] {
+                if child < len && self.above(&self.data[child], &self.data[top]) {
+                    top = child;
+                }
+            }
+            if top == i {
+                break;
+            }
+            self.data.swap(i, top);
+            i = top;
+        }
+    }
+}
+
+impl<T: Ord> Extend<T> for BinaryHeap<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+/// 索引优先队列（小根堆）
+///
+/// 每个元素由 `0..capacity` 内的下标标识，同一下标同时至多出现一次。
+///
+/// # 示例
+/// ```
+/// use data_structure::non_linear::heap::IndexedPriorityQueue;
+///
+/// // 用 decrease-key 实现的 Dijkstra
+/// let edges = [(0, 1, 4), (0, 2, 1), (2, 1, 2), (1, 3, 1), (2, 3, 5)];
+/// let mut dist = [usize::MAX; 4];
+/// let mut queue = IndexedPriorityQueue::new(4);
+/// dist[0] = 0;
+/// queue.push(0, 0).unwrap();
+/// while let Some((u, d)) = queue.pop() {
+///     for &(_, v, w) in edges.iter().filter(|e| e.0 == u) {
+///         if d + w < dist[v] {
+///             dist[v] = d + w;
+///             if queue.contains(v) {
+///                 queue.decrease_key(v, d + w).unwrap();
+///             } else {
+///                 queue.push(v, d + w).unwrap();
+///             }
+///         }
+///     }
+/// }
+/// assert_eq!(dist, [0, 3, 1, 4]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct IndexedPriorityQueue<P> {
+    /// 堆数组，保存下标
+    heap: Vec<usize>,
+    /// 下标在堆数组中的位置
+    position: Vec<Option<usize>>,
+    /// 下标对应的优先级
+    priority: Vec<Option<P>>,
+}
+
+impl<P: Ord> IndexedPriorityQueue<P> {
+    /// 创建可容纳下标 `0..capacity` 的空队列
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            heap: Vec::with_capacity(capacity),
+            position: vec![None; capacity],
+            priority: (0..capacity).map(|_| None).collect(),
+        }
+    }
+
+    /// 下标的上限
+    pub fn capacity(&self) -> usize {
+        self.position.len()
+    }
+
+    /// 获取元素数量
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    /// 检查是否为空
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    /// 检查下标是否在队列中
+    pub fn contains(&self, index: usize) -> bool {
+        self.position.get(index).is_some_and(Option::is_some)
+    }
+
+    /// 查询下标对应的优先级
+    pub fn priority(&self, index: usize) -> Option<&P> {
+        self.priority.get(index)?.as_ref()
+    }
+
+    /// 插入下标及其优先级
+    ///
+    /// # 返回值
+    /// 下标越界或已在队列中时返回错误
+    pub fn push(&mut self, index: usize, priority: P) -> Result<(), &'static str> {
+        if index >= self.capacity() {
+            return Err("下标越界");
+        }
+        if self.contains(index) {
+            return Err("下标已在队列中");
+        }
+        self.position[index] = Some(self.heap.len());
+        self.priority[index] = Some(priority);
+        self.heap.push(index);
+        self.sift_up(self.heap.len() - 1);
+        Ok(())
+    }
+
+    /// 查看优先级最小的下标及其优先级
+    pub fn peek(&self) -> Option<(usize, &P)> {
+        let &index = self.heap.first()?;
+        Some((index, self.priority[index].as_ref().unwrap()))
+    }
+
+    /// 弹出优先级最小的下标及其优先级
+    pub fn pop(&mut self) -> Option<(usize, P)> {
+        let &index = self.heap.first()?;
+        self.remove(index).map(|priority| (index, priority))
+    }
+
+    /// 把下标的优先级降低为 `priority`
+    ///
+    /// # 返回值
+    /// 下标不在队列中，或新优先级大于当前优先级时返回错误
+    pub fn decrease_key(&mut self, index: usize, priority: P) -> Result<(), &'static str> {
+        match self.priority(index) {
+            None => Err("下标不在队列中"),
+            Some(current) if priority > *current => Err("新优先级大于当前优先级"),
+            Some(_) => {
+                self.priority[index] = Some(priority);
+                self.sift_up(self.position[index].unwrap());
+                Ok(())
+            }
+        }
+    }
+
+    /// 把下标的优先级修改为 `priority`，可以升高也可以降低
+    ///
+    /// # 返回值
+    /// 成功时返回旧的优先级，下标不在队列中时返回错误
+    pub fn change_priority(&mut self, index: usize, priority: P) -> Result<P, &'static str> {
+        if !self.contains(index) {
+            return Err("下标不在队列中");
+        }
+        let old = self.priority[index].replace(priority).unwrap();
+        let position = self.position[index].unwrap();
+        self.sift_up(position);
+        self.sift_down(self.position[index].unwrap());
+        Ok(old)
+    }
+
+    /// 从队列中删除下标
+    ///
+    /// # 返回值
+    /// 下标在队列中时返回 `Some(优先级)`，否则返回 `None`
+    pub fn remove(&mut self, index: usize) -> Option<P> {
+        let position = (*self.position.get(index)?)?;
+        let last = self.heap.len() - 1;
+        self.swap(position, last);
+        self.heap.pop();
+        self.position[index] = None;
+        if position < self.heap.len() {
+            self.sift_up(position);
+            self.sift_down(self.position[self.heap[position]].unwrap());
+        }
+        self.priority[index].take()
+    }
+
+    fn less(&self, a: usize, b: usize) -> bool {
+        self.priority[self.heap[a]] < self.priority[self.heap[b]]
+    }
+
+    /// 交换堆数组中的两个位置，同时维护位置表
+    fn swap(&mut self, a: usize, b: usize) {
+        self.heap.swap(a, b);
+        self.position[self.heap[a]] = Some(a);
+        self.position[self.heap[b]] = Some(b);
+    }
+
+    fn sift_up(&mut self, mut i: usize) {
+        while i > 0 && self.less(i, (i - 1) / 2) {
+            self.swap(i, (i - 1) / 2);
+            i = (i - 1) / 2;
+        }
+    }
+
+    fn sift_down(&mut self, mut i: usize) {
+        loop {
+            let mut top = i;
+            for child in [2 * i + 1, 2 * i + 2] {
+                if child < self.heap.len() && self.less(child, top) {
+                    top = child;
+                }
+            }
+            if top == i {
+                break;
+            }
+            self.swap(i, top);
+            i = top;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::binomial::BinomialHeap;
+    use super::dary::DaryHeap;
+    use super::fibonacci::FibonacciHeap;
+    use super::leftist::LeftistHeap;
+    use super::pairing::PairingHeap;
+    use super::*;
+
+    /// 用 decrease-key 实现的 Dijkstra，堆的实现可以任意替换
+    fn dijkstra<Q: PriorityQueue<(usize, usize)>>(
+        mut queue: Q,
+        adj: &[Vec<(usize, usize)>],
+    ) -> Vec<usize> {
+        let mut dist = vec![usize::MAX; adj.len()];
+        let mut handles = vec![None; adj.len()];
+        dist[0] = 0;
+        handles[0] = Some(queue.push((0, 0)));
+        while let Some((d, u)) = queue.pop() {
+            for &(v, w) in &adj[u] {
+                if d + w >= dist[v] {
+                    continue;
+                }
+                dist[v] = d + w;
+                match handles[v] {
+                    Some(handle) if queue.get(handle).is_some() => {
+                        queue.decrease_key(handle, (d + w, v)).unwrap()
+                    }
+                    _ => handles[v] = Some(queue.push((d + w, v))),
+                }
+            }
+        }
+        dist
+    }
+
+    /// 插入、合并、decrease-key 后按序弹出
+    fn meld_and_drain<Q: PriorityQueue<i32>>(mut a: Q, mut b: Q) {
+        let a_handles: Vec<Handle> = (0..30).map(|i| a.push(i * 7 % 30 + 100)).collect();
+        let b_handles: Vec<Handle> = (0..30).map(|i| b.push(i * 11 % 30 + 200)).collect();
+        assert_eq!(a.pop(), Some(100));
+        let offset = a.meld(b);
+        assert_eq!(a.len(), 59);
+        a.decrease_key(b_handles[3] + offset, 5).unwrap();
+        a.decrease_key(a_handles[4], 6).unwrap();
+        assert_eq!(a.decrease_key(a_handles[0], 1), Err("句柄已失效"));
+        assert_eq!(a.decrease_key(a_handles[5], 1000), Err("新值大于当前值"));
+        let mut sorted = Vec::new();
+        while let Some(value) = a.pop() {
+            sorted.push(value);
+        }
+        assert_eq!(sorted.len(), 59);
+        assert_eq!(&sorted[..2], &[5, 6]);
+        assert!(sorted.windows(2).all(|pair| pair[0] <= pair[1]));
+        assert!(a.is_empty());
+    }
+
+    #[test]
+    fn test_priority_queues() {
+        // 4x4 网格，只有向右和向下的边，权值由顶点编号生成
+        let mut adj = vec![Vec::new(); 16];
+        for (v, edges) in adj.iter_mut().enumerate() {
+            if v % 4 < 3 {
+                edges.push((v + 1, v % 5 + 1));
+            }
+            if v < 12 {
+                edges.push((v + 4, (v * 3) % 7 + 1));
+            }
+        }
+        let expected = dijkstra(DaryHeap::new(2), &adj);
+        assert_eq!(dijkstra(DaryHeap::new(4), &adj), expected);
+        assert_eq!(dijkstra(LeftistHeap::new(), &adj), expected);
+        assert_eq!(dijkstra(BinomialHeap::new(), &adj), expected);
+        assert_eq!(dijkstra(PairingHeap::new(), &adj), expected);
+        assert_eq!(dijkstra(FibonacciHeap::new(), &adj), expected);
+
+        meld_and_drain(DaryHeap::new(3), DaryHeap::new(3));
+        meld_and_drain(LeftistHeap::new(), LeftistHeap::new());
+        meld_and_drain(BinomialHeap::new(), BinomialHeap::new());
+        meld_and_drain(PairingHeap::new(), PairingHeap::new());
+        meld_and_drain(FibonacciHeap::new(), FibonacciHeap::new());
+    }
+
+    #[test]
+    fn test_binary_heap() {
+        let values = vec![5, 3, 8, 1, 9, 2, 7, 3];
+        let mut sorted = values.clone();
+        sorted.sort();
+
+        let mut min = BinaryHeap::min_heap();
+        min.extend(values.iter().copied());
+        let mut popped = Vec::new();
+        while let Some(value) = min.pop() {
+            popped.push(value);
+        }
+        assert_eq!(popped, sorted);
+
+        let max = BinaryHeap::from_vec(values.clone(), HeapKind::Max);
+        assert_eq!(max.peek(), Some(&9));
+        assert_eq!(max.len(), 8);
+        assert_eq!(max.into_sorted_vec(), sorted);
+        let min = BinaryHeap::from_vec(values, HeapKind::Min);
+        assert_eq!(min.peek(), Some(&1));
+        assert_eq!(min.into_sorted_vec(), sorted);
+        assert_eq!(BinaryHeap::<i32>::max_heap().pop(), None);
+    }
+
+    #[test]
+    fn test_indexed_priority_queue() {
+        let mut queue = IndexedPriorityQueue::new(6);
+        for (index, priority) in [(0, 50), (1, 30), (2, 40), (3, 10), (4, 20)] {
+            queue.push(index, priority).unwrap();
+        }
+        assert_eq!(queue.push(3, 0), Err("下标已在队列中"));
+        assert_eq!(queue.push(6, 0), Err("下标越界"));
+        assert_eq!(queue.peek(), Some((3, &10)));
+
+        queue.decrease_key(0, 5).unwrap();
+        assert_eq!(queue.decrease_key(1, 35), Err("新优先级大于当前优先级"));
+        assert_eq!(queue.decrease_key(5, 1), Err("下标不在队列中"));
+        assert_eq!(queue.change_priority(3, 60), Ok(10));
+        assert_eq!(queue.remove(2), Some(40));
+        assert_eq!(queue.remove(2), None);
+        assert!(!queue.contains(2));
+        assert_eq!(queue.priority(4), Some(&20));
+
+        let mut order = Vec::new();
+        while let Some(entry) = queue.pop() {
+            order.push(entry);
+        }
+        assert_eq!(order, vec![(0, 5), (4, 20), (1, 30), (3, 60)]);
+        assert!(queue.is_empty());
+    }
+}
@@ -0,0 +1,228 @@
+//! # 二项堆模块
+//!
+//! 二项堆由若干棵度数互不相同的二项树组成，`k` 阶二项树恰有 `2^k` 个节点，
+//! 因此各棵树的阶数对应元素个数的二进制表示。合并两个堆相当于二进制加法，
+//! 同阶的两棵树链接成高一阶的树并向上“进位”；插入、弹出、合并、decrease-key 都是 O(log n)。
+
+use std::mem;
+
+use super::{Handle, PriorityQueue};
+
+#[derive(Clone, Debug)]
+struct Node<T> {
+    value: T,
+    /// 当前保存在该节点中的元素的句柄，decrease-key 上浮时随元素一起移动
+    handle: Handle,
+    parent: Option<usize>,
+    /// 第 `i` 个子节点是 `i` 阶二项树的根
+    children: Vec<usize>,
+}
+
+/// 二项小根堆
+#[derive(Clone, Debug)]
+pub struct BinomialHeap<T> {
+    nodes: Vec<Option<Node<T>>>,
+    /// 句柄所在的节点
+    slots: Vec<Option<usize>>,
+    /// 第 `k` 项是 `k` 阶二项树的根
+    trees: Vec<Option<usize>>,
+    len: usize,
+}
+
+impl<T: Ord> BinomialHeap<T> {
+    /// 创建空堆
+    pub fn new() -> Self {
+        Self {
+            nodes: Vec::new(),
+            slots: Vec::new(),
+            trees: Vec::new(),
+            len: 0,
+        }
+    }
+
+    fn node(&self, i: usize) -> &Node<T> {
+        self.nodes[i].as_ref().unwrap()
+    }
+
+    fn node_mut(&mut self, i: usize) -> &mut Node<T> {
+        self.nodes[i].as_mut().unwrap()
+    }
+
+    /// 链接两棵同阶的二项树，返回高一阶的树的根
+    fn link(&mut self, a: usize, b: usize) -> usize {
+        let (parent, child) = if self.node(b).value < self.node(a).value {
+            (b, a)
+        } else {
+            (a, b)
+        };
+        self.node_mut(child).parent = Some(parent);
+        self.node_mut(parent).children.push(child);
+        parent
+    }
+
+    /// 按二进制加法把另一组按阶排列的二项树并入
+    fn add_trees(&mut self, other: Vec<Option<usize>>) {
+        let mut trees = mem::take(&mut self.trees);
+        let order = trees.len().max(other.len());
+        trees.resize(order, None);
+        let mut carry = None;
+        for (k, other) in other
+            .into_iter()
+            .chain(std::iter::repeat(None))
+            .take(order)
+            .enumerate()
+        {
+            let operands: Vec<usize> = [trees[k], other, carry].into_iter().flatten().collect();
+            (trees[k], carry) = match operands[..] {
+                [] => (None, None),
+                [a] => (Some(a), None),
+                [a, b] => (None, Some(self.link(a, b))),
+                [a, b, c] => (Some(c), Some(self.link(a, b))),
+                _ => unreachable!(),
+            };
+        }
+        trees.push(carry);
+        while trees.last() == Some(&None) {
+            trees.pop();
+        }
+        self.trees = trees;
+    }
+
+    /// 最小元素所在的树的阶数
+    fn min_order(&self) -> Option<usize> {
+        self.trees
+            .iter()
+            .enumerate()
+            .filter_map(|(k, root)| Some((k, (*root)?)))
+            .min_by(|(_, a), (_, b)| self.node(*a).value.cmp(&self.node(*b).value))
+            .map(|(k, _)| k)
+    }
+}
+
+impl<T: Ord> Default for BinomialHeap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord> PriorityQueue<T> for BinomialHeap<T> {
+    fn push(&mut self, value: T) -> Handle {
+        let handle = self.slots.len();
+        self.nodes.push(Some(Node {
+            value,
+            handle,
+            parent: None,
+            children: Vec::new(),
+        }));
+        self.slots.push(Some(self.nodes.len() - 1));
+        self.add_trees(vec![self.slots[handle]]);
+        self.len += 1;
+        handle
+    }
+
+    fn peek(&self) -> Option<&T> {
+        let root = self.trees[self.min_order()?].unwrap();
+        Some(&self.node(root).value)
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        let order = self.min_order()?;
+        let root = self.trees[order].take().unwrap();
+        let node = self.nodes[root].take().unwrap();
+        self.slots[node.handle] = None;
+        for &child in &node.children {
+            self.node_mut(child).parent = None;
+        }
+        while self.trees.last() == Some(&None) {
+            self.trees.pop();
+        }
+        // 根的子树恰好是 0 到 order - 1 阶的二项树
+        self.add_trees(node.children.into_iter().map(Some).collect());
+        self.len -= 1;
+        Some(node.value)
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn get(&self, handle: Handle) -> Option<&T> {
+        let node = (*self.slots.get(handle)?)?;
+        Some(&self.node(node).value)
+    }
+
+    /// 元素沿父节点上浮，与父节点交换元素及句柄
+    fn decrease_key(&mut self, handle: Handle, value: T) -> Result<(), &'static str> {
+        match self.get(handle) {
+            None => return Err("句柄已失效"),
+            Some(current) if value > *current => return Err("新值大于当前值"),
+            Some(_) => {}
+        }
+        let mut i = self.slots[handle].unwrap();
+        self.node_mut(i).value = value;
+        while let Some(parent) = self.node(i).parent {
+            if self.node(parent).value <= self.node(i).value {
+                break;
+            }
+            let mut node = self.nodes[i].take().unwrap();
+            let above = self.nodes[parent].as_mut().unwrap();
+            mem::swap(&mut node.value, &mut above.value);
+            mem::swap(&mut node.handle, &mut above.handle);
+            self.slots[above.handle] = Some(parent);
+            self.slots[node.handle] = Some(i);
+            self.nodes[i] = Some(node);
+            i = parent;
+        }
+        Ok(())
+    }
+
+    fn meld(&mut self, other: Self) -> usize {
+        // 节点表与句柄表总是同步增长，两者的偏移量相同
+        let offset = self.nodes.len();
+        self.nodes.extend(other.nodes.into_iter().map(|node| {
+            node.map(|node| Node {
+                handle: node.handle + offset,
+                parent: node.parent.map(|i| i + offset),
+                children: node.children.iter().map(|i| i + offset).collect(),
+                ..node
+            })
+        }));
+        self.slots
+            .extend(other.slots.into_iter().map(|slot| slot.map(|i| i + offset)));
+        self.add_trees(
+            other
+                .trees
+                .into_iter()
+                .map(|root| root.map(|i| i + offset))
+                .collect(),
+        );
+        self.len += other.len;
+        offset
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_binomial_heap() {
+        let mut heap = BinomialHeap::new();
+        let handles: Vec<Handle> = (0..13).map(|i| heap.push(i * 5 % 13 + 100)).collect();
+        // 13 = 0b1101：0、2、3 阶各一棵树
+        let orders: Vec<bool> = heap.trees.iter().map(Option::is_some).collect();
+        assert_eq!(orders, vec![true, false, true, true]);
+
+        // 降为最小值的元素会上浮到根
+        heap.decrease_key(handles[12], 0).unwrap();
+        assert_eq!(heap.peek(), Some(&0));
+        assert_eq!(heap.get(handles[12]), Some(&0));
+        for &handle in &handles {
+            assert!(heap.get(handle).is_some());
+        }
+        assert_eq!(heap.pop(), Some(0));
+        let orders: Vec<bool> = heap.trees.iter().map(Option::is_some).collect();
+        assert_eq!(orders, vec![false, false, true, true]);
+        assert_eq!(heap.get(handles[12]), None);
+    }
+}
@@ -0,0 +1,167 @@
+//! # d 叉堆模块
+//!
+//! 每个节点有 `d` 个子节点的数组堆。`d` 越大树越矮，decrease-key 上浮更快，
+//! 但弹出时下沉需要比较更多子节点，适合 decrease-key 远多于弹出的场景（如稠密图的 Dijkstra）。
+
+use super::{Handle, PriorityQueue};
+
+/// d 叉小根堆
+#[derive(Clone, Debug)]
+pub struct DaryHeap<T> {
+    arity: usize,
+    /// 堆数组，保存句柄
+    heap: Vec<Handle>,
+    /// 句柄在堆数组中的位置
+    position: Vec<Option<usize>>,
+    /// 句柄对应的元素
+    values: Vec<Option<T>>,
+}
+
+impl<T: Ord> DaryHeap<T> {
+    /// 创建空堆
+    ///
+    /// # 参数
+    /// * `arity` - 每个节点的子节点数 `d`，至少为 2
+    ///
+    /// # Panics
+    /// `arity < 2` 时 panic
+    pub fn new(arity: usize) -> Self {
+        assert!(arity >= 2, "d 叉堆的 d 至少为 2");
+        Self {
+            arity,
+            heap: Vec::new(),
+            position: Vec::new(),
+            values: Vec::new(),
+        }
+    }
+
+    /// 每个节点的子节点数
+    pub fn arity(&self) -> usize {
+        self.arity
+    }
+
+    fn less(&self, a: usize, b: usize) -> bool {
+        self.values[self.heap[a]] < self.values[self.heap[b]]
+    }
+
+    /// 交换堆数组中的两个位置，同时维护位置表
+    fn swap(&mut self, a: usize, b: usize) {
+        self.heap.swap(a, b);
+        self.position[self.heap[a]] = Some(a);
+        self.position[self.heap[b]] = Some(b);
+    }
+
+    fn sift_up(&mut self, mut i: usize) {
+        while i > 0 {
+            let parent = (i - 1) / self.arity;
+            if !self.less(i, parent) {
+                break;
+            }
+            self.swap(i, parent);
+            i = parent;
+        }
+    }
+
+    fn sift_down(&mut self, mut i: usize) {
+        loop {
+            let first = i * self.arity + 1;
+            let last = (first + self.arity).min(self.heap.len());
+            let mut top = i;
+            for child in first..last {
+                if self.less(child, top) {
+                    top = child;
+                }
+            }
+            if top == i {
+                break;
+            }
+            self.swap(i, top);
+            i = top;
+        }
+    }
+}
+
+impl<T: Ord> PriorityQueue<T> for DaryHeap<T> {
+    fn push(&mut self, value: T) -> Handle {
+        let handle = self.values.len();
+        self.values.push(Some(value));
+        self.position.push(Some(self.heap.len()));
+        self.heap.push(handle);
+        self.sift_up(self.heap.len() - 1);
+        handle
+    }
+
+    fn peek(&self) -> Option<&T> {
+        self.values[*self.heap.first()?].as_ref()
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        let &handle = self.heap.first()?;
+        let last = self.heap.len() - 1;
+        self.swap(0, last);
+        self.heap.pop();
+        self.position[handle] = None;
+        if !self.heap.is_empty() {
+            self.sift_down(0);
+        }
+        self.values[handle].take()
+    }
+
+    fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    fn get(&self, handle: Handle) -> Option<&T> {
+        self.values.get(handle)?.as_ref()
+    }
+
+    fn decrease_key(&mut self, handle: Handle, value: T) -> Result<(), &'static str> {
+        match self.get(handle) {
+            None => Err("句柄已失效"),
+            Some(current) if value > *current => Err("新值大于当前值"),
+            Some(_) => {
+                self.values[handle] = Some(value);
+                self.sift_up(self.position[handle].unwrap());
+                Ok(())
+            }
+        }
+    }
+
+    /// 把两个堆数组拼接后重新建堆，时间复杂度 O(n + m)
+    fn meld(&mut self, other: Self) -> usize {
+        let offset = self.values.len();
+        self.values.extend(other.values);
+        self.position.extend(other.position);
+        for handle in other.heap {
+            self.position[handle + offset] = Some(self.heap.len());
+            self.heap.push(handle + offset);
+        }
+        for i in (0..self.heap.len()).rev() {
+            self.sift_down(i);
+        }
+        offset
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dary_heap() {
+        for arity in 2..6 {
+            let mut heap = DaryHeap::new(arity);
+            let handles: Vec<Handle> = (0..50).map(|i| heap.push(i * 17 % 50 + 100)).collect();
+            for (i, &handle) in handles.iter().enumerate().step_by(5) {
+                heap.decrease_key(handle, i as i32).unwrap();
+            }
+            assert_eq!(heap.decrease_key(handles[0], 1000), Err("新值大于当前值"));
+            let mut previous = i32::MIN;
+            while let Some(value) = heap.pop() {
+                assert!(previous <= value);
+                previous = value;
+            }
+            assert_eq!(heap.get(handles[1]), None);
+        }
+    }
+}
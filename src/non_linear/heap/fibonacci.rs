@@ -0,0 +1,261 @@
+//! # 斐波那契堆模块
+//!
+//! 斐波那契堆把插入、合并都推迟为向根表追加树，只在弹出时按度数合并同度数的树。
+//! decrease-key 把节点剪到根表，并对失去第二个子节点的祖先做级联剪切。每个节点记录自己在
+//! 父节点子表中的下标，剪切时用`swap_remove`摘除，不必扫描兄弟节点，
+//! 使插入、合并、decrease-key 的均摊复杂度为 O(1)，弹出为 O(log n)。
+
+use super::{Handle, PriorityQueue};
+
+#[derive(Clone, Debug)]
+struct Node<T> {
+    value: T,
+    parent: Option<usize>,
+    children: Vec<usize>,
+    /// 在父节点`children`中的下标，剪切时据此 O(1) 摘除；根节点上无意义
+    position: usize,
+    /// 成为非根节点后是否已经失去过一个子节点
+    marked: bool,
+}
+
+/// 斐波那契小根堆
+#[derive(Clone, Debug)]
+pub struct FibonacciHeap<T> {
+    nodes: Vec<Option<Node<T>>>,
+    roots: Vec<usize>,
+    min: Option<usize>,
+    len: usize,
+}
+
+impl<T: Ord> FibonacciHeap<T> {
+    /// 创建空堆
+    pub fn new() -> Self {
+        Self {
+            nodes: Vec::new(),
+            roots: Vec::new(),
+            min: None,
+            len: 0,
+        }
+    }
+
+    fn node(&self, i: usize) -> &Node<T> {
+        self.nodes[i].as_ref().unwrap()
+    }
+
+    fn node_mut(&mut self, i: usize) -> &mut Node<T> {
+        self.nodes[i].as_mut().unwrap()
+    }
+
+    /// 根表中新加入的树的根比当前最小值小时更新最小值
+    fn update_min(&mut self, i: usize) {
+        if self
+            .min
+            .is_none_or(|min| self.node(i).value < self.node(min).value)
+        {
+            self.min = Some(i);
+        }
+    }
+
+    /// 链接两棵同度数的树，返回新的根
+    fn link(&mut self, a: usize, b: usize) -> usize {
+        let (parent, child) = if self.node(b).value < self.node(a).value {
+            (b, a)
+        } else {
+            (a, b)
+        };
+        let position = self.node(parent).children.len();
+        let node = self.node_mut(child);
+        node.parent = Some(parent);
+        node.position = position;
+        node.marked = false;
+        self.node_mut(parent).children.push(child);
+        parent
+    }
+
+    /// 合并根表中度数相同的树，直到所有根的度数互不相同
+    fn consolidate(&mut self) {
+        let mut by_degree: Vec<Option<usize>> = Vec::new();
+        for root in std::mem::take(&mut self.roots) {
+            let mut tree = root;
+            loop {
+                let degree = self.node(tree).children.len();
+                if by_degree.len() <= degree {
+                    by_degree.resize(degree + 1, None);
+                }
+                match by_degree[degree].take() {
+                    Some(other) => tree = self.link(tree, other),
+                    None => {
+                        by_degree[degree] = Some(tree);
+                        break;
+                    }
+                }
+            }
+        }
+        self.min = None;
+        for root in by_degree.into_iter().flatten() {
+            self.roots.push(root);
+            self.update_min(root);
+        }
+    }
+
+    /// 把节点从父节点上剪下并移到根表
+    fn cut(&mut self, i: usize, parent: usize) {
+        let position = self.node(i).position;
+        let children = &mut self.node_mut(parent).children;
+        children.swap_remove(position);
+        // 末尾的兄弟被换到了空出的位置
+        if let Some(&moved) = children.get(position) {
+            self.node_mut(moved).position = position;
+        }
+        let node = self.node_mut(i);
+        node.parent = None;
+        node.marked = false;
+        self.roots.push(i);
+    }
+}
+
+impl<T: Ord> Default for FibonacciHeap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord> PriorityQueue<T> for FibonacciHeap<T> {
+    fn push(&mut self, value: T) -> Handle {
+        let handle = self.nodes.len();
+        self.nodes.push(Some(Node {
+            value,
+            parent: None,
+            children: Vec::new(),
+            position: 0,
+            marked: false,
+        }));
+        self.roots.push(handle);
+        self.update_min(handle);
+        self.len += 1;
+        handle
+    }
+
+    fn peek(&self) -> Option<&T> {
+        Some(&self.node(self.min?).value)
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        let min = self.min?;
+        let node = self.nodes[min].take().unwrap();
+        self.roots.retain(|&root| root != min);
+        for &child in &node.children {
+            self.node_mut(child).parent = None;
+        }
+        self.roots.extend(node.children);
+        self.consolidate();
+        self.len -= 1;
+        Some(node.value)
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn get(&self, handle: Handle) -> Option<&T> {
+        Some(&self.nodes.get(handle)?.as_ref()?.value)
+    }
+
+    fn decrease_key(&mut self, handle: Handle, value: T) -> Result<(), &'static str> {
+        match self.get(handle) {
+            None => return Err("句柄已失效"),
+            Some(current) if value > *current => return Err("新值大于当前值"),
+            Some(_) => self.node_mut(handle).value = value,
+        }
+        if let Some(parent) = self.node(handle).parent
+            && self.node(handle).value < self.node(parent).value
+        {
+            self.cut(handle, parent);
+            // 级联剪切：祖先第一次失去子节点时做标记，第二次时也剪到根表
+            let mut current = parent;
+            while let Some(parent) = self.node(current).parent {
+                if !self.node(current).marked {
+                    self.node_mut(current).marked = true;
+                    break;
+                }
+                self.cut(current, parent);
+                current = parent;
+            }
+        }
+        self.update_min(handle);
+        Ok(())
+    }
+
+    fn meld(&mut self, other: Self) -> usize {
+        let offset = self.nodes.len();
+        self.nodes.extend(other.nodes.into_iter().map(|node| {
+            node.map(|node| Node {
+                parent: node.parent.map(|i| i + offset),
+                children: node.children.iter().map(|i| i + offset).collect(),
+                ..node
+            })
+        }));
+        for root in other.roots {
+            self.roots.push(root + offset);
+            self.update_min(root + offset);
+        }
+        self.len += other.len;
+        offset
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fibonacci_heap() {
+        let mut heap = FibonacciHeap::new();
+        for i in 0..64 {
+            heap.push(i + 100);
+        }
+        // 弹出后其余 63 个节点合并成度数互不相同的树
+        assert_eq!(heap.pop(), Some(100));
+        let mut degrees: Vec<usize> = heap
+            .roots
+            .iter()
+            .map(|&root| heap.node(root).children.len())
+            .collect();
+        degrees.sort();
+        assert_eq!(degrees, vec![0, 1, 2, 3, 4, 5]);
+
+        // 连续剪掉同一非根节点的两个子节点会触发级联剪切
+        let big = heap.roots.iter().copied();
+        let big = big
+            .max_by_key(|&root| heap.node(root).children.len())
+            .unwrap();
+        let child = heap.node(big).children.iter().copied();
+        let child = child
+            .max_by_key(|&child| heap.node(child).children.len())
+            .unwrap();
+        let grandchildren = heap.node(child).children.clone();
+        let roots = heap.roots.len();
+        heap.decrease_key(grandchildren[0], 1).unwrap();
+        assert!(heap.node(child).marked);
+        assert_eq!(heap.roots.len(), roots + 1);
+        heap.decrease_key(grandchildren[1], 2).unwrap();
+        assert_eq!(heap.node(child).parent, None);
+        assert_eq!(heap.roots.len(), roots + 3);
+        assert_eq!(heap.peek(), Some(&1));
+        for (i, node) in heap.nodes.iter().enumerate() {
+            if let Some(node) = node
+                && let Some(parent) = node.parent
+            {
+                assert_eq!(heap.node(parent).children[node.position], i);
+            }
+        }
+
+        let mut sorted = Vec::new();
+        while let Some(value) = heap.pop() {
+            sorted.push(value);
+        }
+        assert_eq!(sorted.len(), 63);
+        assert_eq!(&sorted[..2], &[1, 2]);
+        assert!(sorted.windows(2).all(|pair| pair[0] <= pair[1]));
+    }
+}
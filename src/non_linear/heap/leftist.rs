@@ -0,0 +1,209 @@
+//! # 左偏堆模块
+//!
+//! 左偏堆是满足堆序的二叉树，每个节点记录零路径长（到最近空子节点的距离），
+//! 且左子节点的零路径长不小于右子节点。合并只沿两棵树的右路径进行，
+//! 右路径长度不超过 O(log n)，因此合并、插入、弹出都是 O(log n)。
+
+use super::{Handle, PriorityQueue};
+
+#[derive(Clone, Debug)]
+struct Node<T> {
+    value: T,
+    left: Option<usize>,
+    right: Option<usize>,
+    parent: Option<usize>,
+    /// 零路径长，空节点为 0
+    rank: usize,
+}
+
+/// 左偏小根堆
+#[derive(Clone, Debug)]
+pub struct LeftistHeap<T> {
+    nodes: Vec<Option<Node<T>>>,
+    root: Option<usize>,
+    len: usize,
+}
+
+impl<T: Ord> LeftistHeap<T> {
+    /// 创建空堆
+    pub fn new() -> Self {
+        Self {
+            nodes: Vec::new(),
+            root: None,
+            len: 0,
+        }
+    }
+
+    fn node(&self, i: usize) -> &Node<T> {
+        self.nodes[i].as_ref().unwrap()
+    }
+
+    fn node_mut(&mut self, i: usize) -> &mut Node<T> {
+        self.nodes[i].as_mut().unwrap()
+    }
+
+    fn rank(&self, i: Option<usize>) -> usize {
+        i.map_or(0, |i| self.node(i).rank)
+    }
+
+    /// 交换子节点使左偏性质成立，并更新零路径长
+    fn fix(&mut self, i: usize) {
+        let (left, right) = (self.node(i).left, self.node(i).right);
+        if self.rank(left) < self.rank(right) {
+            let node = self.node_mut(i);
+            node.left = right;
+            node.right = left;
+        }
+        let right = self.node(i).right;
+        self.node_mut(i).rank = self.rank(right) + 1;
+    }
+
+    /// 合并两棵根节点没有父节点的子树，返回新的根
+    fn merge(&mut self, a: Option<usize>, b: Option<usize>) -> Option<usize> {
+        let (Some(a), Some(b)) = (a, b) else {
+            return a.or(b);
+        };
+        let (a, b) = if self.node(b).value < self.node(a).value {
+            (b, a)
+        } else {
+            (a, b)
+        };
+        let right = self.node(a).right;
+        let merged = self.merge(right, Some(b)).unwrap();
+        self.node_mut(merged).parent = Some(a);
+        self.node_mut(a).right = Some(merged);
+        self.fix(a);
+        Some(a)
+    }
+}
+
+impl<T: Ord> Default for LeftistHeap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord> PriorityQueue<T> for LeftistHeap<T> {
+    fn push(&mut self, value: T) -> Handle {
+        let handle = self.nodes.len();
+        self.nodes.push(Some(Node {
+            value,
+            left: None,
+            right: None,
+            parent: None,
+            rank: 1,
+        }));
+        self.root = self.merge(self.root, Some(handle));
+        self.len += 1;
+        handle
+    }
+
+    fn peek(&self) -> Option<&T> {
+        Some(&self.node(self.root?).value)
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        let root = self.nodes[self.root?].take().unwrap();
+        for child in [root.left, root.right].into_iter().flatten() {
+            self.node_mut(child).parent = None;
+        }
+        self.root = self.merge(root.left, root.right);
+        self.len -= 1;
+        Some(root.value)
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn get(&self, handle: Handle) -> Option<&T> {
+        Some(&self.nodes.get(handle)?.as_ref()?.value)
+    }
+
+    /// 把节点所在子树从父节点上剪下，修复路径上的零路径长后再与根合并
+    fn decrease_key(&mut self, handle: Handle, value: T) -> Result<(), &'static str> {
+        match self.get(handle) {
+            None => return Err("句柄已失效"),
+            Some(current) if value > *current => return Err("新值大于当前值"),
+            Some(_) => self.node_mut(handle).value = value,
+        }
+        let Some(parent) = self.node(handle).parent else {
+            return Ok(());
+        };
+        if self.node(parent).value <= self.node(handle).value {
+            return Ok(());
+        }
+        let node = self.node_mut(parent);
+        if node.left == Some(handle) {
+            node.left = None;
+        } else {
+            node.right = None;
+        }
+        self.node_mut(handle).parent = None;
+        // 剪掉子树只会让零路径长变小，沿父节点向上修复直到不再变化
+        let mut current = Some(parent);
+        while let Some(i) = current {
+            let rank = self.node(i).rank;
+            self.fix(i);
+            if self.node(i).rank == rank {
+                break;
+            }
+            current = self.node(i).parent;
+        }
+        self.root = self.merge(self.root, Some(handle));
+        Ok(())
+    }
+
+    fn meld(&mut self, other: Self) -> usize {
+        let offset = self.nodes.len();
+        let shift = |i: Option<usize>| i.map(|i| i + offset);
+        self.nodes.extend(other.nodes.into_iter().map(|node| {
+            node.map(|node| Node {
+                left: shift(node.left),
+                right: shift(node.right),
+                parent: shift(node.parent),
+                ..node
+            })
+        }));
+        self.root = self.merge(self.root, shift(other.root));
+        self.len += other.len;
+        offset
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 检查堆序与左偏性质，返回子树的零路径长
+    fn check<T: Ord>(heap: &LeftistHeap<T>, i: Option<usize>) -> usize {
+        let Some(i) = i else {
+            return 0;
+        };
+        let node = heap.node(i);
+        for child in [node.left, node.right].into_iter().flatten() {
+            assert!(heap.node(child).value >= node.value);
+            assert_eq!(heap.node(child).parent, Some(i));
+        }
+        let (left, right) = (check(heap, node.left), check(heap, node.right));
+        assert!(left >= right);
+        assert_eq!(node.rank, right + 1);
+        node.rank
+    }
+
+    #[test]
+    fn test_leftist_heap() {
+        let mut heap = LeftistHeap::new();
+        let handles: Vec<Handle> = (0..100).map(|i| heap.push(i * 37 % 100 + 100)).collect();
+        check(&heap, heap.root);
+        for (i, &handle) in handles.iter().enumerate().rev().step_by(3) {
+            heap.decrease_key(handle, i).unwrap();
+            check(&heap, heap.root);
+        }
+        for _ in 0..50 {
+            heap.pop();
+            check(&heap, heap.root);
+        }
+        assert_eq!(heap.len(), 50);
+    }
+}
@@ -0,0 +1,200 @@
+//! # 配对堆模块
+//!
+//! 配对堆是多叉的堆序树，子节点用“左孩子右兄弟”链表保存。插入、合并与 decrease-key
+//! 只需一次链接；弹出时把根的子树先从左到右两两配对，再从右到左依次合并（两趟合并），
+//! 均摊复杂度为 O(log n)。实际运行中通常是最快的可合并堆之一。
+
+use super::{Handle, PriorityQueue};
+
+#[derive(Clone, Debug)]
+struct Node<T> {
+    value: T,
+    /// 第一个子节点
+    child: Option<usize>,
+    /// 下一个兄弟
+    sibling: Option<usize>,
+    /// 上一个兄弟；是第一个子节点时为父节点
+    prev: Option<usize>,
+}
+
+/// 配对小根堆
+#[derive(Clone, Debug)]
+pub struct PairingHeap<T> {
+    nodes: Vec<Option<Node<T>>>,
+    root: Option<usize>,
+    len: usize,
+}
+
+impl<T: Ord> PairingHeap<T> {
+    /// 创建空堆
+    pub fn new() -> Self {
+        Self {
+            nodes: Vec::new(),
+            root: None,
+            len: 0,
+        }
+    }
+
+    fn node(&self, i: usize) -> &Node<T> {
+        self.nodes[i].as_ref().unwrap()
+    }
+
+    fn node_mut(&mut self, i: usize) -> &mut Node<T> {
+        self.nodes[i].as_mut().unwrap()
+    }
+
+    /// 链接两棵独立的树：根较大的一棵成为另一棵根的第一个子节点
+    fn link(&mut self, a: usize, b: usize) -> usize {
+        let (parent, child) = if self.node(b).value < self.node(a).value {
+            (b, a)
+        } else {
+            (a, b)
+        };
+        let first = self.node(parent).child;
+        if let Some(first) = first {
+            self.node_mut(first).prev = Some(child);
+        }
+        let node = self.node_mut(child);
+        node.sibling = first;
+        node.prev = Some(parent);
+        self.node_mut(parent).child = Some(child);
+        parent
+    }
+
+    fn merge(&mut self, a: Option<usize>, b: Option<usize>) -> Option<usize> {
+        match (a, b) {
+            (Some(a), Some(b)) => Some(self.link(a, b)),
+            _ => a.or(b),
+        }
+    }
+
+    /// 把节点所在子树从树中剪下
+    fn cut(&mut self, i: usize) {
+        let Node { prev, sibling, .. } = *self.node(i);
+        let prev = prev.unwrap();
+        if self.node(prev).child == Some(i) {
+            self.node_mut(prev).child = sibling;
+        } else {
+            self.node_mut(prev).sibling = sibling;
+        }
+        if let Some(sibling) = sibling {
+            self.node_mut(sibling).prev = Some(prev);
+        }
+        let node = self.node_mut(i);
+        node.prev = None;
+        node.sibling = None;
+    }
+}
+
+impl<T: Ord> Default for PairingHeap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord> PriorityQueue<T> for PairingHeap<T> {
+    fn push(&mut self, value: T) -> Handle {
+        let handle = self.nodes.len();
+        self.nodes.push(Some(Node {
+            value,
+            child: None,
+            sibling: None,
+            prev: None,
+        }));
+        self.root = self.merge(self.root, Some(handle));
+        self.len += 1;
+        handle
+    }
+
+    fn peek(&self) -> Option<&T> {
+        Some(&self.node(self.root?).value)
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        let root = self.nodes[self.root?].take().unwrap();
+        let mut children = Vec::new();
+        let mut current = root.child;
+        while let Some(child) = current {
+            let node = self.node_mut(child);
+            current = node.sibling.take();
+            node.prev = None;
+            children.push(child);
+        }
+        // 第一趟：从左到右两两配对
+        let paired: Vec<usize> = children
+            .chunks(2)
+            .map(|pair| match *pair {
+                [a, b] => self.link(a, b),
+                [a] => a,
+                _ => unreachable!(),
+            })
+            .collect();
+        // 第二趟：从右到左依次合并
+        self.root = paired
+            .into_iter()
+            .rev()
+            .reduce(|merged, tree| self.link(tree, merged));
+        self.len -= 1;
+        Some(root.value)
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn get(&self, handle: Handle) -> Option<&T> {
+        Some(&self.nodes.get(handle)?.as_ref()?.value)
+    }
+
+    fn decrease_key(&mut self, handle: Handle, value: T) -> Result<(), &'static str> {
+        match self.get(handle) {
+            None => return Err("句柄已失效"),
+            Some(current) if value > *current => return Err("新值大于当前值"),
+            Some(_) => self.node_mut(handle).value = value,
+        }
+        if self.root != Some(handle) {
+            self.cut(handle);
+            self.root = self.merge(self.root, Some(handle));
+        }
+        Ok(())
+    }
+
+    fn meld(&mut self, other: Self) -> usize {
+        let offset = self.nodes.len();
+        let shift = |i: Option<usize>| i.map(|i| i + offset);
+        self.nodes.extend(other.nodes.into_iter().map(|node| {
+            node.map(|node| Node {
+                child: shift(node.child),
+                sibling: shift(node.sibling),
+                prev: shift(node.prev),
+                ..node
+            })
+        }));
+        self.root = self.merge(self.root, shift(other.root));
+        self.len += other.len;
+        offset
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pairing_heap() {
+        let mut heap = PairingHeap::new();
+        let handles: Vec<Handle> = (0..100).map(|i| heap.push(i * 41 % 100 + 100)).collect();
+        // 先弹出一次，让树形成多层结构后再 decrease-key
+        assert_eq!(heap.pop(), Some(100));
+        for (i, &handle) in handles.iter().enumerate().skip(1).step_by(7) {
+            heap.decrease_key(handle, i).unwrap();
+        }
+        let mut sorted = Vec::new();
+        while let Some(value) = heap.pop() {
+            sorted.push(value);
+        }
+        assert_eq!(sorted.len(), 99);
+        assert!(sorted.windows(2).all(|pair| pair[0] <= pair[1]));
+        assert_eq!(heap.decrease_key(handles[1], 0), Err("句柄已失效"));
+    }
+}
@@ -1,2 +1,8 @@
-﻿pub mod graph;
+﻿pub mod bplus_tree;
+pub mod btree;
+pub mod disjoint_set;
+pub mod fenwick_tree;
+pub mod graph;
+pub mod heap;
+pub mod segment_tree;
 pub mod tree;
\ No newline at end of file
@@ -0,0 +1,613 @@
+//! # 线段树模块
+//!
+//! 线段树把数组划分为一棵完全二叉树，每个节点保存一个区间的聚合值，区间查询与修改都是 O(log n)。
+//! 聚合方式由 [`Monoid`]（幺半群：满足结合律的二元运算及其单位元）描述，
+//! 因此同一套实现可以用于区间和、区间最值等任意可结合的统计。
+//!
+//! - [`SegmentTree`]：单点修改、区间查询，自底向上非递归实现；
+//! - [`LazySegmentTree`]：区间修改、区间查询。修改暂存在节点的懒标记上，
+//!   访问子节点前才下推，修改的作用方式由 [`LazyMonoid`] 描述。
+//!
+//! 内置的幺半群有 [`Sum`]、[`Min`]、[`Max`]，以及带区间修改的 [`AddSum`]、[`AddMin`]、[`AssignSum`]。
+//! 区间参数与切片下标一样接受 `a..b`、`..b`、`a..=b` 等写法，越界时 panic。
+//!
+//! ## 示例
+//! ```
+//! use data_structure::non_linear::segment_tree::{AddSum, LazySegmentTree, Min, SegmentTree};
+//!
+//! let mut mins = SegmentTree::<Min<i32>>::from(vec![Some(5), Some(2), Some(8), Some(6)]);
+//! assert_eq!(mins.query(2..), Some(6));
+//! mins.set(3, Some(1));
+//! assert_eq!(mins.query(..), Some(1));
+//!
+//! let mut sums = LazySegmentTree::<AddSum<i64>>::from(vec![1, 2, 3, 4, 5]);
+//! sums.update(1..4, &10);
+//! assert_eq!(sums.query(..), 45);
+//! assert_eq!(sums.get(3), 14);
+//! ```
+
+use std::marker::PhantomData;
+use std::ops::{Add, Bound, RangeBounds};
+
+/// 幺半群：满足结合律的二元运算及其单位元
+pub trait Monoid {
+    /// 聚合值的类型
+    type Value: Clone;
+
+    /// 单位元，与任何值结合都得到该值本身
+    fn identity() -> Self::Value;
+
+    /// 结合两个相邻区间的聚合值，`a`在左、`b`在右
+    fn combine(a: &Self::Value, b: &Self::Value) -> Self::Value;
+}
+
+/// 支持区间修改的幺半群
+///
+/// 修改必须对聚合值“可分配”：对整个区间施加修改后的聚合值，只依赖原聚合值、修改和区间长度。
+pub trait LazyMonoid: Monoid {
+    /// 修改的类型
+    type Update: Clone;
+
+    /// 对长度为`len`、聚合值为`value`的区间施加修改
+    fn apply(update: &Self::Update, value: &Self::Value, len: usize) -> Self::Value;
+
+    /// 合并两次修改：先施加`older`，再施加`newer`
+    fn compose(newer: &Self::Update, older: &Self::Update) -> Self::Update;
+}
+
+/// 计算`n`个`x`相加，只需要加法，对整数和浮点数都适用
+pub(crate) fn times<T: Add<Output = T> + Copy + Default>(x: T, mut n: usize) -> T {
+    let (mut result, mut power) = (T::default(), x);
+    loop {
+        if n & 1 == 1 {
+            result = result + power;
+        }
+        n >>= 1;
+        // 不再需要更高的位时不能再翻倍，否则结果不溢出时中间值也可能溢出
+        if n == 0 {
+            return result;
+        }
+        power = power + power;
+    }
+}
+
+/// 把区间参数转换为`[start, end)`，越界时panic
+pub(crate) fn to_range(range: impl RangeBounds<usize>, len: usize) -> (usize, usize) {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start + 1,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end + 1,
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+    assert!(
+        start <= end && end <= len,
+        "区间 {start}..{end} 超出长度 {len}"
+    );
+    (start, end)
+}
+
+/// 区间和，单位元为`T::default()`
+pub struct Sum<T>(PhantomData<T>);
+
+impl<T: Add<Output = T> + Copy + Default> Monoid for Sum<T> {
+    type Value = T;
+
+    fn identity() -> T {
+        T::default()
+    }
+
+    fn combine(a: &T, b: &T) -> T {
+        *a + *b
+    }
+}
+
+/// 区间最小值，用`None`表示正无穷（空区间的结果）
+pub struct Min<T>(PhantomData<T>);
+
+impl<T: Ord + Copy> Monoid for Min<T> {
+    type Value = Option<T>;
+
+    fn identity() -> Option<T> {
+        None
+    }
+
+    fn combine(a: &Option<T>, b: &Option<T>) -> Option<T> {
+        match (a, b) {
+            (Some(a), Some(b)) => Some(*a.min(b)),
+            _ => a.or(*b),
+        }
+    }
+}
+
+/// 区间最大值，用`None`表示负无穷（空区间的结果）
+pub struct Max<T>(PhantomData<T>);
+
+impl<T: Ord + Copy> Monoid for Max<T> {
+    type Value = Option<T>;
+
+    fn identity() -> Option<T> {
+        None
+    }
+
+    fn combine(a: &Option<T>, b: &Option<T>) -> Option<T> {
+        *a.max(b)
+    }
+}
+
+/// 区间加、区间求和
+pub struct AddSum<T>(PhantomData<T>);
+
+impl<T: Add<Output = T> + Copy + Default> Monoid for AddSum<T> {
+    type Value = T;
+
+    fn identity() -> T {
+        T::default()
+    }
+
+    fn combine(a: &T, b: &T) -> T {
+        *a + *b
+    }
+}
+
+impl<T: Add<Output = T> + Copy + Default> LazyMonoid for AddSum<T> {
+    type Update = T;
+
+    fn apply(update: &T, value: &T, len: usize) -> T {
+        *value + times(*update, len)
+    }
+
+    fn compose(newer: &T, older: &T) -> T {
+        *newer + *older
+    }
+}
+
+/// 区间加、区间最小值
+pub struct AddMin<T>(PhantomData<T>);
+
+impl<T: Ord + Copy> Monoid for AddMin<T> {
+    type Value = Option<T>;
+
+    fn identity() -> Option<T> {
+        None
+    }
+
+    fn combine(a: &Option<T>, b: &Option<T>) -> Option<T> {
+        Min::<T>::combine(a, b)
+    }
+}
+
+impl<T: Ord + Add<Output = T> + Copy> LazyMonoid for AddMin<T> {
+    type Update = T;
+
+    fn apply(update: &T, value: &Option<T>, _len: usize) -> Option<T> {
+        value.map(|value| value + *update)
+    }
+
+    fn compose(newer: &T, older: &T) -> T {
+        *newer + *older
+    }
+}
+
+/// 区间赋值、区间求和
+pub struct AssignSum<T>(PhantomData<T>);
+
+impl<T: Add<Output = T> + Copy + Default> Monoid for AssignSum<T> {
+    type Value = T;
+
+    fn identity() -> T {
+        T::default()
+    }
+
+    fn combine(a: &T, b: &T) -> T {
+        *a + *b
+    }
+}
+
+impl<T: Add<Output = T> + Copy + Default> LazyMonoid for AssignSum<T> {
+    type Update = T;
+
+    fn apply(update: &T, _value: &T, len: usize) -> T {
+        times(*update, len)
+    }
+
+    fn compose(newer: &T, _older: &T) -> T {
+        *newer
+    }
+}
+
+/// 单点修改、区间查询的线段树
+///
+/// 用长度为`2 * size`的数组存储完全二叉树，`size`是不小于元素个数的2的幂，
+/// 节点`i`的子节点为`2i`和`2i + 1`，叶子从下标`size`开始。
+pub struct SegmentTree<M: Monoid> {
+    len: usize,
+    size: usize,
+    nodes: Vec<M::Value>,
+}
+
+impl<M: Monoid> SegmentTree<M> {
+    /// 创建长度为`len`、所有元素都是单位元的线段树
+    pub fn new(len: usize) -> Self {
+        Self::from(vec![M::identity(); len])
+    }
+
+    /// 元素个数
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// 检查是否为空
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// 获取第`i`个元素
+    ///
+    /// # Panics
+    /// `i`越界时panic
+    pub fn get(&self, i: usize) -> &M::Value {
+        assert!(i < self.len, "下标 {i} 超出长度 {}", self.len);
+        &self.nodes[self.size + i]
+    }
+
+    /// 修改第`i`个元素，并更新它的所有祖先
+    ///
+    /// # Panics
+    /// `i`越界时panic
+    pub fn set(&mut self, i: usize, value: M::Value) {
+        assert!(i < self.len, "下标 {i} 超出长度 {}", self.len);
+        let mut i = self.size + i;
+        self.nodes[i] = value;
+        while i > 1 {
+            i /= 2;
+            self.nodes[i] = M::combine(&self.nodes[2 * i], &self.nodes[2 * i + 1]);
+        }
+    }
+
+    /// 区间查询
+    ///
+    /// # 参数
+    /// * `range` - 查询的区间
+    ///
+    /// # 返回值
+    /// 区间内元素按顺序结合的结果，空区间返回单位元
+    ///
+    /// # Panics
+    /// 区间越界时panic
+    pub fn query(&self, range: impl RangeBounds<usize>) -> M::Value {
+        let (start, end) = to_range(range, self.len);
+        let (mut l, mut r) = (start + self.size, end + self.size);
+        // 左右两侧分别累积，保持结合顺序，不要求运算满足交换律
+        let (mut left, mut right) = (M::identity(), M::identity());
+        while l < r {
+            if l & 1 == 1 {
+                left = M::combine(&left, &self.nodes[l]);
+                l += 1;
+            }
+            if r & 1 == 1 {
+                r -= 1;
+                right = M::combine(&self.nodes[r], &right);
+            }
+            l /= 2;
+            r /= 2;
+        }
+        M::combine(&left, &right)
+    }
+}
+
+impl<M: Monoid> From<Vec<M::Value>> for SegmentTree<M> {
+    /// 由初始数组自底向上建树，O(n)
+    fn from(values: Vec<M::Value>) -> Self {
+        let len = values.len();
+        let size = len.next_power_of_two();
+        let mut nodes = vec![M::identity(); size];
+        nodes.extend(values);
+        nodes.resize(2 * size, M::identity());
+        for i in (1..size).rev() {
+            nodes[i] = M::combine(&nodes[2 * i], &nodes[2 * i + 1]);
+        }
+        Self { len, size, nodes }
+    }
+}
+
+/// 区间修改、区间查询的线段树
+pub struct LazySegmentTree<M: LazyMonoid> {
+    len: usize,
+    /// 节点`1`为根，覆盖`[0, len)`，节点`i`的子节点为`2i`和`2i + 1`
+    nodes: Vec<M::Value>,
+    /// 已施加到本节点、尚未下推到子节点的修改
+    lazy: Vec<Option<M::Update>>,
+}
+
+impl<M: LazyMonoid> LazySegmentTree<M> {
+    /// 创建长度为`len`、所有元素都是单位元的线段树
+    pub fn new(len: usize) -> Self {
+        Self::from(vec![M::identity(); len])
+    }
+
+    /// 元素个数
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// 检查是否为空
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn build(&mut self, node: usize, lo: usize, hi: usize, values: &[M::Value]) {
+        if hi - lo == 1 {
+            self.nodes[node] = values[lo].clone();
+            return;
+        }
+        let mid = lo + (hi - lo) / 2;
+        self.build(2 * node, lo, mid, values);
+        self.build(2 * node + 1, mid, hi, values);
+        self.pull(node);
+    }
+
+    fn pull(&mut self, node: usize) {
+        self.nodes[node] = M::combine(&self.nodes[2 * node], &self.nodes[2 * node + 1]);
+    }
+
+    /// 对节点覆盖的整个区间施加修改，并记下懒标记
+    fn apply(&mut self, node: usize, len: usize, update: &M::Update) {
+        self.nodes[node] = M::apply(update, &self.nodes[node], len);
+        self.lazy[node] = Some(match &self.lazy[node] {
+            Some(older) => M::compose(update, older),
+            None => update.clone(),
+        });
+    }
+
+    /// 把懒标记下推到两个子节点
+    fn push(&mut self, node: usize, lo: usize, mid: usize, hi: usize) {
+        if let Some(update) = self.lazy[node].take() {
+            self.apply(2 * node, mid - lo, &update);
+            self.apply(2 * node + 1, hi - mid, &update);
+        }
+    }
+
+    fn update_at(
+        &mut self,
+        node: usize,
+        (lo, hi): (usize, usize),
+        (start, end): (usize, usize),
+        update: &M::Update,
+    ) {
+        if end <= lo || hi <= start {
+            return;
+        }
+        if start <= lo && hi <= end {
+            self.apply(node, hi - lo, update);
+            return;
+        }
+        let mid = lo + (hi - lo) / 2;
+        self.push(node, lo, mid, hi);
+        self.update_at(2 * node, (lo, mid), (start, end), update);
+        self.update_at(2 * node + 1, (mid, hi), (start, end), update);
+        self.pull(node);
+    }
+
+    fn query_at(
+        &mut self,
+        node: usize,
+        (lo, hi): (usize, usize),
+        (start, end): (usize, usize),
+    ) -> M::Value {
+        if end <= lo || hi <= start {
+            return M::identity();
+        }
+        if start <= lo && hi <= end {
+            return self.nodes[node].clone();
+        }
+        let mid = lo + (hi - lo) / 2;
+        self.push(node, lo, mid, hi);
+        let left = self.query_at(2 * node, (lo, mid), (start, end));
+        let right = self.query_at(2 * node + 1, (mid, hi), (start, end));
+        M::combine(&left, &right)
+    }
+
+    /// 区间修改
+    ///
+    /// # 参数
+    /// * `range` - 修改的区间
+    /// * `update` - 施加到区间内每个元素上的修改
+    ///
+    /// # Panics
+    /// 区间越界时panic
+    pub fn update(&mut self, range: impl RangeBounds<usize>, update: &M::Update) {
+        let range = to_range(range, self.len);
+        if range.0 < range.1 {
+            self.update_at(1, (0, self.len), range, update);
+        }
+    }
+
+    /// 区间查询
+    ///
+    /// 查询途中会下推懒标记，因此需要可变引用。
+    ///
+    /// # 参数
+    /// * `range` - 查询的区间
+    ///
+    /// # 返回值
+    /// 区间内元素按顺序结合的结果，空区间返回单位元
+    ///
+    /// # Panics
+    /// 区间越界时panic
+    pub fn query(&mut self, range: impl RangeBounds<usize>) -> M::Value {
+        let range = to_range(range, self.len);
+        if range.0 == range.1 {
+            return M::identity();
+        }
+        self.query_at(1, (0, self.len), range)
+    }
+
+    /// 获取第`i`个元素
+    ///
+    /// # Panics
+    /// `i`越界时panic
+    pub fn get(&mut self, i: usize) -> M::Value {
+        self.query(i..=i)
+    }
+
+    /// 把第`i`个元素设为`value`
+    ///
+    /// # Panics
+    /// `i`越界时panic
+    pub fn set(&mut self, i: usize, value: M::Value) {
+        assert!(i < self.len, "下标 {i} 超出长度 {}", self.len);
+        let (mut node, mut lo, mut hi) = (1, 0, self.len);
+        let mut path = Vec::new();
+        while hi - lo > 1 {
+            let mid = lo + (hi - lo) / 2;
+            self.push(node, lo, mid, hi);
+            path.push(node);
+            (node, lo, hi) = if i < mid {
+                (2 * node, lo, mid)
+            } else {
+                (2 * node + 1, mid, hi)
+            };
+        }
+        self.nodes[node] = value;
+        self.lazy[node] = None;
+        for node in path.into_iter().rev() {
+            self.pull(node);
+        }
+    }
+}
+
+impl<M: LazyMonoid> From<Vec<M::Value>> for LazySegmentTree<M> {
+    /// 由初始数组递归建树，O(n)
+    fn from(values: Vec<M::Value>) -> Self {
+        let len = values.len();
+        let capacity = 4 * len.max(1);
+        let mut tree = Self {
+            len,
+            nodes: vec![M::identity(); capacity],
+            lazy: vec![None; capacity],
+        };
+        if len > 0 {
+            tree.build(1, 0, len, &values);
+        }
+        tree
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 简单的线性同余伪随机数
+    fn random(seed: &mut u64) -> usize {
+        *seed = seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (*seed >> 33) as usize
+    }
+
+    #[test]
+    fn test_segment_tree() {
+        let values = vec![5, 3, 7, 9, 6, 4, 1, 2, 8];
+        let mut sums = SegmentTree::<Sum<i32>>::from(values.clone());
+        let mut maxs =
+            SegmentTree::<Max<i32>>::from(values.iter().copied().map(Some).collect::<Vec<_>>());
+        assert_eq!(sums.query(..), 45);
+        assert_eq!(sums.query(2..5), 22);
+        assert_eq!(sums.query(4..4), 0);
+        assert_eq!(maxs.query(4..=7), Some(6));
+        assert_eq!(maxs.query(0..0), None);
+        sums.set(8, -8);
+        maxs.set(5, Some(10));
+        assert_eq!(sums.query(7..), -6);
+        assert_eq!(maxs.query(..), Some(10));
+        assert_eq!(*sums.get(8), -8);
+
+        // 字符串拼接不满足交换律，检查结合顺序
+        struct Concat;
+        impl Monoid for Concat {
+            type Value = String;
+            fn identity() -> String {
+                String::new()
+            }
+            fn combine(a: &String, b: &String) -> String {
+                a.clone() + b
+            }
+        }
+        let words: Vec<String> = "abcdefg".chars().map(String::from).collect();
+        let concat = SegmentTree::<Concat>::from(words);
+        assert_eq!(concat.query(1..6), "bcdef");
+    }
+
+    #[test]
+    fn test_lazy_segment_tree() {
+        let mut seed = 7;
+        let n = 37;
+        let mut naive: Vec<i64> = (0..n).map(|_| random(&mut seed) as i64 % 100).collect();
+        let mut sums = LazySegmentTree::<AddSum<i64>>::from(naive.clone());
+        let mut mins = LazySegmentTree::<AddMin<i64>>::from(
+            naive.iter().copied().map(Some).collect::<Vec<_>>(),
+        );
+        let mut assigned = LazySegmentTree::<AssignSum<i64>>::from(naive.clone());
+        let mut naive_assigned = naive.clone();
+        for _ in 0..500 {
+            let (a, b) = (random(&mut seed) % (n + 1), random(&mut seed) % (n + 1));
+            let (start, end) = (a.min(b), a.max(b));
+            let delta = random(&mut seed) as i64 % 21 - 10;
+            match random(&mut seed) % 4 {
+                0 => {
+                    sums.update(start..end, &delta);
+                    mins.update(start..end, &delta);
+                    naive[start..end].iter_mut().for_each(|x| *x += delta);
+                }
+                1 => {
+                    assigned.update(start..end, &delta);
+                    naive_assigned[start..end].fill(delta);
+                }
+                2 if start < n => {
+                    sums.set(start, delta);
+                    mins.set(start, Some(delta));
+                    naive[start] = delta;
+                }
+                _ => {
+                    assert_eq!(
+                        sums.query(start..end),
+                        naive[start..end].iter().sum::<i64>()
+                    );
+                    assert_eq!(
+                        mins.query(start..end),
+                        naive[start..end].iter().copied().min()
+                    );
+                    assert_eq!(
+                        assigned.query(start..end),
+                        naive_assigned[start..end].iter().sum::<i64>()
+                    );
+                }
+            }
+        }
+        for (i, &value) in naive.iter().enumerate() {
+            assert_eq!(sums.get(i), value);
+        }
+    }
+
+    #[test]
+    fn test_times_near_overflow() {
+        let half = i32::MAX / 2;
+        assert_eq!(times(half, 2), i32::MAX - 1);
+        assert_eq!(times(i32::MAX, 1), i32::MAX);
+        assert_eq!(times(half, 0), 0);
+
+        let mut assigned = LazySegmentTree::<AssignSum<i32>>::from(vec![0, 0]);
+        assigned.update(0..1, &2_000_000_000);
+        assert_eq!(assigned.query(..), 2_000_000_000);
+        assigned.update(.., &half);
+        assert_eq!(assigned.query(..), i32::MAX - 1);
+
+        let mut added = LazySegmentTree::<AddSum<i32>>::from(vec![0]);
+        added.update(.., &1_500_000_000);
+        assert_eq!(added.get(0), 1_500_000_000);
+    }
+}
@@ -1,4 +1,26 @@
+pub mod avl;
+pub mod bst;
+pub mod build;
+pub mod general;
+pub mod huffman;
+pub mod iter;
+pub mod lca;
+pub mod metrics;
+pub mod radix;
+pub mod rbtree;
+pub mod render;
+mod search;
+pub mod splay;
+pub mod threaded;
+pub mod treap;
+pub mod trie;
+
+pub use iter::{IntoIter, Iter, IterMut, TraversalOrder};
+
+use std::collections::VecDeque;
+use std::mem;
 use std::ops::{Deref, DerefMut};
+use std::ptr;
 
 /// 二叉树节点结构体
 #[derive(Clone,Debug,PartialEq,Eq)]
@@ -120,7 +142,160 @@ where T:Clone,
     pub fn mut_data(&mut self)->&mut T{
         &mut self.data
     }
-    
+
+    /// 替换节点数据
+    ///
+    /// # 参数
+    /// * `data` - 新的节点数据
+    ///
+    /// # 返回值
+    /// 原来的节点数据
+    pub fn replace_data(&mut self,data:T)->T{
+        mem::replace(&mut self.data,data)
+    }
+
+    /// 获取左子节点的不可变引用
+    ///
+    /// # 返回值
+    /// 左子节点存在时返回其引用，否则返回None
+    pub fn left_child(&self)->Option<&Self>{
+        self.left.as_deref()
+    }
+
+    /// 获取右子节点的不可变引用
+    ///
+    /// # 返回值
+    /// 右子节点存在时返回其引用，否则返回None
+    pub fn right_child(&self)->Option<&Self>{
+        self.right.as_deref()
+    }
+
+    /// 检查节点是否为叶子节点
+    ///
+    /// # 返回值
+    /// 左右子节点都不存在时返回true，否则返回false
+    pub fn is_leaf(&self)->bool{
+        self.left.is_none()&&self.right.is_none()
+    }
+
+    /// 把一棵已有的子树接到左侧
+    ///
+    /// # 参数
+    /// * `subtree` - 要接上的子树，传入空树即删除左子树
+    ///
+    /// # 返回值
+    /// 被替换下来的原左子树
+    pub fn set_left_subtree(&mut self,subtree:BinaryTree<T>)->BinaryTree<T>{
+        BinaryTree(mem::replace(&mut self.left,subtree.0))
+    }
+
+    /// 把一棵已有的子树接到右侧
+    ///
+    /// # 参数
+    /// * `subtree` - 要接上的子树，传入空树即删除右子树
+    ///
+    /// # 返回值
+    /// 被替换下来的原右子树
+    pub fn set_right_subtree(&mut self,subtree:BinaryTree<T>)->BinaryTree<T>{
+        BinaryTree(mem::replace(&mut self.right,subtree.0))
+    }
+
+    /// 摘下左子树
+    ///
+    /// # 返回值
+    /// 摘下的左子树，左子节点不存在时为空树
+    pub fn take_left(&mut self)->BinaryTree<T>{
+        BinaryTree(self.left.take())
+    }
+
+    /// 摘下右子树
+    ///
+    /// # 返回值
+    /// 摘下的右子树，右子节点不存在时为空树
+    pub fn take_right(&mut self)->BinaryTree<T>{
+        BinaryTree(self.right.take())
+    }
+
+    /// 删除子树中第一个（按层序）数据等于`data`的叶子节点
+    ///
+    /// 当前节点本身不会被删除。
+    ///
+    /// # 参数
+    /// * `data` - 要删除的叶子节点的数据
+    ///
+    /// # 返回值
+    /// 删除成功时返回叶子节点的数据，找不到时返回None
+    pub fn remove_leaf(&mut self,data:&T)->Option<T>
+    where T:PartialEq,
+    {
+        let mut queue=VecDeque::from([self]);
+        while let Some(node)=queue.pop_front(){
+            for child in [&mut node.left,&mut node.right]{
+                if child.as_ref().is_some_and(|c| c.is_leaf()&&c.data==*data){
+                    return child.take().map(|c| c.data);
+                }
+            }
+            queue.extend(node.left.as_deref_mut());
+            queue.extend(node.right.as_deref_mut());
+        }
+        None
+    }
+
+    /// 交换左右子树
+    pub fn swap_children(&mut self){
+        mem::swap(&mut self.left,&mut self.right);
+    }
+
+    /// 镜像翻转整棵子树（非递归）
+    pub fn mirror(&mut self){
+        let mut stack=vec![self];
+        while let Some(node)=stack.pop(){
+            node.swap_children();
+            stack.extend(node.left.as_deref_mut());
+            stack.extend(node.right.as_deref_mut());
+        }
+    }
+
+    /// 以当前节点为支点左旋，右子节点成为当前位置的新节点
+    ///
+    /// 旋转保持中序序列不变。
+    ///
+    /// # 返回值
+    /// 右子节点不存在时返回错误
+    pub fn rotate_left(&mut self)->Result<(),&'static str>{
+        let mut right=self.right.take().ok_or("右子节点不存在")?;
+        self.right=right.left.take();
+        mem::swap(self,&mut *right);
+        // 此时`right`中是原来的当前节点
+        self.left=Some(right);
+        Ok(())
+    }
+
+    /// 以当前节点为支点右旋，左子节点成为当前位置的新节点
+    ///
+    /// 旋转保持中序序列不变。
+    ///
+    /// # 返回值
+    /// 左子节点不存在时返回错误
+    pub fn rotate_right(&mut self)->Result<(),&'static str>{
+        let mut left=self.left.take().ok_or("左子节点不存在")?;
+        self.left=left.right.take();
+        mem::swap(self,&mut *left);
+        // 此时`left`中是原来的当前节点
+        self.right=Some(left);
+        Ok(())
+    }
+
+    fn _pre_order_traverse_ref<'a>(&'a self,result:&mut Vec<&'a T>){
+        result.push(self.get_data());
+        if let Some(left)=&self.left {
+            left._pre_order_traverse_ref(result);
+        }
+        if let Some(right)=&self.right {
+            right._pre_order_traverse_ref(result);
+        }
+    }
+
     fn _in_order_traverse_ref<'a>(&'a self,result:&mut Vec<&'a T>){
         if let Some(left)=&self.left {
             left._in_order_traverse_ref(result);
@@ -130,16 +305,157 @@ where T:Clone,
             right._in_order_traverse_ref(result);
         }
     }
-    
-    /// 中序遍历二叉树（返回可变引用）
+
+    fn _post_order_traverse_ref<'a>(&'a self,result:&mut Vec<&'a T>){
+        if let Some(left)=&self.left {
+            left._post_order_traverse_ref(result);
+        }
+        if let Some(right)=&self.right {
+            right._post_order_traverse_ref(result);
+        }
+        result.push(self.get_data());
+    }
+
+    fn _level_order_traverse_ref<'a>(&'a self,result:&mut Vec<&'a T>){
+        let mut queue=VecDeque::from([self]);
+        while let Some(node)=queue.pop_front() {
+            result.push(node.get_data());
+            if let Some(left)=&node.left {
+                queue.push_back(left);
+            }
+            if let Some(right)=&node.right {
+                queue.push_back(right);
+            }
+        }
+    }
+
+    // 可变遍历需要先把节点拆成三个互不重叠的字段，才能同时借出数据和子树
+    fn _pre_order_traverse_mut<'a>(&'a mut self,result:&mut Vec<&'a mut T>){
+        let BinaryNode{data,left,right}=self;
+        result.push(data);
+        if let Some(left)=left {
+            left._pre_order_traverse_mut(result);
+        }
+        if let Some(right)=right {
+            right._pre_order_traverse_mut(result);
+        }
+    }
+
+    fn _in_order_traverse_mut<'a>(&'a mut self,result:&mut Vec<&'a mut T>){
+        let BinaryNode{data,left,right}=self;
+        if let Some(left)=left {
+            left._in_order_traverse_mut(result);
+        }
+        result.push(data);
+        if let Some(right)=right {
+            right._in_order_traverse_mut(result);
+        }
+    }
+
+    fn _post_order_traverse_mut<'a>(&'a mut self,result:&mut Vec<&'a mut T>){
+        let BinaryNode{data,left,right}=self;
+        if let Some(left)=left {
+            left._post_order_traverse_mut(result);
+        }
+        if let Some(right)=right {
+            right._post_order_traverse_mut(result);
+        }
+        result.push(data);
+    }
+
+    fn _level_order_traverse_mut<'a>(&'a mut self,result:&mut Vec<&'a mut T>){
+        let mut queue=VecDeque::from([self]);
+        while let Some(node)=queue.pop_front() {
+            let BinaryNode{data,left,right}=node;
+            result.push(data);
+            if let Some(left)=left {
+                queue.push_back(left);
+            }
+            if let Some(right)=right {
+                queue.push_back(right);
+            }
+        }
+    }
+
+    /// 以当前节点为根先序遍历（递归）
     ///
     /// # 返回值
-    /// 包含所有节点数据可变引用的向量
-    pub fn in_order_traverse_mut(&self)->Vec<&T>{
+    /// 包含所有节点数据引用的向量
+    pub fn pre_order_traverse(&self)->Vec<&T>{
+        let mut result=Vec::new();
+        self._pre_order_traverse_ref(&mut result);
+        result
+    }
+
+    /// 以当前节点为根中序遍历（递归）
+    ///
+    /// # 返回值
+    /// 包含所有节点数据引用的向量
+    pub fn in_order_traverse(&self)->Vec<&T>{
         let mut result=Vec::new();
         self._in_order_traverse_ref(&mut result);
         result
     }
+
+    /// 以当前节点为根后序遍历（递归）
+    ///
+    /// # 返回值
+    /// 包含所有节点数据引用的向量
+    pub fn post_order_traverse(&self)->Vec<&T>{
+        let mut result=Vec::new();
+        self._post_order_traverse_ref(&mut result);
+        result
+    }
+
+    /// 以当前节点为根层序遍历
+    ///
+    /// # 返回值
+    /// 包含所有节点数据引用的向量
+    pub fn level_order_traverse(&self)->Vec<&T>{
+        let mut result=Vec::new();
+        self._level_order_traverse_ref(&mut result);
+        result
+    }
+
+    /// 先序遍历（返回可变引用）
+    ///
+    /// # 返回值
+    /// 包含所有节点数据可变引用的向量
+    pub fn pre_order_traverse_mut(&mut self)->Vec<&mut T>{
+        let mut result=Vec::new();
+        self._pre_order_traverse_mut(&mut result);
+        result
+    }
+
+    /// 中序遍历（返回可变引用）
+    ///
+    /// # 返回值
+    /// 包含所有节点数据可变引用的向量
+    pub fn in_order_traverse_mut(&mut self)->Vec<&mut T>{
+        let mut result=Vec::new();
+        self._in_order_traverse_mut(&mut result);
+        result
+    }
+
+    /// 后序遍历（返回可变引用）
+    ///
+    /// # 返回值
+    /// 包含所有节点数据可变引用的向量
+    pub fn post_order_traverse_mut(&mut self)->Vec<&mut T>{
+        let mut result=Vec::new();
+        self._post_order_traverse_mut(&mut result);
+        result
+    }
+
+    /// 层序遍历（返回可变引用）
+    ///
+    /// # 返回值
+    /// 包含所有节点数据可变引用的向量
+    pub fn level_order_traverse_mut(&mut self)->Vec<&mut T>{
+        let mut result=Vec::new();
+        self._level_order_traverse_mut(&mut result);
+        result
+    }
 }
 
 /// 二叉树结构体
@@ -183,6 +499,14 @@ where T:Clone,
         self.0.is_none()
     }
     
+    /// 先序遍历二叉树（递归）
+    ///
+    /// # 返回值
+    /// 包含所有节点数据引用的向量
+    pub fn pre_order_traverse(&self) -> Vec<&T> {
+        self.0.as_ref().map_or_else(Vec::new, |root| root.pre_order_traverse())
+    }
+
     /// 中序遍历二叉树
     ///
     /// # 返回值
@@ -194,6 +518,198 @@ where T:Clone,
         }
         result
     }
+
+    /// 后序遍历二叉树（递归）
+    ///
+    /// # 返回值
+    /// 包含所有节点数据引用的向量
+    pub fn post_order_traverse(&self) -> Vec<&T> {
+        self.0.as_ref().map_or_else(Vec::new, |root| root.post_order_traverse())
+    }
+
+    /// 层序遍历二叉树
+    ///
+    /// # 返回值
+    /// 包含所有节点数据引用的向量
+    pub fn level_order_traverse(&self) -> Vec<&T> {
+        self.0.as_ref().map_or_else(Vec::new, |root| root.level_order_traverse())
+    }
+
+    /// 先序遍历二叉树（显式栈，非递归）
+    ///
+    /// # 返回值
+    /// 包含所有节点数据引用的向量
+    pub fn pre_order_traverse_iterative(&self) -> Vec<&T> {
+        let mut result = Vec::new();
+        let mut stack: Vec<&BinaryNode<T>> = self.0.as_deref().into_iter().collect();
+        while let Some(node) = stack.pop() {
+            result.push(&node.data);
+            // 右子树先入栈，保证左子树先出栈
+            if let Some(right) = node.right.as_deref() {
+                stack.push(right);
+            }
+            if let Some(left) = node.left.as_deref() {
+                stack.push(left);
+            }
+        }
+        result
+    }
+
+    /// 中序遍历二叉树（显式栈，非递归）
+    ///
+    /// # 返回值
+    /// 包含所有节点数据引用的向量
+    pub fn in_order_traverse_iterative(&self) -> Vec<&T> {
+        let mut result = Vec::new();
+        let mut stack: Vec<&BinaryNode<T>> = Vec::new();
+        let mut current = self.0.as_deref();
+        while current.is_some() || !stack.is_empty() {
+            // 一路向左把节点入栈，左子树为空时出栈访问并转向右子树
+            while let Some(node) = current {
+                stack.push(node);
+                current = node.left.as_deref();
+            }
+            let node = stack.pop().unwrap();
+            result.push(&node.data);
+            current = node.right.as_deref();
+        }
+        result
+    }
+
+    /// 后序遍历二叉树（显式栈，非递归）
+    ///
+    /// # 返回值
+    /// 包含所有节点数据引用的向量
+    pub fn post_order_traverse_iterative(&self) -> Vec<&T> {
+        let mut result = Vec::new();
+        let mut stack: Vec<&BinaryNode<T>> = Vec::new();
+        let mut current = self.0.as_deref();
+        // 记录上一个访问的节点，用来判断右子树是否已经遍历完
+        let mut last: Option<&BinaryNode<T>> = None;
+        while current.is_some() || !stack.is_empty() {
+            while let Some(node) = current {
+                stack.push(node);
+                current = node.left.as_deref();
+            }
+            let top = *stack.last().unwrap();
+            match top.right.as_deref() {
+                Some(right) if !last.is_some_and(|l| ptr::eq(l, right)) => current = Some(right),
+                _ => {
+                    result.push(&top.data);
+                    last = stack.pop();
+                }
+            }
+        }
+        result
+    }
+
+    /// Morris 中序遍历
+    ///
+    /// 利用叶子节点空闲的右指针临时指回后继节点（线索），不需要栈也不需要递归，
+    /// 遍历结束时所有线索都会被拆除，树的结构保持不变。
+    ///
+    /// # 返回值
+    /// 包含所有节点数据引用的向量
+    pub fn morris_in_order_traverse(&mut self) -> Vec<&T> {
+        self.morris(false)
+    }
+
+    /// Morris 先序遍历
+    ///
+    /// 与 [`BinaryTree::morris_in_order_traverse`] 相同，只是在建立线索时访问节点。
+    ///
+    /// # 返回值
+    /// 包含所有节点数据引用的向量
+    pub fn morris_pre_order_traverse(&mut self) -> Vec<&T> {
+        self.morris(true)
+    }
+
+    fn morris(&mut self, pre_order: bool) -> Vec<&T> {
+        let mut visited: Vec<*const T> = Vec::new();
+        let Some(root) = self.0.as_deref_mut() else {
+            return Vec::new();
+        };
+        let mut current: *mut BinaryNode<T> = root;
+        // SAFETY: `Option<Box<BinaryNode<T>>>` 与可空指针布局相同。遍历期间只通过
+        // `link`/`set_right` 以裸指针读写子节点字段，线索以裸指针的形式写入空闲的右指针，
+        // 从不构造指向祖先的 `Box`，也不创建任何引用；遍历结束时所有线索都已清空，
+        // 这之后才把收集到的裸指针转成引用。遍历过程中不调用外部代码，不会在线索存在时 panic。
+        unsafe {
+            while !current.is_null() {
+                let left = Self::link(&raw const (*current).left);
+                if left.is_null() {
+                    visited.push(&raw const (*current).data);
+                    current = Self::link(&raw const (*current).right);
+                    continue;
+                }
+                // 寻找左子树中最右的节点，即当前节点的中序前驱
+                let mut pred = left;
+                loop {
+                    let right = Self::link(&raw const (*pred).right);
+                    if right.is_null() || right == current {
+                        break;
+                    }
+                    pred = right;
+                }
+                if Self::link(&raw const (*pred).right).is_null() {
+                    if pre_order {
+                        visited.push(&raw const (*current).data);
+                    }
+                    Self::set_right(pred, current);
+                    current = left;
+                } else {
+                    Self::set_right(pred, ptr::null_mut());
+                    if !pre_order {
+                        visited.push(&raw const (*current).data);
+                    }
+                    current = Self::link(&raw const (*current).right);
+                }
+            }
+            visited.into_iter().map(|data| &*data).collect()
+        }
+    }
+
+    /// 以裸指针读出子节点字段，不产生 `Box`
+    unsafe fn link(field: *const Option<Box<BinaryNode<T>>>) -> *mut BinaryNode<T> {
+        unsafe { *field.cast::<*mut BinaryNode<T>>() }
+    }
+
+    /// 以裸指针写入右子节点字段，用于建立和拆除线索
+    unsafe fn set_right(node: *mut BinaryNode<T>, target: *mut BinaryNode<T>) {
+        unsafe { *(&raw mut (*node).right).cast::<*mut BinaryNode<T>>() = target }
+    }
+
+    /// 先序遍历二叉树（返回可变引用）
+    ///
+    /// # 返回值
+    /// 包含所有节点数据可变引用的向量
+    pub fn pre_order_traverse_mut(&mut self) -> Vec<&mut T> {
+        self.0.as_mut().map_or_else(Vec::new, |root| root.pre_order_traverse_mut())
+    }
+
+    /// 中序遍历二叉树（返回可变引用）
+    ///
+    /// # 返回值
+    /// 包含所有节点数据可变引用的向量
+    pub fn in_order_traverse_mut(&mut self) -> Vec<&mut T> {
+        self.0.as_mut().map_or_else(Vec::new, |root| root.in_order_traverse_mut())
+    }
+
+    /// 后序遍历二叉树（返回可变引用）
+    ///
+    /// # 返回值
+    /// 包含所有节点数据可变引用的向量
+    pub fn post_order_traverse_mut(&mut self) -> Vec<&mut T> {
+        self.0.as_mut().map_or_else(Vec::new, |root| root.post_order_traverse_mut())
+    }
+
+    /// 层序遍历二叉树（返回可变引用）
+    ///
+    /// # 返回值
+    /// 包含所有节点数据可变引用的向量
+    pub fn level_order_traverse_mut(&mut self) -> Vec<&mut T> {
+        self.0.as_mut().map_or_else(Vec::new, |root| root.level_order_traverse_mut())
+    }
 }
 
 impl<T> Deref for BinaryTree<T>{
@@ -289,4 +805,99 @@ mod tests{
         assert_eq!(node, Some(&mut BinaryNode::new(2)));
         println!("{:?}",tree);
     }
+
+    fn sample_tree()->BinaryTree<i32>{
+        //       1
+        //      / \
+        //     2   3
+        //    / \   \
+        //   4   5   6
+        //      /
+        //     7
+        let mut root=BinaryNode::new(1);
+        root.left_mut(2).right_mut(3);
+        let left=root.next_left_node().unwrap();
+        left.left_mut(4).right_mut(5);
+        left.next_right_node().unwrap().left_mut(7);
+        root.next_right_node().unwrap().right_mut(6);
+        let mut tree=BinaryTree::new();
+        tree.set_root(root);
+        tree
+    }
+
+    #[test]
+    fn test_traversal_orders(){
+        let mut tree=sample_tree();
+        let pre=vec![&1,&2,&4,&5,&7,&3,&6];
+        let mid=vec![&4,&2,&7,&5,&1,&3,&6];
+        let post=vec![&4,&7,&5,&2,&6,&3,&1];
+        assert_eq!(tree.pre_order_traverse(),pre);
+        assert_eq!(tree.pre_order_traverse_iterative(),pre);
+        assert_eq!(tree.in_order_traverse(),mid);
+        assert_eq!(tree.in_order_traverse_iterative(),mid);
+        assert_eq!(tree.post_order_traverse(),post);
+        assert_eq!(tree.post_order_traverse_iterative(),post);
+        assert_eq!(tree.level_order_traverse(),vec![&1,&2,&3,&4,&5,&6,&7]);
+
+        // Morris 遍历结束后树的结构不变
+        let before=tree.clone();
+        assert_eq!(tree.morris_in_order_traverse(),mid);
+        assert_eq!(tree.morris_pre_order_traverse(),pre);
+        assert_eq!(*tree,*before);
+        assert!(BinaryTree::<i32>::new().morris_in_order_traverse().is_empty());
+        assert!(BinaryTree::<i32>::new().post_order_traverse_iterative().is_empty());
+    }
+
+    #[test]
+    fn test_structural_edit(){
+        let mut tree=sample_tree();
+        let root=tree.as_mut().unwrap();
+        // 把2的子树摘下接到3的左侧
+        let subtree=root.take_left();
+        assert_eq!(subtree.pre_order_traverse(),vec![&2,&4,&5,&7]);
+        let old=root.next_right_node().unwrap().set_left_subtree(subtree);
+        assert!(old.if_empty());
+        assert_eq!(root.left_child(),None);
+        assert_eq!(root.right_child().unwrap().left_child().unwrap().data,2);
+        assert_eq!(root.pre_order_traverse(),vec![&1,&3,&2,&4,&5,&7,&6]);
+
+        assert_eq!(root.replace_data(10),1);
+        assert_eq!(root.remove_leaf(&5),None);
+        assert_eq!(root.remove_leaf(&7),Some(7));
+        assert_eq!(root.remove_leaf(&5),Some(5));
+        assert!(root.right_child().unwrap().left_child().unwrap().right_child().is_none());
+
+        // 旋转保持中序序列不变，左旋再右旋恢复原状
+        let mut tree=sample_tree();
+        let before=tree.clone();
+        let mid=vec![&4,&2,&7,&5,&1,&3,&6];
+        let root=tree.as_mut().unwrap();
+        root.rotate_right().unwrap();
+        assert_eq!(root.data,2);
+        assert_eq!(root.in_order_traverse(),mid);
+        root.rotate_left().unwrap();
+        assert_eq!(*tree,*before);
+        let root=tree.as_mut().unwrap();
+        assert!(root.next_left_node().unwrap().next_left_node().unwrap().rotate_left().is_err());
+
+        root.mirror();
+        assert_eq!(root.in_order_traverse(),vec![&6,&3,&1,&5,&7,&2,&4]);
+        root.swap_children();
+        assert_eq!(root.left_child().unwrap().data,2);
+    }
+
+    #[test]
+    fn test_mut_traversal(){
+        let mut tree=sample_tree();
+        for (i,data) in tree.level_order_traverse_mut().into_iter().enumerate(){
+            *data=i as i32*10;
+        }
+        assert_eq!(tree.level_order_traverse(),vec![&0,&10,&20,&30,&40,&50,&60]);
+        for data in tree.post_order_traverse_mut(){
+            *data+=1;
+        }
+        let root=tree.as_mut().unwrap();
+        *root.in_order_traverse_mut()[0]-=1;
+        assert_eq!(root.in_order_traverse(),vec![&30,&11,&61,&41,&1,&21,&51]);
+    }
 }
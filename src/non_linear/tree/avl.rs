@@ -0,0 +1,396 @@
+//! # AVL 树模块
+//!
+//! 基于 [`BinaryNode`] 实现的自平衡二叉搜索树。每个节点记录子树高度，
+//! 插入和删除后沿路径回溯，通过单旋转或双旋转使任意节点左右子树的高度差不超过 1，
+//! 从而保证查找、插入、删除都是 O(log n)。
+//!
+//! ## 示例
+//! ```
+//! use data_structure::non_linear::tree::avl::AvlTree;
+//!
+//! let mut avl = AvlTree::new();
+//! // 有序插入不会退化成链表
+//! for i in 0..1000 {
+//!     avl.insert(i, i * 2);
+//! }
+//! assert!(avl.height() <= 11);
+//! assert_eq!(avl.get(&300), Some(&600));
+//! assert_eq!(avl.remove(&300), Some(600));
+//! assert!(avl.check().is_ok());
+//! ```
+
+use std::cmp::Ordering;
+use std::mem;
+
+use super::BinaryNode;
+use super::search::{self, KeyValue};
+
+/// 节点中保存的键值对以及以该节点为根的子树高度
+#[derive(Clone, Debug)]
+struct Entry<K, V> {
+    key: K,
+    value: V,
+    height: usize,
+}
+
+impl<K, V> KeyValue for Entry<K, V> {
+    type Key = K;
+    type Value = V;
+
+    fn key(&self) -> &K {
+        &self.key
+    }
+
+    fn value(&self) -> &V {
+        &self.value
+    }
+
+    fn value_mut(&mut self) -> &mut V {
+        &mut self.value
+    }
+}
+
+type Node<K, V> = BinaryNode<Entry<K, V>>;
+type Link<K, V> = Option<Box<Node<K, V>>>;
+
+fn height<K, V>(link: &Link<K, V>) -> usize {
+    link.as_ref().map_or(0, |node| node.data.height)
+}
+
+/// 平衡因子：左子树高度减右子树高度
+fn balance_factor<K, V>(node: &Node<K, V>) -> isize {
+    height(&node.left) as isize - height(&node.right) as isize
+}
+
+fn update<K, V>(node: &mut Node<K, V>) {
+    node.data.height = height(&node.left).max(height(&node.right)) + 1;
+}
+
+/// 右旋：左子节点成为新的根
+fn rotate_right<K, V>(mut node: Box<Node<K, V>>) -> Box<Node<K, V>> {
+    let mut left = node.left.take().expect("右旋需要左子节点");
+    node.left = left.right.take();
+    update(&mut node);
+    left.right = Some(node);
+    update(&mut left);
+    left
+}
+
+/// 左旋：右子节点成为新的根
+fn rotate_left<K, V>(mut node: Box<Node<K, V>>) -> Box<Node<K, V>> {
+    let mut right = node.right.take().expect("左旋需要右子节点");
+    node.right = right.left.take();
+    update(&mut node);
+    right.left = Some(node);
+    update(&mut right);
+    right
+}
+
+/// 更新高度，并在失衡时通过旋转恢复平衡
+fn rebalance<K, V>(mut node: Box<Node<K, V>>) -> Box<Node<K, V>> {
+    update(&mut node);
+    match balance_factor(&node) {
+        2 => {
+            // LR 型先把左子树左旋成 LL 型
+            if balance_factor(node.left.as_ref().unwrap()) < 0 {
+                node.left = node.left.take().map(rotate_left);
+            }
+            rotate_right(node)
+        }
+        -2 => {
+            // RL 型先把右子树右旋成 RR 型
+            if balance_factor(node.right.as_ref().unwrap()) > 0 {
+                node.right = node.right.take().map(rotate_right);
+            }
+            rotate_left(node)
+        }
+        _ => node,
+    }
+}
+
+/// AVL 树
+///
+/// 提供与有序映射相同的接口，不允许重复的键。
+#[derive(Clone, Debug)]
+pub struct AvlTree<K, V> {
+    root: Link<K, V>,
+    len: usize,
+}
+
+impl<K, V> AvlTree<K, V>
+where
+    K: Ord,
+{
+    /// 创建空的 AVL 树
+    pub fn new() -> Self {
+        Self { root: None, len: 0 }
+    }
+
+    /// 获取键值对的数量
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// 检查是否为空
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// 树的高度，空树为 0
+    pub fn height(&self) -> usize {
+        height(&self.root)
+    }
+
+    /// 插入键值对
+    ///
+    /// # 返回值
+    /// 键已存在时替换旧值并返回 `Some(旧值)`，否则返回 `None`
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let (root, old) = Self::insert_at(self.root.take(), key, value);
+        self.root = Some(root);
+        if old.is_none() {
+            self.len += 1;
+        }
+        old
+    }
+
+    fn insert_at(link: Link<K, V>, key: K, value: V) -> (Box<Node<K, V>>, Option<V>) {
+        let Some(mut node) = link else {
+            let entry = Entry {
+                key,
+                value,
+                height: 1,
+            };
+            return (
+                Box::new(BinaryNode {
+                    data: entry,
+                    left: None,
+                    right: None,
+                }),
+                None,
+            );
+        };
+        let old = match key.cmp(&node.data.key) {
+            Ordering::Equal => {
+                let old = mem::replace(&mut node.data.value, value);
+                return (node, Some(old));
+            }
+            Ordering::Less => {
+                let (left, old) = Self::insert_at(node.left.take(), key, value);
+                node.left = Some(left);
+                old
+            }
+            Ordering::Greater => {
+                let (right, old) = Self::insert_at(node.right.take(), key, value);
+                node.right = Some(right);
+                old
+            }
+        };
+        (rebalance(node), old)
+    }
+
+    /// 查找键对应的值
+    pub fn get(&self, key: &K) -> Option<&V> {
+        search::get(&self.root, key)
+    }
+
+    /// 查找键对应的值的可变引用
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        search::get_mut(&mut self.root, key)
+    }
+
+    /// 检查是否包含指定的键
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// 删除键值对
+    ///
+    /// # 返回值
+    /// 键存在时返回 `Some(值)`，否则返回 `None`
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let (root, removed) = Self::remove_at(self.root.take(), key);
+        self.root = root;
+        if removed.is_some() {
+            self.len -= 1;
+        }
+        removed
+    }
+
+    fn remove_at(link: Link<K, V>, key: &K) -> (Link<K, V>, Option<V>) {
+        let Some(mut node) = link else {
+            return (None, None);
+        };
+        let removed = match key.cmp(&node.data.key) {
+            Ordering::Less => {
+                let (left, removed) = Self::remove_at(node.left.take(), key);
+                node.left = left;
+                removed
+            }
+            Ordering::Greater => {
+                let (right, removed) = Self::remove_at(node.right.take(), key);
+                node.right = right;
+                removed
+            }
+            Ordering::Equal => {
+                let BinaryNode { data, left, right } = *node;
+                let replacement = match (left, right) {
+                    (None, None) => None,
+                    (Some(child), None) | (None, Some(child)) => Some(child),
+                    (Some(left), Some(right)) => {
+                        // 两个子节点时用中序后继顶替
+                        let (mut successor, rest) = Self::take_min(right);
+                        successor.left = Some(left);
+                        successor.right = rest;
+                        Some(rebalance(successor))
+                    }
+                };
+                return (replacement, Some(data.value));
+            }
+        };
+        (Some(rebalance(node)), removed)
+    }
+
+    /// 从子树中摘下最小节点
+    ///
+    /// # 返回值
+    /// `(最小节点, 重新平衡后的剩余子树)`
+    fn take_min(mut node: Box<Node<K, V>>) -> (Box<Node<K, V>>, Link<K, V>) {
+        match node.left.take() {
+            None => {
+                let rest = node.right.take();
+                (node, rest)
+            }
+            Some(left) => {
+                let (min, rest) = Self::take_min(left);
+                node.left = rest;
+                (min, Some(rebalance(node)))
+            }
+        }
+    }
+
+    /// 最小的键值对
+    pub fn min(&self) -> Option<(&K, &V)> {
+        search::min(&self.root)
+    }
+
+    /// 最大的键值对
+    pub fn max(&self) -> Option<(&K, &V)> {
+        search::max(&self.root)
+    }
+
+    /// 按键从小到大遍历所有键值对
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter(search::Range::all(&self.root))
+    }
+
+    /// 检查 AVL 树的不变量
+    ///
+    /// 依次检查：键严格递增、记录的高度正确、每个节点的平衡因子在 [-1, 1] 内、节点数量与 `len` 一致。
+    ///
+    /// # 返回值
+    /// 全部满足时返回 `Ok(())`，否则返回描述第一个被破坏的不变量的错误
+    pub fn check(&self) -> Result<(), &'static str> {
+        let mut count = 0;
+        let mut previous: Option<&K> = None;
+        for (key, _) in self.iter() {
+            if previous.is_some_and(|previous| previous >= key) {
+                return Err("键不是严格递增的");
+            }
+            previous = Some(key);
+            count += 1;
+        }
+        if count != self.len {
+            return Err("节点数量与长度不一致");
+        }
+        Self::check_node(&self.root).map(|_| ())
+    }
+
+    /// 递归检查高度与平衡因子，返回子树的实际高度
+    fn check_node(link: &Link<K, V>) -> Result<usize, &'static str> {
+        let Some(node) = link else {
+            return Ok(0);
+        };
+        let left = Self::check_node(&node.left)?;
+        let right = Self::check_node(&node.right)?;
+        if node.data.height != left.max(right) + 1 {
+            return Err("节点记录的高度不正确");
+        }
+        if left.abs_diff(right) > 1 {
+            return Err("节点的平衡因子超出 [-1, 1]");
+        }
+        Ok(node.data.height)
+    }
+}
+
+impl<K: Ord, V> Default for AvlTree<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 按键的顺序惰性遍历 AVL 树的迭代器
+pub struct Iter<'a, K, V>(search::Range<'a, Entry<K, V>>);
+
+impl<'a, K: Ord, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+}
+
+impl<'a, K: Ord, V> IntoIterator for &'a AvlTree<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_avl_rotations() {
+        // 分别触发 LL、RR、LR、RL 四种失衡
+        for keys in [[3, 2, 1], [1, 2, 3], [3, 1, 2], [1, 3, 2]] {
+            let mut avl = AvlTree::new();
+            for key in keys {
+                avl.insert(key, ());
+                assert!(avl.check().is_ok());
+            }
+            assert_eq!(avl.height(), 2);
+            assert_eq!(avl.root.as_ref().unwrap().data.key, 2);
+        }
+    }
+
+    #[test]
+    fn test_avl_insert_remove() {
+        let mut avl = AvlTree::new();
+        // 伪随机顺序插入 0..500
+        for i in 0..500 {
+            assert_eq!(avl.insert(i * 7 % 500, i), None);
+            assert!(avl.check().is_ok());
+        }
+        assert_eq!(avl.len(), 500);
+        assert!(avl.height() <= 13);
+        assert_eq!(avl.insert(7, 0), Some(1));
+        *avl.get_mut(&7).unwrap() += 1;
+        assert_eq!(avl.get(&7), Some(&1));
+        assert_eq!(avl.min(), Some((&0, &0)));
+        assert_eq!(avl.max(), Some((&499, &357)));
+
+        for key in (0..500).filter(|key| key % 3 != 0) {
+            assert!(avl.remove(&key).is_some());
+            assert!(avl.check().is_ok());
+        }
+        assert_eq!(avl.remove(&1), None);
+        let keys: Vec<i32> = avl.iter().map(|(k, _)| *k).collect();
+        assert_eq!(keys, (0..500).step_by(3).collect::<Vec<_>>());
+        assert!(!avl.contains_key(&2));
+        assert_eq!(avl.len(), keys.len());
+    }
+}
@@ -0,0 +1,384 @@
+//! # 二叉搜索树模块
+//!
+//! 基于 [`BinaryNode`] 实现的二叉搜索树（有序映射）。节点数据中额外记录子树大小，
+//! 用于在 O(h) 时间内完成 rank/select 查询，其中 h 为树高。
+//!
+//! ## 示例
+//! ```
+//! use data_structure::non_linear::tree::bst::BinarySearchTree;
+//!
+//! let mut bst = BinarySearchTree::new();
+//! for (k, v) in [(5, 'e'), (2, 'b'), (8, 'h'), (1, 'a')] {
+//!     bst.insert(k, v);
+//! }
+//! assert_eq!(bst.get(&2), Some(&'b'));
+//! assert_eq!(bst.floor(&7), Some((&5, &'e')));
+//! assert_eq!(bst.rank(&8), 3);
+//! assert_eq!(bst.range(2..=5).map(|(k, _)| *k).collect::<Vec<_>>(), vec![2, 5]);
+//! ```
+
+use std::cmp::Ordering;
+use std::mem;
+use std::ops::RangeBounds;
+
+use super::BinaryNode;
+use super::search::{self, KeyValue};
+
+/// 节点中保存的键值对以及以该节点为根的子树大小
+#[derive(Clone, Debug)]
+struct Entry<K, V> {
+    key: K,
+    value: V,
+    size: usize,
+}
+
+impl<K, V> KeyValue for Entry<K, V> {
+    type Key = K;
+    type Value = V;
+
+    fn key(&self) -> &K {
+        &self.key
+    }
+
+    fn value(&self) -> &V {
+        &self.value
+    }
+
+    fn value_mut(&mut self) -> &mut V {
+        &mut self.value
+    }
+}
+
+type Node<K, V> = BinaryNode<Entry<K, V>>;
+type Link<K, V> = Option<Box<Node<K, V>>>;
+
+fn size<K, V>(link: &Link<K, V>) -> usize {
+    link.as_ref().map_or(0, |node| node.data.size)
+}
+
+/// 二叉搜索树
+///
+/// 左子树中的键都小于根，右子树中的键都大于根，不允许重复的键。
+#[derive(Clone, Debug)]
+pub struct BinarySearchTree<K, V> {
+    root: Link<K, V>,
+}
+
+impl<K, V> BinarySearchTree<K, V>
+where
+    K: Ord,
+{
+    /// 创建空的二叉搜索树
+    pub fn new() -> Self {
+        Self { root: None }
+    }
+
+    /// 获取键值对的数量
+    pub fn len(&self) -> usize {
+        size(&self.root)
+    }
+
+    /// 检查是否为空
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    /// 插入键值对
+    ///
+    /// # 返回值
+    /// 键已存在时替换旧值并返回 `Some(旧值)`，否则返回 `None`
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        if let Some(old) = self.get_mut(&key) {
+            return Some(mem::replace(old, value));
+        }
+        // 键不存在，新节点一定会挂到查找路径的末端，路径上每个节点的子树大小都加一
+        let mut link = &mut self.root;
+        while let Some(node) = link {
+            node.data.size += 1;
+            link = if key < node.data.key {
+                &mut node.left
+            } else {
+                &mut node.right
+            };
+        }
+        *link = Some(Box::new(BinaryNode {
+            data: Entry {
+                key,
+                value,
+                size: 1,
+            },
+            left: None,
+            right: None,
+        }));
+        None
+    }
+
+    /// 查找键对应的值
+    pub fn get(&self, key: &K) -> Option<&V> {
+        search::get(&self.root, key)
+    }
+
+    /// 查找键对应的值的可变引用
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        search::get_mut(&mut self.root, key)
+    }
+
+    /// 检查是否包含指定的键
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// 删除键值对
+    ///
+    /// 被删节点有两个子节点时，用右子树中的最小节点（中序后继）顶替它的位置。
+    ///
+    /// # 返回值
+    /// 键存在时返回 `Some(值)`，否则返回 `None`
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        if !self.contains_key(key) {
+            return None;
+        }
+        let mut link = &mut self.root;
+        loop {
+            let order = key.cmp(&link.as_ref().unwrap().data.key);
+            if order == Ordering::Equal {
+                break;
+            }
+            let node = link.as_mut().unwrap();
+            node.data.size -= 1;
+            link = if order == Ordering::Less {
+                &mut node.left
+            } else {
+                &mut node.right
+            };
+        }
+        let mut node = link.take().unwrap();
+        *link = match (node.left.take(), node.right.take()) {
+            (None, None) => None,
+            (Some(child), None) | (None, Some(child)) => Some(child),
+            (Some(left), Some(right)) => {
+                let (mut successor, rest) = Self::take_min(right);
+                successor.data.size = left.data.size + size(&rest) + 1;
+                successor.left = Some(left);
+                successor.right = rest;
+                Some(successor)
+            }
+        };
+        Some(node.data.value)
+    }
+
+    /// 从子树中摘下最小节点
+    ///
+    /// # 返回值
+    /// `(最小节点, 剩余的子树)`
+    fn take_min(mut root: Box<Node<K, V>>) -> (Box<Node<K, V>>, Link<K, V>) {
+        if root.left.is_none() {
+            let rest = root.right.take();
+            return (root, rest);
+        }
+        root.data.size -= 1;
+        let mut link = &mut root.left;
+        while link.as_ref().unwrap().left.is_some() {
+            let node = link.as_mut().unwrap();
+            node.data.size -= 1;
+            link = &mut node.left;
+        }
+        let mut min = link.take().unwrap();
+        *link = min.right.take();
+        (min, Some(root))
+    }
+
+    /// 最小的键值对
+    pub fn min(&self) -> Option<(&K, &V)> {
+        search::min(&self.root)
+    }
+
+    /// 最大的键值对
+    pub fn max(&self) -> Option<(&K, &V)> {
+        search::max(&self.root)
+    }
+
+    /// 不大于 `key` 的最大键值对
+    pub fn floor(&self, key: &K) -> Option<(&K, &V)> {
+        let mut best = None;
+        let mut current = self.root.as_deref();
+        while let Some(node) = current {
+            match key.cmp(&node.data.key) {
+                Ordering::Less => current = node.left.as_deref(),
+                Ordering::Equal => return Some((&node.data.key, &node.data.value)),
+                Ordering::Greater => {
+                    best = Some((&node.data.key, &node.data.value));
+                    current = node.right.as_deref();
+                }
+            }
+        }
+        best
+    }
+
+    /// 不小于 `key` 的最小键值对
+    pub fn ceiling(&self, key: &K) -> Option<(&K, &V)> {
+        let mut best = None;
+        let mut current = self.root.as_deref();
+        while let Some(node) = current {
+            match key.cmp(&node.data.key) {
+                Ordering::Greater => current = node.right.as_deref(),
+                Ordering::Equal => return Some((&node.data.key, &node.data.value)),
+                Ordering::Less => {
+                    best = Some((&node.data.key, &node.data.value));
+                    current = node.left.as_deref();
+                }
+            }
+        }
+        best
+    }
+
+    /// 小于 `key` 的键的数量
+    pub fn rank(&self, key: &K) -> usize {
+        let mut rank = 0;
+        let mut current = self.root.as_deref();
+        while let Some(node) = current {
+            match key.cmp(&node.data.key) {
+                Ordering::Less => current = node.left.as_deref(),
+                Ordering::Equal => return rank + size(&node.left),
+                Ordering::Greater => {
+                    rank += size(&node.left) + 1;
+                    current = node.right.as_deref();
+                }
+            }
+        }
+        rank
+    }
+
+    /// 排名为 `index` 的键值对（从 0 开始），即恰有 `index` 个键比它小
+    pub fn select(&self, mut index: usize) -> Option<(&K, &V)> {
+        let mut current = self.root.as_deref();
+        while let Some(node) = current {
+            let left = size(&node.left);
+            match index.cmp(&left) {
+                Ordering::Less => current = node.left.as_deref(),
+                Ordering::Equal => return Some((&node.data.key, &node.data.value)),
+                Ordering::Greater => {
+                    index -= left + 1;
+                    current = node.right.as_deref();
+                }
+            }
+        }
+        None
+    }
+
+    /// 按键从小到大遍历所有键值对
+    pub fn iter(&self) -> Range<'_, K, V> {
+        Range(search::Range::all(&self.root))
+    }
+
+    /// 按键从小到大遍历落在 `range` 内的键值对
+    ///
+    /// # 示例
+    /// ```
+    /// use data_structure::non_linear::tree::bst::BinarySearchTree;
+    ///
+    /// let mut bst = BinarySearchTree::new();
+    /// (0..10).for_each(|i| { bst.insert(i, i * i); });
+    /// assert_eq!(bst.range(7..).map(|(_, v)| *v).collect::<Vec<_>>(), vec![49, 64, 81]);
+    /// ```
+    pub fn range<R>(&self, range: R) -> Range<'_, K, V>
+    where
+        R: RangeBounds<K>,
+        K: Clone,
+    {
+        Range(search::Range::new(
+            &self.root,
+            range.start_bound().cloned(),
+            range.end_bound().cloned(),
+        ))
+    }
+}
+
+impl<K: Ord, V> Default for BinarySearchTree<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 按键的顺序惰性遍历二叉搜索树的迭代器
+pub struct Range<'a, K, V>(search::Range<'a, Entry<K, V>>);
+
+impl<'a, K: Ord, V> Iterator for Range<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ops::Bound;
+
+    fn sample() -> BinarySearchTree<i32, String> {
+        let mut bst = BinarySearchTree::new();
+        for key in [50, 30, 70, 20, 40, 60, 80, 35, 45, 65] {
+            assert_eq!(bst.insert(key, key.to_string()), None);
+        }
+        bst
+    }
+
+    fn keys(bst: &BinarySearchTree<i32, String>) -> Vec<i32> {
+        bst.iter().map(|(k, _)| *k).collect()
+    }
+
+    #[test]
+    fn test_bst_insert_remove() {
+        let mut bst = sample();
+        assert_eq!(bst.len(), 10);
+        assert_eq!(bst.insert(40, "forty".to_string()), Some("40".to_string()));
+        assert_eq!(bst.get(&40).map(String::as_str), Some("forty"));
+        assert_eq!(bst.get(&41), None);
+        bst.get_mut(&20).unwrap().push('!');
+        assert_eq!(bst.get(&20).map(String::as_str), Some("20!"));
+
+        // 叶子、单子节点和双子节点三种删除情况
+        assert_eq!(bst.remove(&35), Some("35".to_string()));
+        assert_eq!(bst.remove(&60), Some("60".to_string()));
+        assert_eq!(bst.remove(&30), Some("30".to_string()));
+        assert_eq!(bst.remove(&50), Some("50".to_string()));
+        assert_eq!(bst.remove(&50), None);
+        assert_eq!(keys(&bst), vec![20, 40, 45, 65, 70, 80]);
+        assert_eq!(bst.len(), 6);
+        // 子树大小在删除后保持正确
+        for (i, key) in keys(&bst).into_iter().enumerate() {
+            assert_eq!(bst.rank(&key), i);
+            assert_eq!(bst.select(i).map(|(k, _)| *k), Some(key));
+        }
+        while let Some((&key, _)) = bst.min() {
+            bst.remove(&key);
+        }
+        assert!(bst.is_empty());
+    }
+
+    #[test]
+    fn test_bst_order_queries() {
+        let bst = sample();
+        assert_eq!(bst.min().map(|(k, _)| *k), Some(20));
+        assert_eq!(bst.max().map(|(k, _)| *k), Some(80));
+        assert_eq!(bst.floor(&64).map(|(k, _)| *k), Some(60));
+        assert_eq!(bst.floor(&10), None);
+        assert_eq!(bst.ceiling(&36).map(|(k, _)| *k), Some(40));
+        assert_eq!(bst.ceiling(&80).map(|(k, _)| *k), Some(80));
+        assert_eq!(bst.ceiling(&81), None);
+        assert_eq!(bst.rank(&10), 0);
+        assert_eq!(bst.rank(&62), 7);
+        assert_eq!(bst.rank(&100), 10);
+        assert_eq!(bst.select(10), None);
+
+        let range: Vec<i32> = bst.range(35..65).map(|(k, _)| *k).collect();
+        assert_eq!(range, vec![35, 40, 45, 50, 60]);
+        let range: Vec<i32> = bst
+            .range((Bound::Excluded(45), Bound::Included(70)))
+            .map(|(k, _)| *k)
+            .collect();
+        assert_eq!(range, vec![50, 60, 65, 70]);
+        assert_eq!(bst.range(..20).count(), 0);
+    }
+}
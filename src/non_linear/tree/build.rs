@@ -0,0 +1,295 @@
+//! # 由遍历序列重建二叉树
+//!
+//! 单独的先序、中序或后序序列都不能唯一确定一棵二叉树，常见的重建方式有两类：
+//!
+//! - 中序序列配合先序或后序序列：要求节点值互不相同，否则无法在中序序列中定位根；
+//! - 带空标记的先序或层序序列：用 `None` 表示空子树，序列本身就确定了树的形状。
+//!
+//! 输入不一致时（长度不同、元素不同、存在重复值、空标记位置不合法）返回错误而不是 panic。
+//!
+//! ## 示例
+//! ```
+//! use data_structure::non_linear::tree::BinaryTree;
+//!
+//! let tree = BinaryTree::from_pre_in_order(&[1, 2, 4, 3], &[4, 2, 1, 3]).unwrap();
+//! assert_eq!(tree.post_order_traverse(), vec![&4, &2, &3, &1]);
+//!
+//! let same = BinaryTree::from_level_order([Some(1), Some(2), Some(3), Some(4)]).unwrap();
+//! assert_eq!(same.pre_order_traverse(), tree.pre_order_traverse());
+//! ```
+
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+
+use super::{BinaryNode, BinaryTree};
+
+type Link<T> = Option<Box<BinaryNode<T>>>;
+
+fn leaf<T>(data: T) -> Box<BinaryNode<T>> {
+    Box::new(BinaryNode {
+        data,
+        left: None,
+        right: None,
+    })
+}
+
+/// 记录中序序列中每个值的下标，并检查两个序列长度一致、没有重复值
+fn in_order_positions<'a, T: Eq + Hash>(
+    order: &[T],
+    in_order: &'a [T],
+) -> Result<HashMap<&'a T, usize>, &'static str> {
+    if order.len() != in_order.len() {
+        return Err("序列长度不一致");
+    }
+    let mut positions = HashMap::with_capacity(in_order.len());
+    for (i, value) in in_order.iter().enumerate() {
+        if positions.insert(value, i).is_some() {
+            return Err("序列中有重复的值");
+        }
+    }
+    Ok(positions)
+}
+
+/// 由根所在序列的区间 `order` 和对应的中序区间 `in_order[start..]` 递归建树
+///
+/// `root_first` 为 `true` 时根在 `order` 的开头（先序），否则在末尾（后序）。
+/// 每一步都检查根在中序区间内，长度相等且值互不相同时这就保证了两个序列一致。
+fn build_with_in_order<T: Clone + Eq + Hash>(
+    order: &[T],
+    start: usize,
+    positions: &HashMap<&T, usize>,
+    root_first: bool,
+) -> Result<Link<T>, &'static str> {
+    let (root, rest) = match (root_first, order) {
+        (_, []) => return Ok(None),
+        (true, [root, rest @ ..]) | (false, [rest @ .., root]) => (root, rest),
+    };
+    let index = match positions.get(root) {
+        Some(&i) if (start..start + order.len()).contains(&i) => i,
+        _ => return Err("遍历序列与中序序列不一致"),
+    };
+    let (left, right) = rest.split_at(index - start);
+    let mut node = leaf(root.clone());
+    node.left = build_with_in_order(left, start, positions, root_first)?;
+    node.right = build_with_in_order(right, index + 1, positions, root_first)?;
+    Ok(Some(node))
+}
+
+/// 从带空标记的先序序列中读出一棵子树
+fn build_pre_order<T>(
+    values: &mut impl Iterator<Item = Option<T>>,
+) -> Result<Link<T>, &'static str> {
+    let Some(data) = values.next().ok_or("先序序列提前结束")? else {
+        return Ok(None);
+    };
+    let mut node = leaf(data);
+    node.left = build_pre_order(values)?;
+    node.right = build_pre_order(values)?;
+    Ok(Some(node))
+}
+
+impl<T: Clone + Eq + Hash> BinaryTree<T> {
+    /// 由先序序列和中序序列重建二叉树
+    ///
+    /// # 参数
+    /// * `pre_order` - 先序遍历序列
+    /// * `in_order` - 中序遍历序列
+    ///
+    /// # 返回值
+    /// 重建的二叉树；序列长度不同、含重复值或两者不一致时返回错误
+    ///
+    /// # 示例
+    /// ```
+    /// use data_structure::non_linear::tree::BinaryTree;
+    ///
+    /// let tree = BinaryTree::from_pre_in_order(&['a', 'b', 'c'], &['b', 'a', 'c']).unwrap();
+    /// assert_eq!(tree.level_order_traverse(), vec![&'a', &'b', &'c']);
+    /// assert!(BinaryTree::from_pre_in_order(&[1, 1], &[1, 1]).is_err());
+    /// ```
+    pub fn from_pre_in_order(pre_order: &[T], in_order: &[T]) -> Result<Self, &'static str> {
+        let positions = in_order_positions(pre_order, in_order)?;
+        Ok(BinaryTree(build_with_in_order(
+            pre_order, 0, &positions, true,
+        )?))
+    }
+
+    /// 由后序序列和中序序列重建二叉树
+    ///
+    /// # 参数
+    /// * `post_order` - 后序遍历序列
+    /// * `in_order` - 中序遍历序列
+    ///
+    /// # 返回值
+    /// 重建的二叉树；序列长度不同、含重复值或两者不一致时返回错误
+    pub fn from_post_in_order(post_order: &[T], in_order: &[T]) -> Result<Self, &'static str> {
+        let positions = in_order_positions(post_order, in_order)?;
+        Ok(BinaryTree(build_with_in_order(
+            post_order, 0, &positions, false,
+        )?))
+    }
+}
+
+impl<T> BinaryTree<T> {
+    /// 由带空标记的层序序列重建二叉树
+    ///
+    /// 序列逐层从左到右列出每个非空节点的左右子节点，`None` 表示空子树；
+    /// 末尾的 `None` 可以省略。
+    ///
+    /// # 参数
+    /// * `values` - 层序序列，`None` 为空标记
+    ///
+    /// # 返回值
+    /// 重建的二叉树；空节点之后仍有节点时返回错误
+    ///
+    /// # 示例
+    /// ```
+    /// use data_structure::non_linear::tree::BinaryTree;
+    ///
+    /// let tree = BinaryTree::from_level_order([Some(1), None, Some(2), Some(3)]).unwrap();
+    /// assert_eq!(tree.in_order_traverse(), vec![&1, &3, &2]);
+    /// assert!(BinaryTree::from_level_order([None, Some(1)]).is_err());
+    /// ```
+    pub fn from_level_order(
+        values: impl IntoIterator<Item = Option<T>>,
+    ) -> Result<Self, &'static str> {
+        let mut values = values.into_iter();
+        let mut tree = BinaryTree(values.next().flatten().map(leaf));
+        let mut queue: VecDeque<&mut BinaryNode<T>> = tree.0.as_deref_mut().into_iter().collect();
+        while let Some(node) = queue.pop_front() {
+            let BinaryNode { left, right, .. } = node;
+            *left = values.next().flatten().map(leaf);
+            *right = values.next().flatten().map(leaf);
+            queue.extend(left.as_deref_mut());
+            queue.extend(right.as_deref_mut());
+        }
+        // 所有节点的子节点都已读完，剩下的只能是空标记
+        if values.any(|value| value.is_some()) {
+            return Err("层序序列中有节点没有父节点");
+        }
+        Ok(tree)
+    }
+
+    /// 由带空标记的先序序列重建二叉树
+    ///
+    /// 每个空子树都必须用 `None` 标出，例如只有根节点 `1` 的树对应 `[Some(1), None, None]`。
+    ///
+    /// # 参数
+    /// * `values` - 先序序列，`None` 为空标记
+    ///
+    /// # 返回值
+    /// 重建的二叉树；序列提前结束或有多余元素时返回错误
+    pub fn from_pre_order(
+        values: impl IntoIterator<Item = Option<T>>,
+    ) -> Result<Self, &'static str> {
+        let mut values = values.into_iter();
+        let root = build_pre_order(&mut values)?;
+        if values.next().is_some() {
+            return Err("先序序列有多余的元素");
+        }
+        Ok(BinaryTree(root))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_in_order() {
+        //       1
+        //      / \
+        //     2   3
+        //    /   / \
+        //   4   5   6
+        //        \
+        //         7
+        let pre = [1, 2, 4, 3, 5, 7, 6];
+        let in_order = [4, 2, 1, 5, 7, 3, 6];
+        let post = [4, 2, 7, 5, 6, 3, 1];
+        let a = BinaryTree::from_pre_in_order(&pre, &in_order).unwrap();
+        let b = BinaryTree::from_post_in_order(&post, &in_order).unwrap();
+        assert_eq!(a.0, b.0);
+        assert_eq!(a.post_order_traverse(), post.iter().collect::<Vec<_>>());
+        assert_eq!(b.pre_order_traverse(), pre.iter().collect::<Vec<_>>());
+
+        let empty = BinaryTree::<i32>::from_pre_in_order(&[], &[]).unwrap();
+        assert!(empty.if_empty());
+        assert_eq!(
+            BinaryTree::from_pre_in_order(&[1, 2], &[1]).unwrap_err(),
+            "序列长度不一致"
+        );
+        assert_eq!(
+            BinaryTree::from_pre_in_order(&[1, 2, 2], &[2, 1, 2]).unwrap_err(),
+            "序列中有重复的值"
+        );
+        assert_eq!(
+            BinaryTree::from_pre_in_order(&[1, 2, 3], &[2, 1, 4]).unwrap_err(),
+            "遍历序列与中序序列不一致"
+        );
+        // 元素相同但顺序矛盾：中序中 3 在根 1 的右侧，后序却把它排进左子树
+        assert!(BinaryTree::from_post_in_order(&[3, 2, 1], &[2, 1, 3]).is_err());
+    }
+
+    #[test]
+    fn test_from_null_marked() {
+        let level = [
+            Some(1),
+            Some(2),
+            Some(3),
+            Some(4),
+            None,
+            Some(5),
+            Some(6),
+            None,
+            None,
+            None,
+            Some(7),
+        ];
+        let pre = [
+            Some(1),
+            Some(2),
+            Some(4),
+            None,
+            None,
+            None,
+            Some(3),
+            Some(5),
+            None,
+            Some(7),
+            None,
+            None,
+            Some(6),
+            None,
+            None,
+        ];
+        let a = BinaryTree::from_level_order(level).unwrap();
+        let b = BinaryTree::from_pre_order(pre).unwrap();
+        let c =
+            BinaryTree::from_pre_in_order(&[1, 2, 4, 3, 5, 7, 6], &[4, 2, 1, 5, 7, 3, 6]).unwrap();
+        assert_eq!(a.0, c.0);
+        assert_eq!(b.0, c.0);
+
+        // 末尾的空标记可以省略或多写
+        let padded = level.into_iter().chain([None; 4]);
+        assert_eq!(BinaryTree::from_level_order(padded).unwrap().0, c.0);
+        assert!(BinaryTree::<i32>::from_level_order([]).unwrap().if_empty());
+        assert_eq!(
+            BinaryTree::from_level_order([Some(1), None, None, Some(2)]).unwrap_err(),
+            "层序序列中有节点没有父节点"
+        );
+
+        assert!(
+            BinaryTree::<i32>::from_pre_order([None])
+                .unwrap()
+                .if_empty()
+        );
+        assert_eq!(
+            BinaryTree::from_pre_order([Some(1), None]).unwrap_err(),
+            "先序序列提前结束"
+        );
+        assert_eq!(
+            BinaryTree::from_pre_order([Some(1), None, None, Some(2)]).unwrap_err(),
+            "先序序列有多余的元素"
+        );
+    }
+}
@@ -0,0 +1,343 @@
+//! # 树与森林模块
+//!
+//! [`GeneralTree`] 是每个节点可以有任意多个子节点的树（多叉树），[`Forest`] 是若干棵树的有序集合。
+//!
+//! 森林与二叉树之间有一一对应的“左孩子右兄弟”表示：二叉树节点的左指针指向第一个子节点，
+//! 右指针指向下一个兄弟，森林中各棵树的根互为兄弟。转换后：
+//!
+//! - 森林的先序序列等于二叉树的先序序列；
+//! - 森林的后序序列等于二叉树的中序序列。
+//!
+//! 所有遍历与转换都用显式栈或队列实现，遍历和转换本身不会因为树很宽（兄弟链很长）或很深而栈溢出。
+//! 派生的 `Clone`、`PartialEq` 以及释放仍按树的深度递归，很深的树在这些操作上仍可能栈溢出。
+//!
+//! ## 示例
+//! ```
+//! use data_structure::non_linear::tree::general::GeneralTree;
+//!
+//! let mut root = GeneralTree::new("/");
+//! root.push_child("bin");
+//! root.push_child("usr").push_child("lib");
+//! assert_eq!(root.pre_order(), vec![&"/", &"bin", &"usr", &"lib"]);
+//!
+//! let binary = root.clone().into_binary_tree();
+//! assert_eq!(binary.in_order_traverse(), vec![&"bin", &"lib", &"usr", &"/"]);
+//! assert_eq!(GeneralTree::from_binary_tree(binary), Ok(root));
+//! ```
+
+use std::collections::VecDeque;
+use std::ops::{Deref, DerefMut};
+
+use super::{BinaryNode, BinaryTree};
+
+type Link<T> = Option<Box<BinaryNode<T>>>;
+
+/// 多叉树
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GeneralTree<T> {
+    pub data: T,
+    children: Vec<GeneralTree<T>>,
+}
+
+impl<T> GeneralTree<T> {
+    /// 创建只有根节点的树
+    ///
+    /// # 参数
+    /// * `data` - 根节点的数据
+    pub fn new(data: T) -> Self {
+        Self {
+            data,
+            children: Vec::new(),
+        }
+    }
+
+    /// 用已有的子树创建树
+    ///
+    /// # 参数
+    /// * `data` - 根节点的数据
+    /// * `children` - 按顺序排列的子树
+    pub fn with_children(data: T, children: Vec<GeneralTree<T>>) -> Self {
+        Self { data, children }
+    }
+
+    /// 子树列表
+    pub fn children(&self) -> &[GeneralTree<T>] {
+        &self.children
+    }
+
+    /// 子树列表的可变引用，可以增删或调整子树顺序
+    pub fn children_mut(&mut self) -> &mut Vec<GeneralTree<T>> {
+        &mut self.children
+    }
+
+    /// 追加一个子节点
+    ///
+    /// # 参数
+    /// * `data` - 子节点的数据
+    ///
+    /// # 返回值
+    /// 新子节点的可变引用
+    pub fn push_child(&mut self, data: T) -> &mut GeneralTree<T> {
+        self.add_child(GeneralTree::new(data))
+    }
+
+    /// 追加一棵子树
+    ///
+    /// # 参数
+    /// * `child` - 要追加的子树
+    ///
+    /// # 返回值
+    /// 新子树的可变引用
+    pub fn add_child(&mut self, child: GeneralTree<T>) -> &mut GeneralTree<T> {
+        self.children.push(child);
+        self.children.last_mut().unwrap()
+    }
+
+    /// 子节点个数（节点的度）
+    pub fn degree(&self) -> usize {
+        self.children.len()
+    }
+
+    /// 检查是否为叶子节点
+    pub fn is_leaf(&self) -> bool {
+        self.children.is_empty()
+    }
+
+    /// 节点总数
+    pub fn size(&self) -> usize {
+        self.pre_order().len()
+    }
+
+    /// 树的高度（根到最深叶子路径上的节点数）
+    pub fn height(&self) -> usize {
+        levels(std::slice::from_ref(self)).count()
+    }
+
+    /// 先序遍历：先访问根，再依次先序遍历各棵子树
+    pub fn pre_order(&self) -> Vec<&T> {
+        pre_order(std::slice::from_ref(self))
+    }
+
+    /// 后序遍历：先依次后序遍历各棵子树，再访问根
+    pub fn post_order(&self) -> Vec<&T> {
+        post_order(std::slice::from_ref(self))
+    }
+
+    /// 层序遍历
+    pub fn level_order(&self) -> Vec<&T> {
+        levels(std::slice::from_ref(self)).flatten().collect()
+    }
+
+    /// 转换为左孩子右兄弟表示的二叉树，根节点没有右子节点
+    pub fn into_binary_tree(self) -> BinaryTree<T> {
+        Forest(vec![self]).into_binary_tree()
+    }
+
+    /// 由左孩子右兄弟表示的二叉树还原多叉树
+    ///
+    /// # 参数
+    /// * `tree` - 左孩子右兄弟表示的二叉树
+    ///
+    /// # 返回值
+    /// 还原的多叉树；二叉树为空或根节点有右子节点（对应多棵树）时返回错误
+    pub fn from_binary_tree(tree: BinaryTree<T>) -> Result<Self, &'static str> {
+        match tree.0.as_deref() {
+            None => return Err("二叉树为空"),
+            Some(root) if root.right.is_some() => return Err("根节点有兄弟，对应的是森林"),
+            Some(_) => {}
+        }
+        Ok(Forest::from_binary_tree(tree).0.pop().unwrap())
+    }
+}
+
+/// 森林：有序的若干棵多叉树
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Forest<T>(pub Vec<GeneralTree<T>>);
+
+impl<T> Forest<T> {
+    /// 创建空森林
+    pub fn new() -> Self {
+        Forest(Vec::new())
+    }
+
+    /// 森林中所有节点的个数
+    pub fn size(&self) -> usize {
+        self.pre_order().len()
+    }
+
+    /// 先序遍历：依次先序遍历每棵树
+    pub fn pre_order(&self) -> Vec<&T> {
+        pre_order(&self.0)
+    }
+
+    /// 后序遍历：依次后序遍历每棵树，对应二叉树表示的中序遍历
+    pub fn post_order(&self) -> Vec<&T> {
+        post_order(&self.0)
+    }
+
+    /// 层序遍历：各棵树的同一层依次排列
+    pub fn level_order(&self) -> Vec<&T> {
+        levels(&self.0).flatten().collect()
+    }
+
+    /// 转换为左孩子右兄弟表示的二叉树，各棵树的根沿右指针相连
+    pub fn into_binary_tree(self) -> BinaryTree<T> {
+        let mut root: Link<T> = None;
+        let mut stack: Vec<(Vec<GeneralTree<T>>, &mut Link<T>)> = vec![(self.0, &mut root)];
+        while let Some((trees, mut slot)) = stack.pop() {
+            // 一组兄弟沿右指针排成链，每个节点的子树列表接到它的左指针上
+            for GeneralTree { data, children } in trees {
+                let BinaryNode { left, right, .. } = &mut **slot.insert(Box::new(BinaryNode {
+                    data,
+                    left: None,
+                    right: None,
+                }));
+                stack.push((children, left));
+                slot = right;
+            }
+        }
+        BinaryTree(root)
+    }
+
+    /// 由左孩子右兄弟表示的二叉树还原森林
+    ///
+    /// # 参数
+    /// * `tree` - 左孩子右兄弟表示的二叉树
+    pub fn from_binary_tree(tree: BinaryTree<T>) -> Self {
+        let mut forest = Forest::new();
+        let mut stack: Vec<(Link<T>, &mut Vec<GeneralTree<T>>)> = vec![(tree.0, &mut forest.0)];
+        while let Some((mut link, trees)) = stack.pop() {
+            // 先沿右指针收集整组兄弟，再把各自的左子树展开为它们的子树列表
+            let mut lefts = Vec::new();
+            while let Some(node) = link {
+                let BinaryNode { data, left, right } = *node;
+                trees.push(GeneralTree::new(data));
+                lefts.push(left);
+                link = right;
+            }
+            for (tree, left) in trees.iter_mut().zip(lefts) {
+                stack.push((left, &mut tree.children));
+            }
+        }
+        forest
+    }
+}
+
+impl<T> Default for Forest<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Deref for Forest<T> {
+    type Target = Vec<GeneralTree<T>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Forest<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+fn pre_order<T>(trees: &[GeneralTree<T>]) -> Vec<&T> {
+    let mut result = Vec::new();
+    let mut stack: Vec<&GeneralTree<T>> = trees.iter().rev().collect();
+    while let Some(tree) = stack.pop() {
+        result.push(&tree.data);
+        stack.extend(tree.children.iter().rev());
+    }
+    result
+}
+
+fn post_order<T>(trees: &[GeneralTree<T>]) -> Vec<&T> {
+    // 按“根、最后一棵子树、……、第一棵子树”的顺序访问，倒过来就是后序
+    let mut result = Vec::new();
+    let mut stack: Vec<&GeneralTree<T>> = trees.iter().collect();
+    while let Some(tree) = stack.pop() {
+        result.push(&tree.data);
+        stack.extend(tree.children.iter());
+    }
+    result.reverse();
+    result
+}
+
+/// 逐层产生节点数据
+fn levels<T>(trees: &[GeneralTree<T>]) -> impl Iterator<Item = Vec<&T>> {
+    let mut queue: VecDeque<&GeneralTree<T>> = trees.iter().collect();
+    std::iter::from_fn(move || {
+        if queue.is_empty() {
+            return None;
+        }
+        let level: Vec<&GeneralTree<T>> = queue.drain(..).collect();
+        queue.extend(level.iter().flat_map(|tree| tree.children.iter()));
+        Some(level.into_iter().map(|tree| &tree.data).collect())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    //     A         E
+    //   / | \       |
+    //  B  C  D      F
+    //     |
+    //     G
+    fn sample() -> Forest<char> {
+        let mut a = GeneralTree::new('A');
+        a.push_child('B');
+        a.push_child('C').push_child('G');
+        a.push_child('D');
+        let e = GeneralTree::with_children('E', vec![GeneralTree::new('F')]);
+        Forest(vec![a, e])
+    }
+
+    #[test]
+    fn test_forest_traversal_and_conversion() {
+        let forest = sample();
+        assert_eq!(forest.size(), 7);
+        assert_eq!(forest[0].height(), 3);
+        assert_eq!(forest[0].degree(), 3);
+        let collect = |v: Vec<&char>| v.into_iter().collect::<String>();
+        assert_eq!(collect(forest.pre_order()), "ABCGDEF");
+        assert_eq!(collect(forest.post_order()), "BGCDAFE");
+        assert_eq!(collect(forest.level_order()), "AEBCDFG");
+        assert_eq!(collect(forest[0].level_order()), "ABCDG");
+
+        let binary = forest.clone().into_binary_tree();
+        assert_eq!(collect(binary.pre_order_traverse()), "ABCGDEF");
+        assert_eq!(collect(binary.in_order_traverse()), "BGCDAFE");
+        assert_eq!(Forest::from_binary_tree(binary.clone()), forest);
+        assert_eq!(
+            GeneralTree::from_binary_tree(binary),
+            Err("根节点有兄弟，对应的是森林")
+        );
+        let tree = forest[0].clone();
+        assert_eq!(
+            GeneralTree::from_binary_tree(tree.clone().into_binary_tree()),
+            Ok(tree)
+        );
+        assert_eq!(
+            Forest::<i32>::from_binary_tree(BinaryTree::new()),
+            Forest::new()
+        );
+    }
+
+    #[test]
+    fn test_wide_tree() {
+        // 一万个子节点在二叉树表示中是一条一万层的右链
+        let mut root = GeneralTree::new(0);
+        for i in 1..=10_000 {
+            root.push_child(i);
+        }
+        let binary = root.clone().into_binary_tree();
+        assert_eq!(binary.in_order_traverse_iterative().len(), 10_001);
+        let back = GeneralTree::from_binary_tree(binary).unwrap();
+        assert_eq!(back.post_order().first(), Some(&&1));
+        assert_eq!(back, root);
+    }
+}
@@ -0,0 +1,413 @@
+//! # 哈夫曼编码模块
+//!
+//! 用 [`BinaryNode`] 构造哈夫曼树：每次取出权值最小的两棵树合并，直到只剩一棵。
+//! 从根到叶子的路径（左 0 右 1）即为该字节的编码，出现越频繁的字节编码越短。
+//!
+//! 压缩时只需要每个字节的码长：按 `(码长, 字节)` 排序后依次分配的范式哈夫曼编码
+//! 可以由码长唯一还原，因此数据头只保存码长表。压缩后的格式为：
+//!
+//! | 字段 | 长度 |
+//! | --- | --- |
+//! | 原始数据长度（小端） | 8 字节 |
+//! | 出现的字节种数 `n`（小端） | 2 字节 |
+//! | `(字节, 码长)` | `2n` 字节 |
+//! | 编码后的比特流（高位在前，末尾补 0） | 剩余部分 |
+//!
+//! ## 示例
+//! ```
+//! use data_structure::non_linear::tree::huffman::{decode, encode, HuffmanTree};
+//!
+//! let text = b"abracadabra";
+//! let tree = HuffmanTree::from_bytes(text).unwrap();
+//! assert_eq!(tree.code_lengths()[b'a' as usize], 1);
+//!
+//! let compressed = encode(text);
+//! assert_eq!(decode(&compressed).unwrap(), text);
+//! ```
+
+use std::fmt;
+
+use super::{BinaryNode, BinaryTree};
+use crate::non_linear::heap::BinaryHeap;
+
+/// 码表，下标为字节
+pub type CodeTable = [Option<Code>; 256];
+
+/// 哈夫曼树节点中的数据
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Weighted {
+    /// 子树中所有字节出现的总次数
+    pub weight: u64,
+    /// 叶子节点对应的字节，内部节点为 `None`
+    pub symbol: Option<u8>,
+}
+
+/// 一个字节的编码
+///
+/// 码字保存在 `bits` 的低 `len` 位中，高位先输出。哈夫曼码长不超过 64，
+/// 除非输入大到使出现次数构成极长的斐波那契数列（需要 10^13 字节以上）。
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Code {
+    pub bits: u64,
+    pub len: u8,
+}
+
+impl fmt::Display for Code {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for i in (0..self.len).rev() {
+            write!(f, "{}", (self.bits >> i) & 1)?;
+        }
+        Ok(())
+    }
+}
+
+/// 统计每个字节出现的次数
+pub fn frequencies(data: &[u8]) -> [u64; 256] {
+    let mut frequencies = [0; 256];
+    for &byte in data {
+        frequencies[byte as usize] += 1;
+    }
+    frequencies
+}
+
+/// 哈夫曼树
+#[derive(Clone, Debug)]
+pub struct HuffmanTree {
+    tree: BinaryTree<Weighted>,
+}
+
+impl HuffmanTree {
+    /// 由频率表构造哈夫曼树
+    ///
+    /// 权值相同时先创建的树先出队，因此结果是确定的。
+    ///
+    /// # 参数
+    /// * `frequencies` - 每个字节出现的次数
+    ///
+    /// # 返回值
+    /// 所有次数都为 0 时返回 `None`
+    pub fn from_frequencies(frequencies: &[u64; 256]) -> Option<Self> {
+        let mut trees: Vec<Option<Box<BinaryNode<Weighted>>>> = Vec::new();
+        let mut heap = BinaryHeap::min_heap();
+        for (symbol, &weight) in frequencies.iter().enumerate() {
+            if weight > 0 {
+                heap.push((weight, trees.len()));
+                trees.push(Some(Box::new(BinaryNode::new(Weighted {
+                    weight,
+                    symbol: Some(symbol as u8),
+                }))));
+            }
+        }
+        while heap.len() > 1 {
+            let (weight_a, a) = heap.pop().unwrap();
+            let (weight_b, b) = heap.pop().unwrap();
+            let weight = weight_a + weight_b;
+            let (left, right) = (trees[a].take(), trees[b].take());
+            heap.push((weight, trees.len()));
+            trees.push(Some(Box::new(BinaryNode {
+                data: Weighted {
+                    weight,
+                    symbol: None,
+                },
+                left,
+                right,
+            })));
+        }
+        let (_, root) = heap.pop()?;
+        Some(Self {
+            tree: BinaryTree(trees[root].take()),
+        })
+    }
+
+    /// 统计输入中每个字节的出现次数并构造哈夫曼树
+    pub fn from_bytes(data: &[u8]) -> Option<Self> {
+        Self::from_frequencies(&frequencies(data))
+    }
+
+    /// 底层的二叉树
+    pub fn tree(&self) -> &BinaryTree<Weighted> {
+        &self.tree
+    }
+
+    /// 所有字节出现的总次数
+    pub fn total_weight(&self) -> u64 {
+        self.tree.as_ref().map_or(0, |root| root.data.weight)
+    }
+
+    /// 按深度优先的顺序访问每个叶子及其编码
+    fn for_each_leaf(&self, mut visit: impl FnMut(u8, Code)) {
+        let mut stack: Vec<(&BinaryNode<Weighted>, Code)> = Vec::new();
+        stack.extend(
+            self.tree
+                .as_deref()
+                .map(|root| (root, Code { bits: 0, len: 0 })),
+        );
+        while let Some((node, code)) = stack.pop() {
+            if let Some(symbol) = node.data.symbol {
+                // 只有一种字节时树只有根，仍然用一位编码
+                visit(
+                    symbol,
+                    Code {
+                        len: code.len.max(1),
+                        ..code
+                    },
+                );
+                continue;
+            }
+            for (bit, child) in [(0, &node.left), (1, &node.right)] {
+                if let Some(child) = child.as_deref() {
+                    let bits = code.bits << 1 | bit;
+                    stack.push((
+                        child,
+                        Code {
+                            bits,
+                            len: code.len + 1,
+                        },
+                    ));
+                }
+            }
+        }
+    }
+
+    /// 每个字节的码长，未出现的字节为 0
+    pub fn code_lengths(&self) -> [u8; 256] {
+        let mut lengths = [0; 256];
+        self.for_each_leaf(|symbol, code| lengths[symbol as usize] = code.len);
+        lengths
+    }
+
+    /// 由树的形状直接得到的码表：向左为 0，向右为 1
+    pub fn codes(&self) -> CodeTable {
+        let mut codes = [None; 256];
+        self.for_each_leaf(|symbol, code| codes[symbol as usize] = Some(code));
+        codes
+    }
+
+    /// 码长与树相同的范式哈夫曼码表
+    pub fn canonical_codes(&self) -> CodeTable {
+        canonical_codes(&self.code_lengths())
+    }
+}
+
+/// 由码长表生成范式哈夫曼码表
+///
+/// 按 `(码长, 字节)` 排序后依次分配码字：下一个码字为上一个码字加一，
+/// 码长增加时再左移补 0。
+///
+/// # 参数
+/// * `lengths` - 每个字节的码长，0 表示未出现
+pub fn canonical_codes(lengths: &[u8; 256]) -> CodeTable {
+    let mut symbols: Vec<u8> = (0..=255).filter(|&s| lengths[s as usize] > 0).collect();
+    symbols.sort_by_key(|&s| (lengths[s as usize], s));
+    let mut codes = [None; 256];
+    let mut bits = 0u64;
+    let mut previous = 0;
+    for symbol in symbols {
+        let len = lengths[symbol as usize];
+        if previous > 0 {
+            bits = (bits + 1) << (len - previous);
+        }
+        codes[symbol as usize] = Some(Code { bits, len });
+        previous = len;
+    }
+    codes
+}
+
+/// 按高位在前的顺序把比特写入字节流
+struct BitWriter {
+    bytes: Vec<u8>,
+    used: u8,
+}
+
+impl BitWriter {
+    fn write(&mut self, code: Code) {
+        for i in (0..code.len).rev() {
+            if self.used == 0 {
+                self.bytes.push(0);
+            }
+            let bit = ((code.bits >> i) & 1) as u8;
+            *self.bytes.last_mut().unwrap() |= bit << (7 - self.used);
+            self.used = (self.used + 1) % 8;
+        }
+    }
+}
+
+/// 用范式哈夫曼编码压缩字节流，格式见模块文档
+pub fn encode(data: &[u8]) -> Vec<u8> {
+    let lengths = HuffmanTree::from_bytes(data).map_or([0; 256], |tree| tree.code_lengths());
+    let codes = canonical_codes(&lengths);
+    let mut writer = BitWriter {
+        bytes: Vec::new(),
+        used: 0,
+    };
+    writer.bytes.extend((data.len() as u64).to_le_bytes());
+    let symbols: Vec<u8> = (0..=255).filter(|&s| lengths[s as usize] > 0).collect();
+    writer.bytes.extend((symbols.len() as u16).to_le_bytes());
+    for symbol in symbols {
+        writer.bytes.extend([symbol, lengths[symbol as usize]]);
+    }
+    for &byte in data {
+        writer.write(codes[byte as usize].unwrap());
+    }
+    writer.bytes
+}
+
+/// 解压 [`encode`] 的输出
+///
+/// # 返回值
+/// 数据头不完整、码长表不合法或比特流提前结束时返回错误
+pub fn decode(data: &[u8]) -> Result<Vec<u8>, &'static str> {
+    let header = data.get(..10).ok_or("数据头不完整")?;
+    let len = u64::from_le_bytes(header[..8].try_into().unwrap());
+    let count = u16::from_le_bytes(header[8..].try_into().unwrap()) as usize;
+    let table = data.get(10..10 + 2 * count).ok_or("数据头不完整")?;
+    let mut lengths = [0u8; 256];
+    for pair in table.chunks(2) {
+        if !(1..=64).contains(&pair[1]) || lengths[pair[0] as usize] != 0 {
+            return Err("码长表不合法");
+        }
+        lengths[pair[0] as usize] = pair[1];
+    }
+    // Kraft 不等式：码长满足它时范式编码才是前缀码
+    let kraft: u128 = lengths
+        .iter()
+        .filter(|&&len| len > 0)
+        .map(|&len| 1u128 << (64 - len))
+        .sum();
+    if kraft > 1 << 64 {
+        return Err("码长表不合法");
+    }
+    if len > 0 && count == 0 {
+        return Err("码长表不合法");
+    }
+
+    // 由范式码表重建解码树，叶子保存字节
+    let mut root = BinaryNode::new(None);
+    for (symbol, code) in canonical_codes(&lengths).iter().enumerate() {
+        let Some(code) = code else {
+            continue;
+        };
+        let mut node = &mut root;
+        for i in (0..code.len).rev() {
+            let child = if (code.bits >> i) & 1 == 0 {
+                &mut node.left
+            } else {
+                &mut node.right
+            };
+            node = child.get_or_insert_with(|| Box::new(BinaryNode::new(None)));
+        }
+        node.data = Some(symbol as u8);
+    }
+
+    // 每个码字至少占一比特，先用比特数检查数据头中的长度，再按它分配内存
+    let payload = &data[10 + 2 * count..];
+    if len > payload.len() as u64 * 8 {
+        return Err("比特流提前结束");
+    }
+    let mut output = Vec::with_capacity(len as usize);
+    let mut node = &root;
+    let bits = payload
+        .iter()
+        .flat_map(|byte| (0..8).rev().map(move |i| (byte >> i) & 1));
+    for bit in bits {
+        if output.len() as u64 == len {
+            break;
+        }
+        let child = if bit == 0 { &node.left } else { &node.right };
+        node = child.as_deref().ok_or("比特流中有无效的码字")?;
+        if let Some(symbol) = node.data {
+            output.push(symbol);
+            node = &root;
+        }
+    }
+    if output.len() as u64 != len {
+        return Err("比特流提前结束");
+    }
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_huffman_tree() {
+        // 《算法导论》中的例子
+        let mut frequencies = [0; 256];
+        for (symbol, weight) in [
+            (b'a', 45),
+            (b'b', 13),
+            (b'c', 12),
+            (b'd', 16),
+            (b'e', 9),
+            (b'f', 5),
+        ] {
+            frequencies[symbol as usize] = weight;
+        }
+        let tree = HuffmanTree::from_frequencies(&frequencies).unwrap();
+        assert_eq!(tree.total_weight(), 100);
+        let lengths = tree.code_lengths();
+        let expected = [
+            (b'a', 1),
+            (b'b', 3),
+            (b'c', 3),
+            (b'd', 3),
+            (b'e', 4),
+            (b'f', 4),
+        ];
+        for (symbol, len) in expected {
+            assert_eq!(lengths[symbol as usize], len);
+        }
+        // 树形编码与范式编码码长相同，且范式编码按字节顺序递增
+        let codes = tree.codes();
+        let canonical = tree.canonical_codes();
+        for (symbol, _) in expected {
+            assert_eq!(
+                codes[symbol as usize].unwrap().len,
+                canonical[symbol as usize].unwrap().len
+            );
+        }
+        let canonical: Vec<String> = expected
+            .iter()
+            .map(|(symbol, _)| canonical[*symbol as usize].unwrap().to_string())
+            .collect();
+        assert_eq!(canonical, vec!["0", "100", "101", "110", "1110", "1111"]);
+        assert!(HuffmanTree::from_frequencies(&[0; 256]).is_none());
+    }
+
+    #[test]
+    fn test_huffman_encode_decode() {
+        let inputs: [&[u8]; 5] = [
+            b"",
+            b"aaaaaaaa",
+            b"the quick brown fox jumps over the lazy dog",
+            &(0..=255).collect::<Vec<u8>>(),
+            &(0..5000u32).map(|i| (i * i % 7) as u8).collect::<Vec<u8>>(),
+        ];
+        for input in inputs {
+            let compressed = encode(input);
+            assert_eq!(decode(&compressed).unwrap(), input);
+        }
+        // 出现频率悬殊时可以明显压缩
+        let skewed: Vec<u8> = (0..1000)
+            .map(|i| if i % 10 == 0 { b'x' } else { b'y' })
+            .collect();
+        assert!(encode(&skewed).len() < 150);
+
+        let compressed = encode(b"hello");
+        assert_eq!(decode(&compressed[..5]), Err("数据头不完整"));
+        assert_eq!(
+            decode(&compressed[..compressed.len() - 1]),
+            Err("比特流提前结束")
+        );
+        let mut invalid = compressed.clone();
+        invalid[11] = 0;
+        assert_eq!(decode(&invalid), Err("码长表不合法"));
+        // 数据头中的长度被篡改时返回错误，而不是按它分配内存
+        for len in [u64::MAX, 1 << 40, 5 + 8 * compressed.len() as u64] {
+            let mut corrupted = compressed.clone();
+            corrupted[..8].copy_from_slice(&len.to_le_bytes());
+            assert_eq!(decode(&corrupted), Err("比特流提前结束"));
+        }
+    }
+}
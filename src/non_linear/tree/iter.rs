@@ -0,0 +1,318 @@
+//! # 二叉树迭代器模块
+//!
+//! 为 [`BinaryTree`] 提供按需遍历的迭代器，不会预先把所有节点收集到向量中，
+//! 因此可以提前结束遍历：
+//! - [`Iter`]：产生 `&T`
+//! - [`IterMut`]：产生 `&mut T`
+//! - [`IntoIter`]：消耗二叉树并产生 `T`
+//!
+//! 先序、中序、后序遍历使用内部栈，层序遍历使用内部队列。
+//!
+//! ## 示例
+//! ```
+//! use data_structure::non_linear::tree::{BinaryNode, BinaryTree, TraversalOrder};
+//!
+//! let mut root = BinaryNode::new(2);
+//! root.left_mut(1).right_mut(3);
+//! let mut tree = BinaryTree::new();
+//! tree.set_root(root);
+//!
+//! assert_eq!(tree.iter(TraversalOrder::PreOrder).collect::<Vec<_>>(), vec![&2, &1, &3]);
+//! for data in &mut tree {
+//!     *data *= 10;
+//! }
+//! assert_eq!(tree.into_iter().collect::<Vec<_>>(), vec![10, 20, 30]);
+//! ```
+
+use std::collections::VecDeque;
+
+use super::{BinaryNode, BinaryTree};
+
+/// 二叉树的遍历顺序
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TraversalOrder {
+    /// 先序：根、左、右
+    PreOrder,
+    /// 中序：左、根、右
+    InOrder,
+    /// 后序：左、右、根
+    PostOrder,
+    /// 层序：从上到下、从左到右
+    LevelOrder,
+}
+
+/// 栈中的一项：尚未展开的子树，或已经轮到访问的数据
+enum Frame<N, D> {
+    Node(N),
+    Data(D),
+}
+
+/// 把子树按遍历顺序展开压栈，出栈顺序与压栈顺序相反
+fn expand<N, D>(
+    stack: &mut Vec<Frame<N, D>>,
+    order: TraversalOrder,
+    data: D,
+    left: Option<N>,
+    right: Option<N>,
+) {
+    let data = Frame::Data(data);
+    let left = left.map(Frame::Node);
+    let right = right.map(Frame::Node);
+    let frames = match order {
+        TraversalOrder::PreOrder => [right, left, Some(data)],
+        TraversalOrder::InOrder => [right, Some(data), left],
+        _ => [Some(data), right, left],
+    };
+    stack.extend(frames.into_iter().flatten());
+}
+
+/// 产生节点数据不可变引用的迭代器
+pub struct Iter<'a, T> {
+    order: TraversalOrder,
+    stack: Vec<Frame<&'a BinaryNode<T>, &'a T>>,
+    queue: VecDeque<&'a BinaryNode<T>>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.order == TraversalOrder::LevelOrder {
+            let node = self.queue.pop_front()?;
+            self.queue.extend(node.left.as_deref());
+            self.queue.extend(node.right.as_deref());
+            return Some(&node.data);
+        }
+        loop {
+            match self.stack.pop()? {
+                Frame::Data(data) => return Some(data),
+                Frame::Node(node) => expand(
+                    &mut self.stack,
+                    self.order,
+                    &node.data,
+                    node.left.as_deref(),
+                    node.right.as_deref(),
+                ),
+            }
+        }
+    }
+}
+
+/// 产生节点数据可变引用的迭代器
+pub struct IterMut<'a, T> {
+    order: TraversalOrder,
+    stack: Vec<Frame<&'a mut BinaryNode<T>, &'a mut T>>,
+    queue: VecDeque<&'a mut BinaryNode<T>>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.order == TraversalOrder::LevelOrder {
+            let BinaryNode { data, left, right } = self.queue.pop_front()?;
+            self.queue.extend(left.as_deref_mut());
+            self.queue.extend(right.as_deref_mut());
+            return Some(data);
+        }
+        loop {
+            match self.stack.pop()? {
+                Frame::Data(data) => return Some(data),
+                Frame::Node(BinaryNode { data, left, right }) => expand(
+                    &mut self.stack,
+                    self.order,
+                    data,
+                    left.as_deref_mut(),
+                    right.as_deref_mut(),
+                ),
+            }
+        }
+    }
+}
+
+/// 消耗二叉树并按遍历顺序产生节点数据的迭代器
+pub struct IntoIter<T> {
+    order: TraversalOrder,
+    stack: Vec<Frame<Box<BinaryNode<T>>, T>>,
+    queue: VecDeque<Box<BinaryNode<T>>>,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.order == TraversalOrder::LevelOrder {
+            let BinaryNode { data, left, right } = *self.queue.pop_front()?;
+            self.queue.extend(left);
+            self.queue.extend(right);
+            return Some(data);
+        }
+        loop {
+            match self.stack.pop()? {
+                Frame::Data(data) => return Some(data),
+                Frame::Node(node) => {
+                    let BinaryNode { data, left, right } = *node;
+                    expand(&mut self.stack, self.order, data, left, right);
+                }
+            }
+        }
+    }
+}
+
+impl<T> BinaryTree<T> {
+    /// 按指定顺序遍历的不可变迭代器
+    ///
+    /// # 参数
+    /// * `order` - 遍历顺序
+    pub fn iter(&self, order: TraversalOrder) -> Iter<'_, T> {
+        let mut iter = Iter {
+            order,
+            stack: Vec::new(),
+            queue: VecDeque::new(),
+        };
+        if let Some(root) = self.0.as_deref() {
+            if order == TraversalOrder::LevelOrder {
+                iter.queue.push_back(root);
+            } else {
+                iter.stack.push(Frame::Node(root));
+            }
+        }
+        iter
+    }
+
+    /// 按指定顺序遍历的可变迭代器
+    ///
+    /// # 参数
+    /// * `order` - 遍历顺序
+    pub fn iter_mut(&mut self, order: TraversalOrder) -> IterMut<'_, T> {
+        let mut iter = IterMut {
+            order,
+            stack: Vec::new(),
+            queue: VecDeque::new(),
+        };
+        if let Some(root) = self.0.as_deref_mut() {
+            if order == TraversalOrder::LevelOrder {
+                iter.queue.push_back(root);
+            } else {
+                iter.stack.push(Frame::Node(root));
+            }
+        }
+        iter
+    }
+
+    /// 消耗二叉树，按指定顺序产生节点数据
+    ///
+    /// # 参数
+    /// * `order` - 遍历顺序
+    pub fn into_iter_with(self, order: TraversalOrder) -> IntoIter<T> {
+        let mut iter = IntoIter {
+            order,
+            stack: Vec::new(),
+            queue: VecDeque::new(),
+        };
+        if let Some(root) = self.0 {
+            if order == TraversalOrder::LevelOrder {
+                iter.queue.push_back(root);
+            } else {
+                iter.stack.push(Frame::Node(root));
+            }
+        }
+        iter
+    }
+}
+
+/// 默认按中序遍历
+impl<'a, T> IntoIterator for &'a BinaryTree<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter(TraversalOrder::InOrder)
+    }
+}
+
+/// 默认按中序遍历
+impl<'a, T> IntoIterator for &'a mut BinaryTree<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut(TraversalOrder::InOrder)
+    }
+}
+
+/// 默认按中序遍历
+impl<T> IntoIterator for BinaryTree<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_iter_with(TraversalOrder::InOrder)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_tree() -> BinaryTree<i32> {
+        //       1
+        //      / \
+        //     2   3
+        //    / \   \
+        //   4   5   6
+        let mut root = BinaryNode::new(1);
+        root.left_mut(2).right_mut(3);
+        root.next_left_node().unwrap().left_mut(4).right_mut(5);
+        root.next_right_node().unwrap().right_mut(6);
+        let mut tree = BinaryTree::new();
+        tree.set_root(root);
+        tree
+    }
+
+    #[test]
+    fn test_iter_orders() {
+        let tree = sample_tree();
+        let orders = [
+            (TraversalOrder::PreOrder, tree.pre_order_traverse()),
+            (TraversalOrder::InOrder, tree.in_order_traverse()),
+            (TraversalOrder::PostOrder, tree.post_order_traverse()),
+            (TraversalOrder::LevelOrder, tree.level_order_traverse()),
+        ];
+        for (order, expected) in orders {
+            assert_eq!(tree.iter(order).collect::<Vec<_>>(), expected);
+            let mut copy = tree.clone();
+            let owned: Vec<i32> = expected.iter().map(|x| **x).collect();
+            assert_eq!(copy.iter_mut(order).map(|x| *x).collect::<Vec<_>>(), owned);
+            assert_eq!(copy.into_iter_with(order).collect::<Vec<_>>(), owned);
+        }
+        // 迭代器是惰性的，可以提前结束
+        assert_eq!(
+            tree.iter(TraversalOrder::LevelOrder).find(|x| **x > 2),
+            Some(&3)
+        );
+        assert_eq!(
+            BinaryTree::<i32>::new()
+                .iter(TraversalOrder::PostOrder)
+                .next(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_into_iterator() {
+        let mut tree = sample_tree();
+        for data in &mut tree {
+            *data += 1;
+        }
+        let mut sum = 0;
+        for data in &tree {
+            sum += data;
+        }
+        assert_eq!(sum, 27);
+        for data in tree.iter_mut(TraversalOrder::PostOrder).take(2) {
+            *data = 0;
+        }
+        assert_eq!(tree.into_iter().collect::<Vec<_>>(), vec![0, 3, 0, 2, 4, 7]);
+    }
+}
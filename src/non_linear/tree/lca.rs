@@ -0,0 +1,412 @@
+//! # 最近公共祖先与路径查询
+//!
+//! 提供两种单次查询的最近公共祖先（LCA）算法：
+//!
+//! - 递归版：后序遍历，第一个左右子树（或自身）同时包含两个目标的节点就是答案；
+//! - 父指针版：按层序记录每个节点的父节点与深度，两个节点先上移到同一深度，再一起上移直到相遇。
+//!   不使用递归，适合很深的树。
+//!
+//! 需要反复查询时，[`LcaIndex`] 用倍增法预处理，每次查询 O(log n)。
+//! 以上查询都按数据匹配节点，数据重复时取层序中的第一个。
+//!
+//! ## 示例
+//! ```
+//! use data_structure::non_linear::tree::BinaryTree;
+//! use data_structure::non_linear::tree::lca::LcaIndex;
+//!
+//! let tree = BinaryTree::from_pre_in_order(&[1, 2, 4, 5, 3], &[4, 2, 5, 1, 3]).unwrap();
+//! assert_eq!(tree.lowest_common_ancestor(&4, &5), Some(&2));
+//! assert_eq!(tree.path_between(&4, &3), Some(vec![&4, &2, &1, &3]));
+//!
+//! let index = LcaIndex::new(&tree).unwrap();
+//! assert_eq!(index.lca(&5, &3), Some(&1));
+//! assert_eq!(index.distance(&4, &5), Some(2));
+//! ```
+
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+
+use super::{BinaryNode, BinaryTree};
+
+/// 按层序编号的父节点表，编号 0 为根
+struct Parents<'a, T> {
+    data: Vec<&'a T>,
+    parent: Vec<Option<usize>>,
+    depth: Vec<usize>,
+}
+
+impl<'a, T> Parents<'a, T> {
+    fn new(tree: &'a BinaryTree<T>) -> Self {
+        let mut parents = Parents {
+            data: Vec::new(),
+            parent: Vec::new(),
+            depth: Vec::new(),
+        };
+        let mut queue: VecDeque<(&BinaryNode<T>, Option<usize>)> = tree
+            .0
+            .as_deref()
+            .map(|root| (root, None))
+            .into_iter()
+            .collect();
+        while let Some((node, parent)) = queue.pop_front() {
+            let i = parents.data.len();
+            parents.data.push(&node.data);
+            parents.parent.push(parent);
+            parents
+                .depth
+                .push(parent.map_or(0, |p| parents.depth[p] + 1));
+            for child in [node.left.as_deref(), node.right.as_deref()]
+                .into_iter()
+                .flatten()
+            {
+                queue.push_back((child, Some(i)));
+            }
+        }
+        parents
+    }
+
+    fn find(&self, data: &T) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        self.data.iter().position(|d| *d == data)
+    }
+
+    /// 从节点沿父节点上移到根，包括节点自身
+    fn ancestors(&self, i: usize) -> impl Iterator<Item = usize> + '_ {
+        std::iter::successors(Some(i), |&i| self.parent[i])
+    }
+
+    fn lca(&self, mut a: usize, mut b: usize) -> usize {
+        while self.depth[a] > self.depth[b] {
+            a = self.parent[a].unwrap();
+        }
+        while self.depth[b] > self.depth[a] {
+            b = self.parent[b].unwrap();
+        }
+        while a != b {
+            a = self.parent[a].unwrap();
+            b = self.parent[b].unwrap();
+        }
+        a
+    }
+}
+
+/// 递归查找：返回子树中是否包含`a`、`b`，以及两者都在子树中时的最近公共祖先
+fn search<'a, T: PartialEq>(
+    node: Option<&'a BinaryNode<T>>,
+    a: &T,
+    b: &T,
+) -> (bool, bool, Option<&'a T>) {
+    let Some(node) = node else {
+        return (false, false, None);
+    };
+    let left = search(node.left.as_deref(), a, b);
+    if left.2.is_some() {
+        return left;
+    }
+    let right = search(node.right.as_deref(), a, b);
+    if right.2.is_some() {
+        return right;
+    }
+    let found_a = left.0 || right.0 || node.data == *a;
+    let found_b = left.1 || right.1 || node.data == *b;
+    (found_a, found_b, (found_a && found_b).then_some(&node.data))
+}
+
+impl<T: PartialEq> BinaryTree<T> {
+    /// 最近公共祖先（递归）
+    ///
+    /// # 参数
+    /// * `a` - 第一个节点的数据
+    /// * `b` - 第二个节点的数据
+    ///
+    /// # 返回值
+    /// 两个节点的最近公共祖先的数据；任一节点不存在时返回None
+    pub fn lowest_common_ancestor(&self, a: &T, b: &T) -> Option<&T> {
+        search(self.0.as_deref(), a, b).2
+    }
+
+    /// 最近公共祖先（父指针，非递归）
+    ///
+    /// # 参数
+    /// * `a` - 第一个节点的数据
+    /// * `b` - 第二个节点的数据
+    ///
+    /// # 返回值
+    /// 两个节点的最近公共祖先的数据；任一节点不存在时返回None
+    pub fn lowest_common_ancestor_by_parent(&self, a: &T, b: &T) -> Option<&T> {
+        let parents = Parents::new(self);
+        let (a, b) = (parents.find(a)?, parents.find(b)?);
+        Some(parents.data[parents.lca(a, b)])
+    }
+
+    /// 从根到节点的路径
+    ///
+    /// # 参数
+    /// * `data` - 目标节点的数据
+    ///
+    /// # 返回值
+    /// 路径上各节点的数据，首项为根、末项为目标节点；节点不存在时返回None
+    pub fn path_to(&self, data: &T) -> Option<Vec<&T>> {
+        let parents = Parents::new(self);
+        let mut path: Vec<&T> = parents
+            .ancestors(parents.find(data)?)
+            .map(|i| parents.data[i])
+            .collect();
+        path.reverse();
+        Some(path)
+    }
+
+    /// 两个节点之间的路径
+    ///
+    /// # 参数
+    /// * `a` - 起点的数据
+    /// * `b` - 终点的数据
+    ///
+    /// # 返回值
+    /// 从`a`经最近公共祖先到`b`的各节点数据；任一节点不存在时返回None
+    pub fn path_between(&self, a: &T, b: &T) -> Option<Vec<&T>> {
+        let parents = Parents::new(self);
+        let (a, b) = (parents.find(a)?, parents.find(b)?);
+        let lca = parents.lca(a, b);
+        let mut path: Vec<&T> = parents
+            .ancestors(a)
+            .take_while(|&i| i != lca)
+            .chain([lca])
+            .map(|i| parents.data[i])
+            .collect();
+        let start = path.len();
+        path.extend(
+            parents
+                .ancestors(b)
+                .take_while(|&i| i != lca)
+                .map(|i| parents.data[i]),
+        );
+        path[start..].reverse();
+        Some(path)
+    }
+
+    /// 两个节点之间的距离
+    ///
+    /// # 参数
+    /// * `a` - 第一个节点的数据
+    /// * `b` - 第二个节点的数据
+    ///
+    /// # 返回值
+    /// 两个节点之间路径的边数；任一节点不存在时返回None
+    pub fn distance(&self, a: &T, b: &T) -> Option<usize> {
+        let parents = Parents::new(self);
+        let (a, b) = (parents.find(a)?, parents.find(b)?);
+        let lca = parents.lca(a, b);
+        Some(parents.depth[a] + parents.depth[b] - 2 * parents.depth[lca])
+    }
+}
+
+/// 倍增法预处理的最近公共祖先索引
+///
+/// 预处理 O(n log n)，之后每次查询 O(log n)。索引借用原来的树，要求节点数据互不相同。
+pub struct LcaIndex<'a, T> {
+    data: Vec<&'a T>,
+    index: HashMap<&'a T, usize>,
+    depth: Vec<usize>,
+    /// `up[k][i]` 是节点`i`的第`2^k`个祖先，超出根时为根
+    up: Vec<Vec<usize>>,
+}
+
+impl<'a, T: Eq + Hash> LcaIndex<'a, T> {
+    /// 为二叉树建立索引
+    ///
+    /// # 参数
+    /// * `tree` - 要建立索引的二叉树
+    ///
+    /// # 返回值
+    /// 建好的索引；树中有重复数据时返回错误
+    pub fn new(tree: &'a BinaryTree<T>) -> Result<Self, &'static str> {
+        let Parents {
+            data,
+            parent,
+            depth,
+        } = Parents::new(tree);
+        let mut index = HashMap::with_capacity(data.len());
+        for (i, &value) in data.iter().enumerate() {
+            if index.insert(value, i).is_some() {
+                return Err("树中有重复的值");
+            }
+        }
+        let mut up = vec![parent.iter().map(|p| p.unwrap_or(0)).collect::<Vec<_>>()];
+        let max_depth = depth.iter().copied().max().unwrap_or(0);
+        while 1 << up.len() <= max_depth {
+            let last = up.last().unwrap();
+            up.push(last.iter().map(|&i| last[i]).collect());
+        }
+        Ok(Self {
+            data,
+            index,
+            depth,
+            up,
+        })
+    }
+
+    /// 索引中的节点数
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// 检查索引是否为空
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// 节点的深度，根的深度为0
+    pub fn depth(&self, data: &T) -> Option<usize> {
+        Some(self.depth[*self.index.get(data)?])
+    }
+
+    /// 节点`i`向上第`k`个祖先的编号，`k`不超过节点深度
+    fn lift(&self, mut i: usize, k: usize) -> usize {
+        for (bit, up) in self.up.iter().enumerate() {
+            if k >> bit & 1 == 1 {
+                i = up[i];
+            }
+        }
+        i
+    }
+
+    /// 节点向上第`k`个祖先
+    ///
+    /// # 参数
+    /// * `data` - 节点的数据
+    /// * `k` - 向上的层数，0 为节点自身
+    ///
+    /// # 返回值
+    /// 祖先的数据；节点不存在或`k`超过节点深度时返回None
+    pub fn kth_ancestor(&self, data: &T, k: usize) -> Option<&'a T> {
+        let i = *self.index.get(data)?;
+        (k <= self.depth[i]).then(|| self.data[self.lift(i, k)])
+    }
+
+    fn lca_index(&self, a: usize, b: usize) -> usize {
+        let (a, b) = if self.depth[a] >= self.depth[b] {
+            (a, b)
+        } else {
+            (b, a)
+        };
+        let mut a = self.lift(a, self.depth[a] - self.depth[b]);
+        let mut b = b;
+        if a == b {
+            return a;
+        }
+        // 从大到小尝试跳跃，只要跳完仍不相遇就跳
+        for up in self.up.iter().rev() {
+            if up[a] != up[b] {
+                a = up[a];
+                b = up[b];
+            }
+        }
+        self.up[0][a]
+    }
+
+    /// 最近公共祖先
+    ///
+    /// # 参数
+    /// * `a` - 第一个节点的数据
+    /// * `b` - 第二个节点的数据
+    ///
+    /// # 返回值
+    /// 两个节点的最近公共祖先的数据；任一节点不存在时返回None
+    pub fn lca(&self, a: &T, b: &T) -> Option<&'a T> {
+        let (a, b) = (*self.index.get(a)?, *self.index.get(b)?);
+        Some(self.data[self.lca_index(a, b)])
+    }
+
+    /// 两个节点之间的距离（边数）
+    pub fn distance(&self, a: &T, b: &T) -> Option<usize> {
+        let (a, b) = (*self.index.get(a)?, *self.index.get(b)?);
+        let lca = self.lca_index(a, b);
+        Some(self.depth[a] + self.depth[b] - 2 * self.depth[lca])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    //         1
+    //        / \
+    //       2   3
+    //      / \   \
+    //     4   5   6
+    //        / \
+    //       7   8
+    fn sample() -> BinaryTree<i32> {
+        BinaryTree::from_level_order(
+            [1, 2, 3, 4, 5, 0, 6, 0, 0, 7, 8]
+                .into_iter()
+                .map(|x| (x != 0).then_some(x)),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_lca_and_paths() {
+        let tree = sample();
+        let cases = [
+            ((7, 4), 2),
+            ((7, 8), 5),
+            ((8, 6), 1),
+            ((5, 7), 5),
+            ((3, 3), 3),
+        ];
+        let index = LcaIndex::new(&tree).unwrap();
+        for ((a, b), lca) in cases {
+            assert_eq!(tree.lowest_common_ancestor(&a, &b), Some(&lca));
+            assert_eq!(tree.lowest_common_ancestor_by_parent(&a, &b), Some(&lca));
+            assert_eq!(index.lca(&a, &b), Some(&lca));
+            assert_eq!(tree.distance(&a, &b), index.distance(&a, &b));
+        }
+        assert_eq!(tree.lowest_common_ancestor(&7, &9), None);
+        assert_eq!(tree.lowest_common_ancestor_by_parent(&9, &7), None);
+        assert_eq!(index.lca(&9, &7), None);
+
+        assert_eq!(tree.path_to(&8), Some(vec![&1, &2, &5, &8]));
+        assert_eq!(tree.path_to(&1), Some(vec![&1]));
+        assert_eq!(tree.path_to(&9), None);
+        assert_eq!(
+            tree.path_between(&7, &6),
+            Some(vec![&7, &5, &2, &1, &3, &6])
+        );
+        assert_eq!(tree.path_between(&2, &8), Some(vec![&2, &5, &8]));
+        assert_eq!(tree.path_between(&8, &8), Some(vec![&8]));
+        assert_eq!(tree.distance(&7, &6), Some(5));
+
+        assert_eq!(index.depth(&8), Some(3));
+        assert_eq!(index.kth_ancestor(&8, 2), Some(&2));
+        assert_eq!(index.kth_ancestor(&8, 3), Some(&1));
+        assert_eq!(index.kth_ancestor(&8, 4), None);
+        assert_eq!(index.len(), 8);
+
+        let dup = BinaryTree::from_level_order([Some(1), Some(1)]).unwrap();
+        assert_eq!(LcaIndex::new(&dup).err(), Some("树中有重复的值"));
+    }
+
+    #[test]
+    fn test_lca_index_deep_chain() {
+        // 深度 1000 的左斜链，倍增表需要 10 层
+        let mut tree = BinaryTree::new();
+        for i in (0..1000).rev() {
+            let mut node = BinaryNode::new(i);
+            node.set_left_subtree(tree);
+            tree = BinaryTree(Some(Box::new(node)));
+        }
+        let index = LcaIndex::new(&tree).unwrap();
+        assert_eq!(index.up.len(), 10);
+        assert_eq!(index.lca(&999, &500), Some(&500));
+        assert_eq!(index.kth_ancestor(&999, 999), Some(&0));
+        assert_eq!(index.distance(&3, &997), Some(994));
+        assert_eq!(
+            tree.lowest_common_ancestor_by_parent(&999, &500),
+            Some(&500)
+        );
+    }
+}
@@ -0,0 +1,235 @@
+//! # 二叉树度量与性质判断
+//!
+//! 高度、节点数、叶子数、每层宽度等度量，以及平衡、完全、满二叉树和二叉搜索树的判断。
+//! 所有方法都用显式队列或栈实现，退化成链表的深树也不会栈溢出。
+//!
+//! ## 示例
+//! ```
+//! use data_structure::non_linear::tree::BinaryTree;
+//!
+//! let tree = BinaryTree::from_level_order([Some(2), Some(1), Some(3), None, None, None, Some(4)]).unwrap();
+//! assert_eq!(tree.height(), 3);
+//! assert_eq!(tree.level_widths(), vec![1, 2, 1]);
+//! assert!(tree.is_bst());
+//! assert!(!tree.is_complete());
+//! ```
+
+use std::collections::VecDeque;
+
+use super::{BinaryNode, BinaryTree, TraversalOrder};
+
+/// 按层序编号的节点及其左右子节点的编号
+type Indexed<'a, T> = Vec<(&'a BinaryNode<T>, Option<usize>, Option<usize>)>;
+
+impl<T> BinaryTree<T> {
+    /// 按层序把节点编号，子节点的编号总是大于父节点
+    pub(super) fn indexed<'a>(&'a self) -> Indexed<'a, T> {
+        let mut nodes: Indexed<'a, T> = Vec::new();
+        let mut queue: VecDeque<&BinaryNode<T>> = self.0.as_deref().into_iter().collect();
+        // 出队顺序就是编号顺序，入队时即可算出子节点的编号
+        let mut next = 1;
+        while let Some(node) = queue.pop_front() {
+            let mut child = |child: Option<&'a BinaryNode<T>>| {
+                queue.push_back(child?);
+                next += 1;
+                Some(next - 1)
+            };
+            let (left, right) = (child(node.left.as_deref()), child(node.right.as_deref()));
+            nodes.push((node, left, right));
+        }
+        nodes
+    }
+
+    /// 逐层访问节点
+    fn levels(&self) -> impl Iterator<Item = Vec<&BinaryNode<T>>> {
+        let root: Vec<&BinaryNode<T>> = self.0.as_deref().into_iter().collect();
+        std::iter::successors(Some(root), |level| {
+            let next: Vec<&BinaryNode<T>> = level
+                .iter()
+                .flat_map(|node| [node.left.as_deref(), node.right.as_deref()])
+                .flatten()
+                .collect();
+            (!next.is_empty()).then_some(next)
+        })
+        .filter(|level| !level.is_empty())
+    }
+
+    /// 树的高度
+    ///
+    /// # 返回值
+    /// 根到最深叶子路径上的节点数，空树为0
+    pub fn height(&self) -> usize {
+        self.levels().count()
+    }
+
+    /// 节点总数
+    pub fn size(&self) -> usize {
+        self.levels().map(|level| level.len()).sum()
+    }
+
+    /// 叶子节点数
+    pub fn leaf_count(&self) -> usize {
+        self.levels()
+            .flatten()
+            .filter(|node| node.left.is_none() && node.right.is_none())
+            .count()
+    }
+
+    /// 每层的节点数
+    ///
+    /// # 返回值
+    /// 第`i`项为深度`i`（根的深度为0）的节点数
+    pub fn level_widths(&self) -> Vec<usize> {
+        self.levels().map(|level| level.len()).collect()
+    }
+
+    /// 最大宽度
+    ///
+    /// # 返回值
+    /// 节点最多的一层的节点数，空树为0
+    pub fn max_width(&self) -> usize {
+        self.levels().map(|level| level.len()).max().unwrap_or(0)
+    }
+
+    /// 检查是否为平衡二叉树
+    ///
+    /// # 返回值
+    /// 每个节点左右子树的高度差都不超过1时返回true
+    pub fn is_balanced(&self) -> bool {
+        let nodes = self.indexed();
+        let mut heights = vec![0usize; nodes.len()];
+        // 子节点的编号大于父节点，倒序处理保证先算出子树高度
+        for (i, &(_, left, right)) in nodes.iter().enumerate().rev() {
+            let left = left.map_or(0, |j| heights[j]);
+            let right = right.map_or(0, |j| heights[j]);
+            if left.abs_diff(right) > 1 {
+                return false;
+            }
+            heights[i] = left.max(right) + 1;
+        }
+        true
+    }
+
+    /// 检查是否为完全二叉树
+    ///
+    /// # 返回值
+    /// 除最后一层外每层都是满的、最后一层的节点都靠左时返回true
+    pub fn is_complete(&self) -> bool {
+        let mut queue: VecDeque<Option<&BinaryNode<T>>> = VecDeque::from([self.0.as_deref()]);
+        // 层序遇到第一个空位后，后面不能再有节点
+        while let Some(Some(node)) = queue.pop_front() {
+            queue.push_back(node.left.as_deref());
+            queue.push_back(node.right.as_deref());
+        }
+        queue.iter().all(Option::is_none)
+    }
+
+    /// 检查是否为满二叉树（每个节点都有0个或2个子节点）
+    pub fn is_full(&self) -> bool {
+        self.levels()
+            .flatten()
+            .all(|node| node.left.is_some() == node.right.is_some())
+    }
+
+    /// 检查是否为二叉搜索树
+    ///
+    /// # 返回值
+    /// 中序序列严格递增时返回true
+    pub fn is_bst(&self) -> bool
+    where
+        T: Ord,
+    {
+        let mut prev = None;
+        self.iter(TraversalOrder::InOrder).all(|data| {
+            let increasing = prev.is_none_or(|prev| prev < data);
+            prev = Some(data);
+            increasing
+        })
+    }
+
+    /// 查找数据等于`data`的节点的深度
+    ///
+    /// # 参数
+    /// * `data` - 要查找的数据
+    ///
+    /// # 返回值
+    /// 层序中第一个匹配节点的深度（根的深度为0），找不到时返回None
+    pub fn depth(&self, data: &T) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        self.levels()
+            .position(|level| level.iter().any(|node| node.data == *data))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_metrics() {
+        //       1
+        //      / \
+        //     2   3
+        //    / \   \
+        //   4   5   6
+        //      /
+        //     7
+        let tree = BinaryTree::from_level_order(
+            [1, 2, 3, 4, 5, 0, 6, 0, 0, 7]
+                .into_iter()
+                .map(|x| (x != 0).then_some(x)),
+        )
+        .unwrap();
+        assert_eq!(tree.height(), 4);
+        assert_eq!(tree.size(), 7);
+        assert_eq!(tree.leaf_count(), 3);
+        assert_eq!(tree.level_widths(), vec![1, 2, 3, 1]);
+        assert_eq!(tree.max_width(), 3);
+        assert_eq!(tree.depth(&7), Some(3));
+        assert_eq!(tree.depth(&1), Some(0));
+        assert_eq!(tree.depth(&8), None);
+        assert!(tree.is_balanced());
+        assert!(!tree.is_complete());
+        assert!(!tree.is_full());
+        assert!(!tree.is_bst());
+
+        let empty = BinaryTree::<i32>::new();
+        assert_eq!((empty.height(), empty.size(), empty.max_width()), (0, 0, 0));
+        assert!(empty.is_balanced() && empty.is_complete() && empty.is_full() && empty.is_bst());
+
+        // 十万层的右斜树：递归实现会栈溢出
+        let n = 100_000;
+        let mut chain = BinaryTree::new();
+        for i in (0..n).rev() {
+            let mut node = BinaryNode::new(i);
+            node.set_right_subtree(chain);
+            chain = BinaryTree(Some(Box::new(node)));
+        }
+        assert_eq!(chain.height(), n as usize);
+        assert_eq!(chain.leaf_count(), 1);
+        assert!(chain.is_bst());
+        assert!(!chain.is_balanced());
+        assert_eq!(chain.depth(&(n - 1)), Some(n as usize - 1));
+        // 逐个拆下节点，避免递归析构栈溢出
+        let mut link = chain.0;
+        while let Some(mut node) = link {
+            link = node.right.take();
+        }
+    }
+
+    #[test]
+    fn test_shape_checks() {
+        let complete = BinaryTree::from_level_order((1..=6).map(Some)).unwrap();
+        assert!(complete.is_complete());
+        assert!(!complete.is_full());
+        let full = BinaryTree::from_level_order((1..=7).map(Some)).unwrap();
+        assert!(full.is_complete() && full.is_full() && full.is_balanced());
+        let gap = BinaryTree::from_level_order([Some(1), Some(2), Some(3), None, Some(4)]).unwrap();
+        assert!(!gap.is_complete());
+        // 中序有重复值不是严格的二叉搜索树
+        let dup = BinaryTree::from_level_order([Some(2), Some(2)]).unwrap();
+        assert!(!dup.is_bst());
+    }
+}
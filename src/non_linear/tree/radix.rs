@@ -0,0 +1,382 @@
+//! # 基数树模块
+//!
+//! 基数树（压缩字典树、Patricia 树）把字典树中只有一个子节点且不存放值的节点链压缩成一条边，
+//! 边上标记一段符号序列。节点数不超过键数的两倍，适合键很长、共享前缀又不多的场景。
+//!
+//! 插入时若新键只与某条边的标签共享一部分，就在分叉处把边拆成两段；
+//! 删除后若节点不再存放值且只剩一个子节点，就把它与子节点的边合并，保持树是压缩的。
+//!
+//! 接口与 [`Trie`](super::trie::Trie) 相同，键可以是 `&str`（按字节）、`&[char]` 或
+//! [`String`](crate::linear::string::String) 等能视为 `[S]` 的类型。
+//!
+//! ## 示例
+//! ```
+//! use data_structure::non_linear::tree::radix::RadixTree;
+//!
+//! let mut tree = RadixTree::new();
+//! tree.insert("romane", 1);
+//! tree.insert("romanus", 2);
+//! tree.insert("romulus", 3);
+//! assert_eq!(tree.count_prefix("roman"), 2);
+//! assert_eq!(tree.longest_prefix_of("romanesque"), Some((6, &1)));
+//! // 根 → "rom" → {"an" → {"e", "us"}, "ulus"}，共 6 个节点
+//! assert_eq!(tree.node_count(), 6);
+//! ```
+
+use std::collections::BTreeMap;
+use std::collections::btree_map::Entry;
+use std::mem;
+
+#[derive(Clone, Debug)]
+struct Edge<S, V> {
+    label: Vec<S>,
+    node: Node<S, V>,
+}
+
+#[derive(Clone, Debug)]
+struct Node<S, V> {
+    value: Option<V>,
+    /// 子树中（含自身）键的个数
+    count: usize,
+    /// 按边标签的首个符号索引
+    children: BTreeMap<S, Edge<S, V>>,
+}
+
+impl<S, V> Node<S, V> {
+    fn new() -> Self {
+        Self {
+            value: None,
+            count: 0,
+            children: BTreeMap::new(),
+        }
+    }
+}
+
+/// 从`node`中删除键`key`，并剪掉空分支、合并单子节点链
+fn remove_at<S: Ord, V>(node: &mut Node<S, V>, key: &[S]) -> Option<V> {
+    let Some(first) = key.first() else {
+        let value = node.value.take()?;
+        node.count -= 1;
+        return Some(value);
+    };
+    let edge = node.children.get_mut(first)?;
+    let rest = key.strip_prefix(edge.label.as_slice())?;
+    let value = remove_at(&mut edge.node, rest)?;
+    node.count -= 1;
+    if edge.node.count == 0 {
+        node.children.remove(first);
+    } else if edge.node.value.is_none() && edge.node.children.len() == 1 {
+        let (_, child) = edge.node.children.pop_first().unwrap();
+        edge.label.extend(child.label);
+        edge.node = child.node;
+    }
+    Some(value)
+}
+
+/// 基数树映射
+#[derive(Clone, Debug)]
+pub struct RadixTree<S, V> {
+    root: Node<S, V>,
+}
+
+impl<S: Ord + Clone, V> RadixTree<S, V> {
+    /// 创建空基数树
+    pub fn new() -> Self {
+        Self { root: Node::new() }
+    }
+
+    /// 键的个数
+    pub fn len(&self) -> usize {
+        self.root.count
+    }
+
+    /// 检查是否为空
+    pub fn is_empty(&self) -> bool {
+        self.root.count == 0
+    }
+
+    /// 节点个数（含根节点）
+    pub fn node_count(&self) -> usize {
+        let mut count = 0;
+        let mut stack = vec![&self.root];
+        while let Some(node) = stack.pop() {
+            count += 1;
+            stack.extend(node.children.values().map(|edge| &edge.node));
+        }
+        count
+    }
+
+    fn find(&self, key: &[S]) -> Option<&Node<S, V>> {
+        let (mut node, mut rest) = (&self.root, key);
+        while let Some(first) = rest.first() {
+            let edge = node.children.get(first)?;
+            rest = rest.strip_prefix(edge.label.as_slice())?;
+            node = &edge.node;
+        }
+        Some(node)
+    }
+
+    fn find_mut(&mut self, key: &[S]) -> Option<&mut Node<S, V>> {
+        let (mut node, mut rest) = (&mut self.root, key);
+        while let Some(first) = rest.first() {
+            let edge = node.children.get_mut(first)?;
+            rest = rest.strip_prefix(edge.label.as_slice())?;
+            node = &mut edge.node;
+        }
+        Some(node)
+    }
+
+    /// 找到覆盖前缀的最浅节点，前缀可以在边的中间结束
+    ///
+    /// 返回从根到该节点的完整符号序列及节点本身。
+    fn locate_prefix(&self, prefix: &[S]) -> Option<(Vec<S>, &Node<S, V>)> {
+        let (mut node, mut rest) = (&self.root, prefix);
+        let mut path = Vec::new();
+        while let Some(first) = rest.first() {
+            let edge = node.children.get(first)?;
+            if !edge.label.starts_with(rest) && !rest.starts_with(&edge.label) {
+                return None;
+            }
+            path.extend_from_slice(&edge.label);
+            rest = &rest[rest.len().min(edge.label.len())..];
+            node = &edge.node;
+        }
+        Some((path, node))
+    }
+
+    /// 插入键值对
+    ///
+    /// # 参数
+    /// * `key` - 键
+    /// * `value` - 值
+    ///
+    /// # 返回值
+    /// 键已存在时返回旧值，否则返回None
+    pub fn insert(&mut self, key: impl AsRef<[S]>, value: V) -> Option<V> {
+        let key = key.as_ref();
+        if let Some(node) = self.find_mut(key)
+            && node.value.is_some()
+        {
+            return node.value.replace(value);
+        }
+        let (mut node, mut rest) = (&mut self.root, key);
+        node.count += 1;
+        while let Some(first) = rest.first() {
+            let edge = match node.children.entry(first.clone()) {
+                Entry::Vacant(entry) => {
+                    let mut leaf = Node::new();
+                    leaf.count = 1;
+                    leaf.value = Some(value);
+                    entry.insert(Edge {
+                        label: rest.to_vec(),
+                        node: leaf,
+                    });
+                    return None;
+                }
+                Entry::Occupied(entry) => entry.into_mut(),
+            };
+            let common = edge
+                .label
+                .iter()
+                .zip(rest)
+                .take_while(|(a, b)| a == b)
+                .count();
+            if common < edge.label.len() {
+                // 在分叉处拆边：前半段指向新的中间节点，后半段挂在中间节点下
+                let tail = edge.label.split_off(common);
+                let lower = mem::replace(&mut edge.node, Node::new());
+                edge.node.count = lower.count;
+                edge.node.children.insert(
+                    tail[0].clone(),
+                    Edge {
+                        label: tail,
+                        node: lower,
+                    },
+                );
+            }
+            edge.node.count += 1;
+            node = &mut edge.node;
+            rest = &rest[common..];
+        }
+        node.value = Some(value);
+        None
+    }
+
+    /// 查找键对应的值
+    pub fn get(&self, key: impl AsRef<[S]>) -> Option<&V> {
+        self.find(key.as_ref())?.value.as_ref()
+    }
+
+    /// 查找键对应的值的可变引用
+    pub fn get_mut(&mut self, key: impl AsRef<[S]>) -> Option<&mut V> {
+        self.find_mut(key.as_ref())?.value.as_mut()
+    }
+
+    /// 检查键是否存在
+    pub fn contains_key(&self, key: impl AsRef<[S]>) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// 删除键
+    ///
+    /// # 参数
+    /// * `key` - 要删除的键
+    ///
+    /// # 返回值
+    /// 键存在时返回对应的值，否则返回None
+    pub fn remove(&mut self, key: impl AsRef<[S]>) -> Option<V> {
+        remove_at(&mut self.root, key.as_ref())
+    }
+
+    /// 以`prefix`为前缀的键的个数
+    pub fn count_prefix(&self, prefix: impl AsRef<[S]>) -> usize {
+        self.locate_prefix(prefix.as_ref())
+            .map_or(0, |(_, node)| node.count)
+    }
+
+    /// 按字典序列出以`prefix`为前缀的所有键值对
+    ///
+    /// # 参数
+    /// * `prefix` - 前缀，空前缀列出全部键
+    ///
+    /// # 返回值
+    /// `(键, 值)`组成的向量
+    pub fn keys_with_prefix(&self, prefix: impl AsRef<[S]>) -> Vec<(Vec<S>, &V)> {
+        let mut result = Vec::new();
+        let Some(start) = self.locate_prefix(prefix.as_ref()) else {
+            return result;
+        };
+        let mut stack = vec![start];
+        while let Some((key, node)) = stack.pop() {
+            for edge in node.children.values().rev() {
+                let mut key = key.clone();
+                key.extend_from_slice(&edge.label);
+                stack.push((key, &edge.node));
+            }
+            if let Some(value) = &node.value {
+                result.push((key, value));
+            }
+        }
+        result
+    }
+
+    /// 最长前缀匹配：在所有是`query`前缀的键中找最长的一个
+    ///
+    /// # 参数
+    /// * `query` - 被匹配的序列
+    ///
+    /// # 返回值
+    /// 匹配的键的长度及其值，没有任何键是`query`的前缀时返回None
+    pub fn longest_prefix_of(&self, query: impl AsRef<[S]>) -> Option<(usize, &V)> {
+        let query = query.as_ref();
+        let (mut node, mut matched) = (&self.root, 0);
+        let mut best = node.value.as_ref().map(|value| (0, value));
+        while let Some(first) = query.get(matched) {
+            let Some(edge) = node.children.get(first) else {
+                break;
+            };
+            if !query[matched..].starts_with(&edge.label) {
+                break;
+            }
+            matched += edge.label.len();
+            node = &edge.node;
+            if let Some(value) = &node.value {
+                best = Some((matched, value));
+            }
+        }
+        best
+    }
+}
+
+impl<S: Ord + Clone, V> Default for RadixTree<S, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S: Ord + Clone, V, K: AsRef<[S]>> FromIterator<(K, V)> for RadixTree<S, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut tree = RadixTree::new();
+        for (key, value) in iter {
+            tree.insert(key, value);
+        }
+        tree
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linear::string::String;
+    use crate::non_linear::tree::trie::Trie;
+
+    /// 检查计数正确、边标签非空且与索引一致、除根外没有可以压缩的节点
+    fn check<S: Ord, V>(node: &Node<S, V>, is_root: bool) -> usize {
+        let mut count = node.value.is_some() as usize;
+        for (first, edge) in &node.children {
+            assert!(edge.label.first() == Some(first));
+            count += check(&edge.node, false);
+        }
+        if !is_root {
+            assert!(node.value.is_some() || node.children.len() >= 2);
+        }
+        assert_eq!(node.count, count);
+        count
+    }
+
+    #[test]
+    fn test_radix_tree_matches_trie() {
+        let text = "the quick brown fox jumps over the lazy dog then quietly \
+                    browses thesaurus entries about foxes jumping through doors";
+        let words: Vec<Vec<char>> = text.split(' ').map(|w| w.chars().collect()).collect();
+        let mut radix = RadixTree::new();
+        let mut trie = Trie::new();
+        for (i, word) in words.iter().enumerate() {
+            let key = String::new(word);
+            assert_eq!(radix.insert(key.clone(), i), trie.insert(key, i));
+            check(&radix.root, true);
+        }
+        assert_eq!(radix.len(), trie.len());
+        assert!(radix.node_count() < trie.node_count());
+        for prefix in ["", "th", "qu", "fox", "b", "x", "thes", "z"] {
+            let prefix: Vec<char> = prefix.chars().collect();
+            assert_eq!(radix.count_prefix(&prefix), trie.count_prefix(&prefix));
+            assert_eq!(
+                radix.keys_with_prefix(&prefix),
+                trie.keys_with_prefix(&prefix)
+            );
+        }
+        let query: Vec<char> = "thenceforth".chars().collect();
+        assert_eq!(radix.longest_prefix_of(&query), Some((4, &9)));
+
+        for (i, word) in words.iter().enumerate().step_by(2) {
+            assert_eq!(radix.remove(word), trie.remove(word), "{i}");
+            check(&radix.root, true);
+        }
+        assert_eq!(radix.keys_with_prefix([]), trie.keys_with_prefix([]));
+        for word in &words {
+            radix.remove(word);
+        }
+        assert!(radix.is_empty());
+        assert_eq!(radix.node_count(), 1);
+    }
+
+    #[test]
+    fn test_split_and_merge() {
+        let mut tree: RadixTree<u8, i32> = ["test", "team", "toast"].into_iter().zip(1..).collect();
+        assert_eq!(tree.node_count(), 6);
+        assert_eq!(tree.get("te"), None);
+        // 在已有边中间插入键会拆边，删除后再合并回去
+        tree.insert("tea", 4);
+        assert_eq!(tree.node_count(), 7);
+        assert_eq!(tree.count_prefix("tea"), 2);
+        assert_eq!(tree.remove("team"), Some(2));
+        check(&tree.root, true);
+        assert_eq!(tree.node_count(), 6);
+        assert_eq!(tree.remove("tea"), Some(4));
+        assert_eq!(tree.remove("test"), Some(1));
+        check(&tree.root, true);
+        assert_eq!(tree.node_count(), 2);
+        assert_eq!(tree.keys_with_prefix("to"), vec![(b"toast".to_vec(), &3)]);
+    }
+}
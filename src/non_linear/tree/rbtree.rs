@@ -0,0 +1,505 @@
+//! # 红黑树模块
+//!
+//! 基于 [`BinaryNode`] 实现的左倾红黑树（LLRB），与 2-3 树一一对应：
+//! - 红链接只能是左链接，且不能连续出现两条
+//! - 从根到任意空链接路径上的黑链接数量相同
+//!
+//! 插入和删除都只需要 O(log n) 次旋转与变色，写入密集时比 AVL 树旋转更少。
+//! [`RedBlackTreeMap`] 是有序映射，[`RedBlackTreeSet`] 是在其上包装的有序集合。
+//!
+//! ## 示例
+//! ```
+//! use data_structure::non_linear::tree::rbtree::{RedBlackTreeMap, RedBlackTreeSet};
+//!
+//! let mut map = RedBlackTreeMap::new();
+//! map.insert("b", 2);
+//! map.insert("a", 1);
+//! map.insert("c", 3);
+//! assert_eq!(map.get(&"a"), Some(&1));
+//! assert_eq!(map.range("b"..).map(|(k, _)| *k).collect::<Vec<_>>(), vec!["b", "c"]);
+//!
+//! let mut set: RedBlackTreeSet<i32> = (0..10).collect();
+//! assert!(set.remove(&3));
+//! assert!(set.check().is_ok());
+//! ```
+
+use std::mem;
+use std::ops::RangeBounds;
+
+use super::BinaryNode;
+use super::search::{self, KeyValue};
+
+const RED: bool = true;
+const BLACK: bool = false;
+
+/// 节点中保存的键值对以及指向该节点的链接颜色
+#[derive(Clone, Debug)]
+struct Entry<K, V> {
+    key: K,
+    value: V,
+    color: bool,
+}
+
+impl<K, V> KeyValue for Entry<K, V> {
+    type Key = K;
+    type Value = V;
+
+    fn key(&self) -> &K {
+        &self.key
+    }
+
+    fn value(&self) -> &V {
+        &self.value
+    }
+
+    fn value_mut(&mut self) -> &mut V {
+        &mut self.value
+    }
+}
+
+type Node<K, V> = BinaryNode<Entry<K, V>>;
+type Link<K, V> = Option<Box<Node<K, V>>>;
+
+fn is_red<K, V>(link: &Link<K, V>) -> bool {
+    link.as_ref().is_some_and(|node| node.data.color == RED)
+}
+
+fn rotate_left<K, V>(mut node: Box<Node<K, V>>) -> Box<Node<K, V>> {
+    let mut right = node.right.take().expect("左旋需要右子节点");
+    node.right = right.left.take();
+    right.data.color = node.data.color;
+    node.data.color = RED;
+    right.left = Some(node);
+    right
+}
+
+fn rotate_right<K, V>(mut node: Box<Node<K, V>>) -> Box<Node<K, V>> {
+    let mut left = node.left.take().expect("右旋需要左子节点");
+    node.left = left.right.take();
+    left.data.color = node.data.color;
+    node.data.color = RED;
+    left.right = Some(node);
+    left
+}
+
+/// 翻转节点及其两个子节点的颜色，相当于在 2-3 树中分裂或合并 4-节点
+fn flip_colors<K, V>(node: &mut Node<K, V>) {
+    node.data.color = !node.data.color;
+    for child in [&mut node.left, &mut node.right].into_iter().flatten() {
+        child.data.color = !child.data.color;
+    }
+}
+
+/// 自底向上恢复左倾红黑树的性质
+fn balance<K, V>(mut node: Box<Node<K, V>>) -> Box<Node<K, V>> {
+    if is_red(&node.right) && !is_red(&node.left) {
+        node = rotate_left(node);
+    }
+    if is_red(&node.left) && is_red(&node.left.as_ref().unwrap().left) {
+        node = rotate_right(node);
+    }
+    if is_red(&node.left) && is_red(&node.right) {
+        flip_colors(&mut node);
+    }
+    node
+}
+
+/// 保证左子节点或其左子节点是红色，以便向左删除
+fn move_red_left<K, V>(mut node: Box<Node<K, V>>) -> Box<Node<K, V>> {
+    flip_colors(&mut node);
+    if is_red(&node.right.as_ref().unwrap().left) {
+        node.right = node.right.take().map(rotate_right);
+        node = rotate_left(node);
+        flip_colors(&mut node);
+    }
+    node
+}
+
+/// 保证右子节点或其左子节点是红色，以便向右删除
+fn move_red_right<K, V>(mut node: Box<Node<K, V>>) -> Box<Node<K, V>> {
+    flip_colors(&mut node);
+    if is_red(&node.left.as_ref().unwrap().left) {
+        node = rotate_right(node);
+        flip_colors(&mut node);
+    }
+    node
+}
+
+/// 删除子树中的最小节点，返回 `(剩余子树, 最小节点的数据)`
+fn remove_min<K, V>(mut node: Box<Node<K, V>>) -> (Link<K, V>, Entry<K, V>) {
+    if node.left.is_none() {
+        return (None, node.data);
+    }
+    if !is_red(&node.left) && !is_red(&node.left.as_ref().unwrap().left) {
+        node = move_red_left(node);
+    }
+    let (left, min) = remove_min(node.left.take().unwrap());
+    node.left = left;
+    (Some(balance(node)), min)
+}
+
+/// 红黑树有序映射
+#[derive(Clone, Debug)]
+pub struct RedBlackTreeMap<K, V> {
+    root: Link<K, V>,
+    len: usize,
+}
+
+impl<K, V> RedBlackTreeMap<K, V>
+where
+    K: Ord,
+{
+    /// 创建空的红黑树
+    pub fn new() -> Self {
+        Self { root: None, len: 0 }
+    }
+
+    /// 获取键值对的数量
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// 检查是否为空
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// 插入键值对
+    ///
+    /// # 返回值
+    /// 键已存在时替换旧值并返回 `Some(旧值)`，否则返回 `None`
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        if let Some(old) = self.get_mut(&key) {
+            return Some(mem::replace(old, value));
+        }
+        let mut root = Self::insert_at(self.root.take(), key, value);
+        root.data.color = BLACK;
+        self.root = Some(root);
+        self.len += 1;
+        None
+    }
+
+    /// 插入一个不存在的键
+    fn insert_at(link: Link<K, V>, key: K, value: V) -> Box<Node<K, V>> {
+        let Some(mut node) = link else {
+            let entry = Entry {
+                key,
+                value,
+                color: RED,
+            };
+            return Box::new(BinaryNode {
+                data: entry,
+                left: None,
+                right: None,
+            });
+        };
+        if key < node.data.key {
+            node.left = Some(Self::insert_at(node.left.take(), key, value));
+        } else {
+            node.right = Some(Self::insert_at(node.right.take(), key, value));
+        }
+        balance(node)
+    }
+
+    /// 查找键对应的值
+    pub fn get(&self, key: &K) -> Option<&V> {
+        search::get(&self.root, key)
+    }
+
+    /// 查找键对应的值的可变引用
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        search::get_mut(&mut self.root, key)
+    }
+
+    /// 检查是否包含指定的键
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// 删除键值对
+    ///
+    /// # 返回值
+    /// 键存在时返回 `Some(值)`，否则返回 `None`
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        if !self.contains_key(key) {
+            return None;
+        }
+        let mut root = self.root.take().unwrap();
+        if !is_red(&root.left) && !is_red(&root.right) {
+            root.data.color = RED;
+        }
+        let (mut root, value) = Self::remove_at(root, key);
+        if let Some(root) = root.as_mut() {
+            root.data.color = BLACK;
+        }
+        self.root = root;
+        self.len -= 1;
+        Some(value)
+    }
+
+    /// 删除一个存在的键
+    fn remove_at(mut node: Box<Node<K, V>>, key: &K) -> (Link<K, V>, V) {
+        let value;
+        if *key < node.data.key {
+            if !is_red(&node.left) && !is_red(&node.left.as_ref().unwrap().left) {
+                node = move_red_left(node);
+            }
+            let (left, removed) = Self::remove_at(node.left.take().unwrap(), key);
+            node.left = left;
+            value = removed;
+        } else {
+            if is_red(&node.left) {
+                node = rotate_right(node);
+            }
+            if *key == node.data.key && node.right.is_none() {
+                return (None, node.data.value);
+            }
+            if !is_red(&node.right) && !is_red(&node.right.as_ref().unwrap().left) {
+                node = move_red_right(node);
+            }
+            if *key == node.data.key {
+                // 用右子树中的最小键值对顶替当前节点
+                let (right, min) = remove_min(node.right.take().unwrap());
+                node.right = right;
+                node.data.key = min.key;
+                value = mem::replace(&mut node.data.value, min.value);
+            } else {
+                let (right, removed) = Self::remove_at(node.right.take().unwrap(), key);
+                node.right = right;
+                value = removed;
+            }
+        }
+        (Some(balance(node)), value)
+    }
+
+    /// 最小的键值对
+    pub fn min(&self) -> Option<(&K, &V)> {
+        search::min(&self.root)
+    }
+
+    /// 最大的键值对
+    pub fn max(&self) -> Option<(&K, &V)> {
+        search::max(&self.root)
+    }
+
+    /// 按键从小到大遍历所有键值对
+    pub fn iter(&self) -> Range<'_, K, V> {
+        Range(search::Range::all(&self.root))
+    }
+
+    /// 按键从小到大遍历落在 `range` 内的键值对
+    pub fn range<R>(&self, range: R) -> Range<'_, K, V>
+    where
+        R: RangeBounds<K>,
+        K: Clone,
+    {
+        Range(search::Range::new(
+            &self.root,
+            range.start_bound().cloned(),
+            range.end_bound().cloned(),
+        ))
+    }
+
+    /// 检查红黑树的不变量
+    ///
+    /// 依次检查：键严格递增、根为黑色、没有右倾的红链接、没有连续的红链接、
+    /// 所有路径上的黑链接数量相同、节点数量与 `len` 一致。
+    ///
+    /// # 返回值
+    /// 全部满足时返回 `Ok(())`，否则返回描述第一个被破坏的不变量的错误
+    pub fn check(&self) -> Result<(), &'static str> {
+        let mut count = 0;
+        let mut previous: Option<&K> = None;
+        for (key, _) in self.iter() {
+            if previous.is_some_and(|previous| previous >= key) {
+                return Err("键不是严格递增的");
+            }
+            previous = Some(key);
+            count += 1;
+        }
+        if count != self.len {
+            return Err("节点数量与长度不一致");
+        }
+        if is_red(&self.root) {
+            return Err("根节点不是黑色");
+        }
+        Self::check_node(&self.root).map(|_| ())
+    }
+
+    /// 递归检查颜色约束，返回子树的黑高
+    fn check_node(link: &Link<K, V>) -> Result<usize, &'static str> {
+        let Some(node) = link else {
+            return Ok(0);
+        };
+        if is_red(&node.right) {
+            return Err("存在右倾的红链接");
+        }
+        if node.data.color == RED && is_red(&node.left) {
+            return Err("存在连续的红链接");
+        }
+        let left = Self::check_node(&node.left)?;
+        let right = Self::check_node(&node.right)?;
+        if left != right {
+            return Err("黑高不平衡");
+        }
+        Ok(left + usize::from(node.data.color == BLACK))
+    }
+}
+
+impl<K: Ord, V> Default for RedBlackTreeMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Ord, V> FromIterator<(K, V)> for RedBlackTreeMap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        for (key, value) in iter {
+            map.insert(key, value);
+        }
+        map
+    }
+}
+
+/// 按键的顺序惰性遍历红黑树的迭代器
+pub struct Range<'a, K, V>(search::Range<'a, Entry<K, V>>);
+
+impl<'a, K: Ord, V> Iterator for Range<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+}
+
+/// 红黑树有序集合
+#[derive(Clone, Debug)]
+pub struct RedBlackTreeSet<T> {
+    map: RedBlackTreeMap<T, ()>,
+}
+
+impl<T: Ord> RedBlackTreeSet<T> {
+    /// 创建空集合
+    pub fn new() -> Self {
+        Self {
+            map: RedBlackTreeMap::new(),
+        }
+    }
+
+    /// 获取元素数量
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// 检查是否为空
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// 插入元素，元素已存在时返回 `false`
+    pub fn insert(&mut self, value: T) -> bool {
+        self.map.insert(value, ()).is_none()
+    }
+
+    /// 检查是否包含元素
+    pub fn contains(&self, value: &T) -> bool {
+        self.map.contains_key(value)
+    }
+
+    /// 删除元素，元素不存在时返回 `false`
+    pub fn remove(&mut self, value: &T) -> bool {
+        self.map.remove(value).is_some()
+    }
+
+    /// 最小的元素
+    pub fn first(&self) -> Option<&T> {
+        self.map.min().map(|(value, _)| value)
+    }
+
+    /// 最大的元素
+    pub fn last(&self) -> Option<&T> {
+        self.map.max().map(|(value, _)| value)
+    }
+
+    /// 按从小到大的顺序遍历所有元素
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.map.iter().map(|(value, _)| value)
+    }
+
+    /// 按从小到大的顺序遍历落在 `range` 内的元素
+    pub fn range<R>(&self, range: R) -> impl Iterator<Item = &T>
+    where
+        R: RangeBounds<T>,
+        T: Clone,
+    {
+        self.map.range(range).map(|(value, _)| value)
+    }
+
+    /// 检查红黑树的不变量，见 [`RedBlackTreeMap::check`]
+    pub fn check(&self) -> Result<(), &'static str> {
+        self.map.check()
+    }
+}
+
+impl<T: Ord> Default for RedBlackTreeSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord> FromIterator<T> for RedBlackTreeSet<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for value in iter {
+            set.insert(value);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rbtree_map() {
+        let mut map = RedBlackTreeMap::new();
+        for i in 0..300 {
+            assert_eq!(map.insert(i * 37 % 300, i), None);
+            assert!(map.check().is_ok());
+        }
+        assert_eq!(map.len(), 300);
+        assert_eq!(map.insert(37, 0), Some(1));
+        assert_eq!(map.get(&37), Some(&0));
+        assert_eq!(map.min().map(|(k, _)| *k), Some(0));
+        assert_eq!(map.max().map(|(k, _)| *k), Some(299));
+
+        for key in (0..300).rev().filter(|key| key % 4 != 1) {
+            assert!(map.remove(&key).is_some());
+            assert!(map.check().is_ok());
+        }
+        assert_eq!(map.remove(&0), None);
+        assert_eq!(map.len(), 75);
+        let range: Vec<i32> = map.range(10..=21).map(|(k, _)| *k).collect();
+        assert_eq!(range, vec![13, 17, 21]);
+        let keys: Vec<i32> = map.iter().map(|(k, _)| *k).collect();
+        assert_eq!(keys, (1..300).step_by(4).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_rbtree_set() {
+        let mut set: RedBlackTreeSet<char> = "redblack".chars().collect();
+        assert_eq!(set.len(), 8);
+        assert!(!set.insert('r'));
+        assert!(set.contains(&'k'));
+        assert_eq!(set.first(), Some(&'a'));
+        assert_eq!(set.last(), Some(&'r'));
+        assert_eq!(set.range('c'..'l').collect::<String>(), "cdek");
+        while let Some(&first) = set.first() {
+            assert!(set.remove(&first));
+            assert!(set.check().is_ok());
+        }
+        assert!(set.is_empty());
+    }
+}
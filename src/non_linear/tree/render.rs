@@ -0,0 +1,390 @@
+//! # 二叉树的字符画
+//!
+//! 把二叉树画成带连线的多行文本，用于测试失败信息和教学演示。支持两种布局：
+//!
+//! - 横向（[`Layout::Sideways`]，默认）：根在最左边，右子树画在上方、左子树画在下方，
+//!   把输出顺时针旋转 90° 就是通常的画法。每个节点占一行，宽度只随深度增长，适合较大的树；
+//! - 纵向（[`Layout::TopDown`]）：根在最上方，子节点画在下一层，更直观，但宽度随节点数增长。
+//!
+//! 节点的显示方式可以用闭包自定义，并可以限制每个节点标签和每一行的最大宽度，
+//! 超出部分截断并以 `…` 结尾。宽度按字符个数计算，全角字符会让纵向布局的连线错位。
+//! 标签中的换行等控制字符替换为空格。空树画出来是空字符串。
+//!
+//! 两种布局都不使用递归，退化成链表的深树也不会栈溢出。
+//!
+//! ## 示例
+//! ```
+//! use data_structure::non_linear::tree::BinaryTree;
+//! use data_structure::non_linear::tree::render::Layout;
+//!
+//! let tree = BinaryTree::from_level_order((1..=6).map(Some)).unwrap();
+//! let sideways = [
+//!     "┌── 3",
+//!     "│   └── 6",
+//!     "1",
+//!     "│   ┌── 5",
+//!     "└── 2",
+//!     "    └── 4",
+//! ];
+//! assert_eq!(tree.to_string(), sideways.join("\n"));
+//!
+//! let top_down = [
+//!     "   1",
+//!     " ┌─┴─┐",
+//!     " 2   3",
+//!     "┌┴┐ ┌┘",
+//!     "4 5 6",
+//! ];
+//! assert_eq!(tree.render().layout(Layout::TopDown).to_string(), top_down.join("\n"));
+//! ```
+
+use std::fmt;
+
+use super::{BinaryNode, BinaryTree};
+
+/// 画树的布局
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum Layout {
+    /// 根在最左边，右子树在上、左子树在下
+    #[default]
+    Sideways,
+    /// 根在最上方，子节点在下一层
+    TopDown,
+}
+
+/// 横向布局中节点相对父节点的位置
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Side {
+    Root,
+    Right,
+    Left,
+}
+
+/// 纵向布局中已经画好的一棵子树
+struct Block {
+    /// 每行的起始列和从这一列开始的字符
+    rows: Vec<(usize, Vec<char>)>,
+    width: usize,
+    /// 根节点标签中点所在的列，连线从这里接出
+    middle: usize,
+}
+
+/// 二叉树的字符画渲染器
+///
+/// 由 [`BinaryTree::render`] 或 [`BinaryTree::render_with`] 创建，
+/// 用链式调用设置布局和宽度限制，再通过 [`Display`](fmt::Display) 输出。
+pub struct Renderer<'a, T> {
+    tree: &'a BinaryTree<T>,
+    format: Box<dyn Fn(&T) -> String + 'a>,
+    layout: Layout,
+    label_width: Option<usize>,
+    max_width: Option<usize>,
+}
+
+impl<T: fmt::Display> BinaryTree<T> {
+    /// 用节点数据的 [`Display`](fmt::Display) 作为标签创建渲染器
+    pub fn render(&self) -> Renderer<'_, T> {
+        self.render_with(|data| data.to_string())
+    }
+}
+
+impl<T> BinaryTree<T> {
+    /// 用自定义的格式化函数创建渲染器
+    ///
+    /// # 参数
+    /// * `format` - 把节点数据转成标签的函数
+    ///
+    /// # 示例
+    /// ```
+    /// use data_structure::non_linear::tree::BinaryTree;
+    ///
+    /// let tree = BinaryTree::from_level_order([Some((1, 'a')), None, Some((2, 'b'))]).unwrap();
+    /// let text = tree.render_with(|(key, value)| format!("{key}={value}")).to_string();
+    /// assert_eq!(text, "┌── 2=b\n1=a");
+    /// ```
+    pub fn render_with<'a>(&'a self, format: impl Fn(&T) -> String + 'a) -> Renderer<'a, T> {
+        Renderer {
+            tree: self,
+            format: Box::new(format),
+            layout: Layout::Sideways,
+            label_width: None,
+            max_width: None,
+        }
+    }
+}
+
+impl<'a, T> Renderer<'a, T> {
+    /// 设置布局
+    pub fn layout(mut self, layout: Layout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// 限制每个节点标签的最大字符数
+    ///
+    /// # Panics
+    /// `width`为0时panic
+    pub fn label_width(mut self, width: usize) -> Self {
+        assert!(width > 0, "标签宽度必须大于 0");
+        self.label_width = Some(width);
+        self
+    }
+
+    /// 限制每一行的最大字符数
+    ///
+    /// # Panics
+    /// `width`为0时panic
+    pub fn max_width(mut self, width: usize) -> Self {
+        assert!(width > 0, "行宽必须大于 0");
+        self.max_width = Some(width);
+        self
+    }
+
+    /// 格式化节点数据，替换控制字符并按标签宽度截断
+    fn label(&self, data: &T) -> Vec<char> {
+        let label = (self.format)(data)
+            .chars()
+            .map(|c| if c.is_control() { ' ' } else { c })
+            .collect();
+        match self.label_width {
+            Some(width) => truncate(label, width),
+            None => label,
+        }
+    }
+
+    /// 横向布局：按“右子树、节点、左子树”的顺序逐行输出
+    fn sideways(&self) -> Vec<Vec<char>> {
+        enum Task<'a, T> {
+            Visit(&'a BinaryNode<T>, String, Side),
+            Emit(Vec<char>),
+        }
+        let mut lines = Vec::new();
+        let mut stack: Vec<Task<'_, T>> = self
+            .tree
+            .0
+            .as_deref()
+            .map(|root| Task::Visit(root, String::new(), Side::Root))
+            .into_iter()
+            .collect();
+        while let Some(task) = stack.pop() {
+            let (node, prefix, side) = match task {
+                Task::Emit(line) => {
+                    lines.push(line);
+                    continue;
+                }
+                Task::Visit(node, prefix, side) => (node, prefix, side),
+            };
+            // 竖线把父节点和另一侧的子树连起来
+            let child_prefix = |child_side| match side {
+                Side::Root => String::new(),
+                _ if side != child_side => format!("{prefix}│   "),
+                _ => format!("{prefix}    "),
+            };
+            // 先进后出：左子树最后输出，最先入栈
+            if let Some(left) = node.left.as_deref() {
+                stack.push(Task::Visit(left, child_prefix(Side::Left), Side::Left));
+            }
+            let connector = match side {
+                Side::Root => "",
+                Side::Right => "┌── ",
+                Side::Left => "└── ",
+            };
+            let mut line: Vec<char> = prefix.chars().chain(connector.chars()).collect();
+            line.extend(self.label(&node.data));
+            stack.push(Task::Emit(line));
+            if let Some(right) = node.right.as_deref() {
+                stack.push(Task::Visit(right, child_prefix(Side::Right), Side::Right));
+            }
+        }
+        lines
+    }
+
+    /// 纵向布局：自底向上把子树的字符块拼成父节点的字符块
+    fn top_down(&self) -> Vec<Vec<char>> {
+        // 层序编号中子节点的编号总是大于父节点，逆序处理即可保证子树先画好
+        let nodes = self.tree.indexed();
+        let mut blocks: Vec<Option<Block>> = (0..nodes.len()).map(|_| None).collect();
+        for index in (0..nodes.len()).rev() {
+            let (node, left, right) = nodes[index];
+            let left = left.and_then(|child| blocks[child].take());
+            let right = right.and_then(|child| blocks[child].take());
+            blocks[index] = Some(combine(self.label(&node.data), left, right));
+        }
+        blocks
+            .first_mut()
+            .and_then(Option::take)
+            .map_or_else(Vec::new, |block| {
+                let lines = block.rows.into_iter().map(|(offset, chars)| {
+                    let mut line = vec![' '; offset];
+                    line.extend(chars);
+                    line
+                });
+                lines.collect()
+            })
+    }
+}
+
+/// 截断到`width`个字符，被截断时最后一个字符换成省略号
+fn truncate(mut text: Vec<char>, width: usize) -> Vec<char> {
+    if text.len() > width {
+        text.truncate(width - 1);
+        text.push('…');
+    }
+    text
+}
+
+/// 把节点标签和左右子树的字符块拼成一个字符块
+///
+/// 两棵子树并排、相隔一列，标签居中放在两个子节点之间；只有一个子节点时，
+/// 标签放在子节点的斜上方，以区分左右。子树的行只平移不复制，退化成链表时总代价为 O(n²)。
+fn combine(label: Vec<char>, left: Option<Block>, right: Option<Block>) -> Block {
+    let (left_width, right_offset, center, connector) = match (&left, &right) {
+        (None, None) => {
+            let width = label.len();
+            return Block {
+                middle: width.saturating_sub(1) / 2,
+                rows: vec![(0, label)],
+                width,
+            };
+        }
+        (Some(left), Some(right)) => {
+            let offset = left.width + 1;
+            let end = offset + right.middle;
+            let center = (left.middle + end) / 2;
+            (
+                left.width,
+                offset,
+                center,
+                (left.middle, '┌', end, '┐', '┴'),
+            )
+        }
+        (Some(left), None) => {
+            let center = left.width;
+            (left.width, 0, center, (left.middle, '┌', center, '┘', '┘'))
+        }
+        (None, Some(right)) => (0, 1, 0, (0, '└', 1 + right.middle, '┐', '└')),
+    };
+    let children_width = right
+        .as_ref()
+        .map_or(left_width, |right| right_offset + right.width);
+    // 标签太宽、超出左边界时，整体右移
+    let label_start = center as isize - (label.len().saturating_sub(1) / 2) as isize;
+    let shift = (-label_start).max(0) as usize;
+    let label_start = (label_start + shift as isize) as usize;
+    let width = (children_width + shift).max(label_start + label.len());
+
+    let (from, first, to, last, joint) = connector;
+    let mut line = vec!['─'; to - from + 1];
+    line[0] = first;
+    line[to - from] = last;
+    line[center - from] = joint;
+    let mut rows = vec![(label_start, label), (shift + from, line)];
+    let mut left_rows = left.map(|block| block.rows.into_iter());
+    let mut right_rows = right.map(|block| block.rows.into_iter());
+    loop {
+        let left_row = left_rows.as_mut().and_then(Iterator::next);
+        let right_row = right_rows.as_mut().and_then(Iterator::next);
+        rows.push(match (left_row, right_row) {
+            (None, None) => break,
+            (Some((offset, chars)), None) => (shift + offset, chars),
+            (None, Some((offset, chars))) => (shift + right_offset + offset, chars),
+            (Some((left_offset, mut chars)), Some((offset, right_chars))) => {
+                chars.resize(right_offset + offset - left_offset, ' ');
+                chars.extend(right_chars);
+                (shift + left_offset, chars)
+            }
+        });
+    }
+    Block {
+        rows,
+        width,
+        middle: shift + center,
+    }
+}
+
+impl<T> fmt::Display for Renderer<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let lines = match self.layout {
+            Layout::Sideways => self.sideways(),
+            Layout::TopDown => self.top_down(),
+        };
+        for (i, mut line) in lines.into_iter().enumerate() {
+            while line.last() == Some(&' ') {
+                line.pop();
+            }
+            if let Some(width) = self.max_width {
+                line = truncate(line, width);
+            }
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}", line.into_iter().collect::<String>())?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for BinaryTree<T> {
+    /// 以横向布局画出整棵树
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.render().fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_layouts() {
+        let tree = BinaryTree::from_level_order(
+            [1, 2, 3, 4, 0, 0, 7, 0, 8].map(|x| (x != 0).then_some(x)),
+        )
+        .unwrap();
+        assert_eq!(
+            tree.to_string(),
+            "    ┌── 7\n┌── 3\n1\n└── 2\n    │   ┌── 8\n    └── 4"
+        );
+        assert_eq!(
+            tree.render().layout(Layout::TopDown).to_string(),
+            "   1\n  ┌┴┐\n  2 3\n┌─┘ └┐\n4    7\n└┐\n 8"
+        );
+        // 宽标签超出左边界时整体右移
+        let wide = BinaryTree::from_level_order([Some("root"), Some("l"), Some("r")]).unwrap();
+        assert_eq!(
+            wide.render().layout(Layout::TopDown).to_string(),
+            "root\n┌┴┐\nl r"
+        );
+        let empty: BinaryTree<i32> = BinaryTree::new();
+        assert_eq!(empty.to_string(), "");
+    }
+
+    #[test]
+    fn test_render_limits() {
+        let tree =
+            BinaryTree::from_level_order([Some("a\nlong label"), Some("left"), None]).unwrap();
+        assert_eq!(tree.render().label_width(6).to_string(), "a lon…\n└── left");
+        assert_eq!(tree.render().max_width(5).to_string(), "a lo…\n└── …");
+
+        // 深链：两种布局都不会栈溢出
+        let mut chain = BinaryTree::with_root(0);
+        for i in 1..2000 {
+            let mut parent = BinaryTree::with_root(i);
+            parent.0.as_mut().unwrap().set_left_subtree(chain);
+            chain = parent;
+        }
+        assert_eq!(
+            chain.render().max_width(20).to_string().lines().count(),
+            2000
+        );
+        let top_down = chain
+            .render()
+            .layout(Layout::TopDown)
+            .max_width(8)
+            .to_string();
+        assert_eq!(top_down.lines().count(), 2 * 2000 - 1);
+        while let Some(mut node) = chain.0.take() {
+            chain = node.take_left();
+        }
+    }
+}
@@ -0,0 +1,145 @@
+//! # 搜索树公共模块
+//!
+//! [`bst`](super::bst)、[`avl`](super::avl)、[`rbtree`](super::rbtree)、[`splay`](super::splay)
+//! 和 [`treap`](super::treap) 都把键值对连同各自的平衡信息存放在 [`BinaryNode`] 中。
+//! 不改变树形的查找与按键遍历只依赖键的顺序，与平衡方式无关，统一在这里实现。
+
+use std::cmp::Ordering;
+use std::ops::Bound;
+
+use super::BinaryNode;
+
+type Link<E> = Option<Box<BinaryNode<E>>>;
+
+/// 搜索树节点数据中的键值对
+pub(super) trait KeyValue {
+    type Key;
+    type Value;
+
+    fn key(&self) -> &Self::Key;
+
+    fn value(&self) -> &Self::Value;
+
+    fn value_mut(&mut self) -> &mut Self::Value;
+}
+
+/// 查找键对应的值
+pub(super) fn get<'a, E>(link: &'a Link<E>, key: &E::Key) -> Option<&'a E::Value>
+where
+    E: KeyValue,
+    E::Key: Ord,
+{
+    let mut current = link.as_deref();
+    while let Some(node) = current {
+        current = match key.cmp(node.data.key()) {
+            Ordering::Less => node.left.as_deref(),
+            Ordering::Greater => node.right.as_deref(),
+            Ordering::Equal => return Some(node.data.value()),
+        };
+    }
+    None
+}
+
+/// 查找键对应的值的可变引用
+pub(super) fn get_mut<'a, E>(link: &'a mut Link<E>, key: &E::Key) -> Option<&'a mut E::Value>
+where
+    E: KeyValue,
+    E::Key: Ord,
+{
+    let mut current = link.as_deref_mut();
+    while let Some(node) = current {
+        current = match key.cmp(node.data.key()) {
+            Ordering::Less => node.left.as_deref_mut(),
+            Ordering::Greater => node.right.as_deref_mut(),
+            Ordering::Equal => return Some(node.data.value_mut()),
+        };
+    }
+    None
+}
+
+/// 最小的键值对
+pub(super) fn min<E: KeyValue>(link: &Link<E>) -> Option<(&E::Key, &E::Value)> {
+    let mut node = link.as_deref()?;
+    while let Some(left) = node.left.as_deref() {
+        node = left;
+    }
+    Some((node.data.key(), node.data.value()))
+}
+
+/// 最大的键值对
+pub(super) fn max<E: KeyValue>(link: &Link<E>) -> Option<(&E::Key, &E::Value)> {
+    let mut node = link.as_deref()?;
+    while let Some(right) = node.right.as_deref() {
+        node = right;
+    }
+    Some((node.data.key(), node.data.value()))
+}
+
+/// 按键的顺序惰性遍历落在区间内的键值对
+///
+/// 栈中保存尚未访问的节点，其右子树也都未访问，单次 `next` 均摊 O(1)。
+pub(super) struct Range<'a, E: KeyValue> {
+    stack: Vec<&'a BinaryNode<E>>,
+    end: Bound<E::Key>,
+}
+
+impl<'a, E> Range<'a, E>
+where
+    E: KeyValue,
+    E::Key: Ord,
+{
+    /// 遍历整棵树
+    pub(super) fn all(root: &'a Link<E>) -> Self {
+        Self::new(root, Bound::Unbounded, Bound::Unbounded)
+    }
+
+    /// 遍历键落在 `start` 与 `end` 之间的部分
+    pub(super) fn new(root: &'a Link<E>, start: Bound<E::Key>, end: Bound<E::Key>) -> Self {
+        let mut stack = Vec::new();
+        // 沿查找路径把不小于下界的节点入栈，小于下界的节点及其左子树直接跳过
+        let mut current = root.as_deref();
+        while let Some(node) = current {
+            let key = node.data.key();
+            let above_start = match &start {
+                Bound::Included(start) => key >= start,
+                Bound::Excluded(start) => key > start,
+                Bound::Unbounded => true,
+            };
+            if above_start {
+                stack.push(node);
+                current = node.left.as_deref();
+            } else {
+                current = node.right.as_deref();
+            }
+        }
+        Self { stack, end }
+    }
+}
+
+impl<'a, E> Iterator for Range<'a, E>
+where
+    E: KeyValue,
+    E::Key: Ord,
+{
+    type Item = (&'a E::Key, &'a E::Value);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        let key = node.data.key();
+        let below_end = match &self.end {
+            Bound::Included(end) => key <= end,
+            Bound::Excluded(end) => key < end,
+            Bound::Unbounded => true,
+        };
+        if !below_end {
+            self.stack.clear();
+            return None;
+        }
+        let mut current = node.right.as_deref();
+        while let Some(child) = current {
+            self.stack.push(child);
+            current = child.left.as_deref();
+        }
+        Some((key, node.data.value()))
+    }
+}
@@ -0,0 +1,406 @@
+//! # 伸展树模块
+//!
+//! 基于 [`BinaryNode`] 实现的自调整二叉搜索树。每次查找、插入、删除都把访问到的节点
+//! 通过旋转“伸展”到根，不维护任何平衡信息。单次操作最坏 O(n)，但任意 m 次操作的总代价为
+//! O(m log n)；访问越集中，被访问的键离根越近，适合局部性强或分布偏斜的访问模式。
+//!
+//! 伸展采用自顶向下的方式：沿查找路径把节点分别挂到左、右两棵临时树上，最后重新组装，
+//! 整个过程是迭代的，退化成长链时也不会因递归过深而栈溢出。
+//!
+//! 由于查找也会改变树的形状，[`get`](SplayTree::get) 需要 `&mut self`；
+//! 不想改变形状时可以用 [`peek`](SplayTree::peek)。
+//!
+//! ## 示例
+//! ```
+//! use data_structure::non_linear::tree::splay::SplayTree;
+//!
+//! let mut splay = SplayTree::new();
+//! for i in 0..100 {
+//!     splay.insert(i, i * i);
+//! }
+//! assert_eq!(splay.get(&7), Some(&49));
+//! // 刚访问过的键在根上
+//! assert_eq!(splay.root_key(), Some(&7));
+//! assert_eq!(splay.remove(&7), Some(49));
+//! assert!(splay.check().is_ok());
+//! ```
+
+use std::cmp::Ordering;
+use std::mem;
+
+use super::BinaryNode;
+use super::search::{self, KeyValue};
+
+/// 节点中保存的键值对
+#[derive(Clone, Debug)]
+struct Entry<K, V> {
+    key: K,
+    value: V,
+}
+
+impl<K, V> KeyValue for Entry<K, V> {
+    type Key = K;
+    type Value = V;
+
+    fn key(&self) -> &K {
+        &self.key
+    }
+
+    fn value(&self) -> &V {
+        &self.value
+    }
+
+    fn value_mut(&mut self) -> &mut V {
+        &mut self.value
+    }
+}
+
+type Node<K, V> = BinaryNode<Entry<K, V>>;
+type Link<K, V> = Option<Box<Node<K, V>>>;
+
+fn leaf<K, V>(key: K, value: V) -> Box<Node<K, V>> {
+    Box::new(BinaryNode {
+        data: Entry { key, value },
+        left: None,
+        right: None,
+    })
+}
+
+/// 自顶向下伸展
+///
+/// # 返回值
+/// 新的根：`key`存在时为该键所在节点，否则为查找路径上最后访问的节点（`key`的前驱或后继）
+fn splay<K: Ord, V>(mut root: Box<Node<K, V>>, key: &K) -> Box<Node<K, V>> {
+    // 左树收集小于`key`的节点（右子树待接），右树收集大于`key`的节点（左子树待接）
+    let mut smaller: Vec<Box<Node<K, V>>> = Vec::new();
+    let mut larger: Vec<Box<Node<K, V>>> = Vec::new();
+    loop {
+        match key.cmp(&root.data.key) {
+            Ordering::Equal => break,
+            Ordering::Less => {
+                let Some(mut child) = root.left.take() else {
+                    break;
+                };
+                if *key < child.data.key {
+                    // 一字形：先右旋
+                    root.left = child.right.take();
+                    child.right = Some(root);
+                    root = child;
+                    let Some(next) = root.left.take() else {
+                        break;
+                    };
+                    larger.push(mem::replace(&mut root, next));
+                } else {
+                    larger.push(mem::replace(&mut root, child));
+                }
+            }
+            Ordering::Greater => {
+                let Some(mut child) = root.right.take() else {
+                    break;
+                };
+                if *key > child.data.key {
+                    // 一字形：先左旋
+                    root.right = child.left.take();
+                    child.left = Some(root);
+                    root = child;
+                    let Some(next) = root.right.take() else {
+                        break;
+                    };
+                    smaller.push(mem::replace(&mut root, next));
+                } else {
+                    smaller.push(mem::replace(&mut root, child));
+                }
+            }
+        }
+    }
+    // 组装：后加入左树的节点更大，挂在前一个节点的右子树上
+    let mut left = root.left.take();
+    while let Some(mut node) = smaller.pop() {
+        node.right = left;
+        left = Some(node);
+    }
+    let mut right = root.right.take();
+    while let Some(mut node) = larger.pop() {
+        node.left = right;
+        right = Some(node);
+    }
+    root.left = left;
+    root.right = right;
+    root
+}
+
+/// 伸展树
+///
+/// 提供与有序映射相同的接口，不允许重复的键。
+#[derive(Clone, Debug)]
+pub struct SplayTree<K, V> {
+    root: Link<K, V>,
+    len: usize,
+}
+
+impl<K, V> SplayTree<K, V>
+where
+    K: Ord,
+{
+    /// 创建空的伸展树
+    pub fn new() -> Self {
+        Self { root: None, len: 0 }
+    }
+
+    /// 获取键值对的数量
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// 检查是否为空
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// 树的高度，空树为 0
+    pub fn height(&self) -> usize {
+        let mut height = 0;
+        let mut stack: Vec<(&Node<K, V>, usize)> = self
+            .root
+            .as_deref()
+            .map(|root| (root, 1))
+            .into_iter()
+            .collect();
+        while let Some((node, depth)) = stack.pop() {
+            height = height.max(depth);
+            for child in [&node.left, &node.right].into_iter().flatten() {
+                stack.push((child, depth + 1));
+            }
+        }
+        height
+    }
+
+    /// 根节点的键，即最近一次访问的键或其前驱、后继
+    pub fn root_key(&self) -> Option<&K> {
+        self.root.as_ref().map(|root| &root.data.key)
+    }
+
+    /// 把`key`或查找路径上最后一个节点伸展到根
+    ///
+    /// # 返回值
+    /// 伸展后根节点的键是否等于`key`
+    fn splay(&mut self, key: &K) -> bool {
+        match self.root.take() {
+            None => false,
+            Some(root) => {
+                let root = self.root.insert(splay(root, key));
+                root.data.key == *key
+            }
+        }
+    }
+
+    /// 插入键值对，插入后新键位于根
+    ///
+    /// # 返回值
+    /// 键已存在时替换旧值并返回 `Some(旧值)`，否则返回 `None`
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let Some(root) = self.root.take() else {
+            self.root = Some(leaf(key, value));
+            self.len = 1;
+            return None;
+        };
+        let mut root = splay(root, &key);
+        let node = match key.cmp(&root.data.key) {
+            Ordering::Equal => {
+                let old = mem::replace(&mut root.data.value, value);
+                self.root = Some(root);
+                return Some(old);
+            }
+            // 根是`key`的后继：根连同右子树挂到新节点右边
+            Ordering::Less => {
+                let mut node = leaf(key, value);
+                node.left = root.left.take();
+                node.right = Some(root);
+                node
+            }
+            // 根是`key`的前驱
+            Ordering::Greater => {
+                let mut node = leaf(key, value);
+                node.right = root.right.take();
+                node.left = Some(root);
+                node
+            }
+        };
+        self.root = Some(node);
+        self.len += 1;
+        None
+    }
+
+    /// 查找键对应的值，并把该键伸展到根
+    pub fn get(&mut self, key: &K) -> Option<&V> {
+        if self.splay(key) {
+            self.root.as_ref().map(|root| &root.data.value)
+        } else {
+            None
+        }
+    }
+
+    /// 查找键对应的值的可变引用，并把该键伸展到根
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        if self.splay(key) {
+            self.root.as_mut().map(|root| &mut root.data.value)
+        } else {
+            None
+        }
+    }
+
+    /// 查找键对应的值，不改变树的形状
+    pub fn peek(&self, key: &K) -> Option<&V> {
+        search::get(&self.root, key)
+    }
+
+    /// 检查是否包含指定的键，不改变树的形状
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.peek(key).is_some()
+    }
+
+    /// 删除键值对
+    ///
+    /// 先把`key`伸展到根，再把左子树的最大节点伸展上来接替根。
+    ///
+    /// # 返回值
+    /// 键存在时返回 `Some(值)`，否则返回 `None`
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        if !self.splay(key) {
+            return None;
+        }
+        let root = self.root.take().unwrap();
+        let BinaryNode { data, left, right } = *root;
+        self.root = match left {
+            None => right,
+            Some(left) => {
+                // 左子树的键都小于`key`，伸展后最大节点在根且没有右子树
+                let mut max = splay(left, key);
+                max.right = right;
+                Some(max)
+            }
+        };
+        self.len -= 1;
+        Some(data.value)
+    }
+
+    /// 最小的键值对
+    pub fn min(&self) -> Option<(&K, &V)> {
+        search::min(&self.root)
+    }
+
+    /// 最大的键值对
+    pub fn max(&self) -> Option<(&K, &V)> {
+        search::max(&self.root)
+    }
+
+    /// 按键从小到大遍历所有键值对
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter(search::Range::all(&self.root))
+    }
+
+    /// 检查伸展树的不变量：键严格递增、节点数量与 `len` 一致
+    pub fn check(&self) -> Result<(), &'static str> {
+        let mut count = 0;
+        let mut previous: Option<&K> = None;
+        for (key, _) in self.iter() {
+            if previous.is_some_and(|previous| previous >= key) {
+                return Err("键不是严格递增的");
+            }
+            previous = Some(key);
+            count += 1;
+        }
+        if count != self.len {
+            return Err("节点数量与长度不一致");
+        }
+        Ok(())
+    }
+}
+
+impl<K: Ord, V> Default for SplayTree<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V> Drop for SplayTree<K, V> {
+    /// 伸展树可能退化成长链，逐个拆下节点以免递归释放时栈溢出
+    fn drop(&mut self) {
+        let mut stack: Vec<Box<Node<K, V>>> = self.root.take().into_iter().collect();
+        while let Some(mut node) = stack.pop() {
+            stack.extend(node.left.take());
+            stack.extend(node.right.take());
+        }
+    }
+}
+
+/// 按键的顺序惰性遍历伸展树的迭代器
+pub struct Iter<'a, K, V>(search::Range<'a, Entry<K, V>>);
+
+impl<'a, K: Ord, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+}
+
+impl<'a, K: Ord, V> IntoIterator for &'a SplayTree<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_splay_insert_remove() {
+        let mut splay = SplayTree::new();
+        for i in 0..500 {
+            assert_eq!(splay.insert(i * 7 % 500, i), None);
+            assert_eq!(splay.root_key(), Some(&(i * 7 % 500)));
+        }
+        assert!(splay.check().is_ok());
+        assert_eq!(splay.len(), 500);
+        assert_eq!(splay.insert(7, 0), Some(1));
+        *splay.get_mut(&7).unwrap() += 1;
+        assert_eq!(splay.peek(&7), Some(&1));
+        assert_eq!(splay.get(&1000), None);
+        assert_eq!(splay.min(), Some((&0, &0)));
+        assert_eq!(splay.max(), Some((&499, &357)));
+
+        for key in (0..500).filter(|key| key % 3 != 0) {
+            assert!(splay.remove(&key).is_some());
+        }
+        assert!(splay.check().is_ok());
+        assert_eq!(splay.remove(&1), None);
+        let keys: Vec<i32> = splay.iter().map(|(k, _)| *k).collect();
+        assert_eq!(keys, (0..500).step_by(3).collect::<Vec<_>>());
+        assert!(!splay.contains_key(&2));
+    }
+
+    #[test]
+    fn test_splay_skewed_access() {
+        // 有序插入得到一条长链，访问最深的节点后高度大约减半
+        let mut splay = SplayTree::new();
+        for i in 0..100_000 {
+            splay.insert(i, ());
+        }
+        assert_eq!(splay.height(), 100_000);
+        assert!(splay.get(&0).is_some());
+        assert!(splay.height() <= 50_002);
+        // 反复访问少数热点键，它们都停留在根附近
+        for _ in 0..3 {
+            for key in [10, 20, 30] {
+                splay.get(&key);
+            }
+        }
+        assert_eq!(splay.root_key(), Some(&30));
+        assert!(splay.check().is_ok());
+    }
+}
@@ -0,0 +1,421 @@
+//! # 中序线索二叉树模块
+//!
+//! 普通二叉树中有 n + 1 个空指针。中序线索二叉树把空的左指针改为指向中序前驱、
+//! 空的右指针改为指向中序后继的“线索”，于是不用递归也不用栈就能找到任一节点的前驱和后继，
+//! 中序遍历只需 O(1) 的额外空间。
+//!
+//! 节点存放在数组中，用下标（[`NodeId`]）互相引用；每个指针要么是子节点，要么是线索。
+//!
+//! ## 示例
+//! ```
+//! use data_structure::non_linear::tree::BinaryTree;
+//! use data_structure::non_linear::tree::threaded::ThreadedBinaryTree;
+//!
+//! let tree = BinaryTree::from_level_order([Some(2), Some(1), Some(4)]).unwrap();
+//! let mut threaded = ThreadedBinaryTree::from(tree);
+//! let four = threaded.last().unwrap();
+//! threaded.insert_left(four, 3);
+//! assert_eq!(threaded.iter().collect::<Vec<_>>(), vec![&1, &2, &3, &4]);
+//! assert_eq!(threaded.iter().rev().collect::<Vec<_>>(), vec![&4, &3, &2, &1]);
+//! ```
+
+use std::collections::VecDeque;
+
+use super::{BinaryNode, BinaryTree};
+
+/// 节点编号
+pub type NodeId = usize;
+
+/// 节点的左/右指针
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Link {
+    /// 指向子节点
+    Child(NodeId),
+    /// 指向中序前驱（左指针）或后继（右指针），中序的第一个/最后一个节点为None
+    Thread(Option<NodeId>),
+}
+
+#[derive(Clone, Debug)]
+struct Node<T> {
+    data: T,
+    left: Link,
+    right: Link,
+}
+
+/// 中序线索二叉树
+#[derive(Clone, Debug)]
+pub struct ThreadedBinaryTree<T> {
+    nodes: Vec<Node<T>>,
+    root: Option<NodeId>,
+}
+
+impl<T> ThreadedBinaryTree<T> {
+    /// 创建空的线索二叉树
+    pub fn new() -> Self {
+        Self {
+            nodes: Vec::new(),
+            root: None,
+        }
+    }
+
+    /// 节点个数
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// 检查是否为空树
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// 根节点编号
+    pub fn root(&self) -> Option<NodeId> {
+        self.root
+    }
+
+    /// 获取节点数据的不可变引用，编号无效时返回None
+    pub fn get(&self, id: NodeId) -> Option<&T> {
+        Some(&self.nodes.get(id)?.data)
+    }
+
+    /// 获取节点数据的可变引用，编号无效时返回None
+    pub fn get_mut(&mut self, id: NodeId) -> Option<&mut T> {
+        Some(&mut self.nodes.get_mut(id)?.data)
+    }
+
+    /// 左子节点（不含线索）
+    pub fn left_child(&self, id: NodeId) -> Option<NodeId> {
+        match self.nodes[id].left {
+            Link::Child(child) => Some(child),
+            Link::Thread(_) => None,
+        }
+    }
+
+    /// 右子节点（不含线索）
+    pub fn right_child(&self, id: NodeId) -> Option<NodeId> {
+        match self.nodes[id].right {
+            Link::Child(child) => Some(child),
+            Link::Thread(_) => None,
+        }
+    }
+
+    /// 子树中序的第一个节点
+    fn leftmost(&self, mut id: NodeId) -> NodeId {
+        while let Link::Child(left) = self.nodes[id].left {
+            id = left;
+        }
+        id
+    }
+
+    /// 子树中序的最后一个节点
+    fn rightmost(&self, mut id: NodeId) -> NodeId {
+        while let Link::Child(right) = self.nodes[id].right {
+            id = right;
+        }
+        id
+    }
+
+    /// 中序的第一个节点
+    pub fn first(&self) -> Option<NodeId> {
+        Some(self.leftmost(self.root?))
+    }
+
+    /// 中序的最后一个节点
+    pub fn last(&self) -> Option<NodeId> {
+        Some(self.rightmost(self.root?))
+    }
+
+    /// 中序后继
+    ///
+    /// 右指针是线索时直接得到后继，否则后继是右子树的最左节点。
+    ///
+    /// # 参数
+    /// * `id` - 节点编号
+    ///
+    /// # 返回值
+    /// 后继的编号，`id`是中序最后一个节点时返回None
+    pub fn successor(&self, id: NodeId) -> Option<NodeId> {
+        match self.nodes[id].right {
+            Link::Thread(next) => next,
+            Link::Child(right) => Some(self.leftmost(right)),
+        }
+    }
+
+    /// 中序前驱
+    ///
+    /// # 参数
+    /// * `id` - 节点编号
+    ///
+    /// # 返回值
+    /// 前驱的编号，`id`是中序第一个节点时返回None
+    pub fn predecessor(&self, id: NodeId) -> Option<NodeId> {
+        match self.nodes[id].left {
+            Link::Thread(prev) => prev,
+            Link::Child(left) => Some(self.rightmost(left)),
+        }
+    }
+
+    /// 沿线索做中序遍历的迭代器，不使用栈，可以反向遍历
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            tree: self,
+            front: self.first(),
+            back: self.last(),
+            remaining: self.len(),
+        }
+    }
+
+    fn push(&mut self, data: T, left: Link, right: Link) -> NodeId {
+        self.nodes.push(Node { data, left, right });
+        self.nodes.len() - 1
+    }
+
+    /// 插入新根，原来的树成为新根的左子树
+    ///
+    /// # 参数
+    /// * `data` - 新根的数据
+    ///
+    /// # 返回值
+    /// 新根的编号
+    pub fn insert_root(&mut self, data: T) -> NodeId {
+        let last = self.last();
+        let left = self.root.map_or(Link::Thread(None), Link::Child);
+        let id = self.push(data, left, Link::Thread(None));
+        // 新根排在中序最后，原来的最后一个节点的后继线索改指向它
+        if let Some(last) = last {
+            self.nodes[last].right = Link::Thread(Some(id));
+        }
+        self.root = Some(id);
+        id
+    }
+
+    /// 插入新节点作为`parent`的左子节点，原来的左子树成为新节点的左子树
+    ///
+    /// # 参数
+    /// * `parent` - 父节点编号
+    /// * `data` - 新节点的数据
+    ///
+    /// # 返回值
+    /// 新节点的编号
+    ///
+    /// # Panics
+    /// `parent`不是有效编号时panic
+    pub fn insert_left(&mut self, parent: NodeId, data: T) -> NodeId {
+        let left = self.nodes[parent].left;
+        let id = self.push(data, left, Link::Thread(Some(parent)));
+        self.nodes[parent].left = Link::Child(id);
+        // 新节点在中序中紧挨在 parent 之前，原左子树的最右节点的后继变为新节点
+        if let Link::Child(left) = left {
+            let last = self.rightmost(left);
+            self.nodes[last].right = Link::Thread(Some(id));
+        }
+        id
+    }
+
+    /// 插入新节点作为`parent`的右子节点，原来的右子树成为新节点的右子树
+    ///
+    /// # 参数
+    /// * `parent` - 父节点编号
+    /// * `data` - 新节点的数据
+    ///
+    /// # 返回值
+    /// 新节点的编号
+    ///
+    /// # Panics
+    /// `parent`不是有效编号时panic
+    pub fn insert_right(&mut self, parent: NodeId, data: T) -> NodeId {
+        let right = self.nodes[parent].right;
+        let id = self.push(data, Link::Thread(Some(parent)), right);
+        self.nodes[parent].right = Link::Child(id);
+        // 新节点在中序中紧挨在 parent 之后，原右子树的最左节点的前驱变为新节点
+        if let Link::Child(right) = right {
+            let first = self.leftmost(right);
+            self.nodes[first].left = Link::Thread(Some(id));
+        }
+        id
+    }
+}
+
+impl<T> Default for ThreadedBinaryTree<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 待编号的子树，以及它的父节点编号和是否为左子节点
+type Pending<T> = (Box<BinaryNode<T>>, Option<(NodeId, bool)>);
+
+/// 由二叉树建立线索：节点按层序编号，再按中序把空指针连成线索
+impl<T> From<BinaryTree<T>> for ThreadedBinaryTree<T> {
+    fn from(tree: BinaryTree<T>) -> Self {
+        let mut nodes: Vec<Node<T>> = Vec::new();
+        let mut queue: VecDeque<Pending<T>> = tree.0.map(|root| (root, None)).into_iter().collect();
+        while let Some((node, parent)) = queue.pop_front() {
+            let BinaryNode { data, left, right } = *node;
+            let id = nodes.len();
+            nodes.push(Node {
+                data,
+                left: Link::Thread(None),
+                right: Link::Thread(None),
+            });
+            match parent {
+                Some((parent, true)) => nodes[parent].left = Link::Child(id),
+                Some((parent, false)) => nodes[parent].right = Link::Child(id),
+                None => {}
+            }
+            queue.extend(left.map(|left| (left, Some((id, true)))));
+            queue.extend(right.map(|right| (right, Some((id, false)))));
+        }
+
+        // 用显式栈求中序序列，再给空指针填上前驱/后继线索
+        let mut order = Vec::with_capacity(nodes.len());
+        let mut stack = Vec::new();
+        let mut current = (!nodes.is_empty()).then_some(0);
+        while current.is_some() || !stack.is_empty() {
+            while let Some(id) = current {
+                stack.push(id);
+                current = match nodes[id].left {
+                    Link::Child(left) => Some(left),
+                    Link::Thread(_) => None,
+                };
+            }
+            let id = stack.pop().unwrap();
+            order.push(id);
+            current = match nodes[id].right {
+                Link::Child(right) => Some(right),
+                Link::Thread(_) => None,
+            };
+        }
+        for (i, &id) in order.iter().enumerate() {
+            let node = &mut nodes[id];
+            if let Link::Thread(_) = node.left {
+                node.left = Link::Thread(i.checked_sub(1).map(|prev| order[prev]));
+            }
+            if let Link::Thread(_) = node.right {
+                node.right = Link::Thread(order.get(i + 1).copied());
+            }
+        }
+        let root = (!nodes.is_empty()).then_some(0);
+        Self { nodes, root }
+    }
+}
+
+/// 线索二叉树的中序迭代器
+pub struct Iter<'a, T> {
+    tree: &'a ThreadedBinaryTree<T>,
+    front: Option<NodeId>,
+    back: Option<NodeId>,
+    remaining: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let id = self.front?;
+        self.front = self.tree.successor(id);
+        self.remaining -= 1;
+        Some(&self.tree.nodes[id].data)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let id = self.back?;
+        self.back = self.tree.predecessor(id);
+        self.remaining -= 1;
+        Some(&self.tree.nodes[id].data)
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<'a, T> IntoIterator for &'a ThreadedBinaryTree<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 检查每条线索都指向中序的前驱/后继
+    fn check<T>(tree: &ThreadedBinaryTree<T>) {
+        let mut order = Vec::new();
+        let mut current = tree.first();
+        while let Some(id) = current {
+            order.push(id);
+            current = tree.successor(id);
+        }
+        assert_eq!(order.len(), tree.len());
+        for (i, &id) in order.iter().enumerate() {
+            assert_eq!(tree.predecessor(id), i.checked_sub(1).map(|p| order[p]));
+            if let Link::Thread(next) = tree.nodes[id].right {
+                assert_eq!(next, order.get(i + 1).copied());
+            }
+        }
+    }
+
+    #[test]
+    fn test_threaded_binary_tree() {
+        //       1
+        //      / \
+        //     2   3
+        //    / \   \
+        //   4   5   6
+        //      /
+        //     7
+        let tree = BinaryTree::from_level_order(
+            [1, 2, 3, 4, 5, 0, 6, 0, 0, 7]
+                .into_iter()
+                .map(|x| (x != 0).then_some(x)),
+        )
+        .unwrap();
+        let expected: Vec<i32> = tree.in_order_traverse().into_iter().copied().collect();
+        let mut threaded = ThreadedBinaryTree::from(tree);
+        check(&threaded);
+        assert_eq!(threaded.iter().copied().collect::<Vec<_>>(), expected);
+        assert_eq!(threaded.iter().rev().count(), 7);
+        let mut iter = threaded.iter();
+        assert_eq!((iter.next(), iter.next_back()), (Some(&4), Some(&6)));
+        assert_eq!(iter.len(), 5);
+
+        // 在有左子树的节点 1 与叶子节点 7 处插入
+        let root = threaded.root().unwrap();
+        threaded.insert_left(root, 10);
+        let two = threaded.successor(threaded.first().unwrap()).unwrap();
+        assert_eq!(threaded.get(two), Some(&2));
+        let seven = threaded
+            .left_child(threaded.right_child(two).unwrap())
+            .unwrap();
+        threaded.insert_right(seven, 11);
+        threaded.insert_root(12);
+        check(&threaded);
+        assert_eq!(
+            threaded.iter().copied().collect::<Vec<_>>(),
+            vec![4, 2, 7, 11, 5, 10, 1, 3, 6, 12]
+        );
+
+        let mut empty = ThreadedBinaryTree::from(BinaryTree::new());
+        assert_eq!(empty.iter().next(), None);
+        let a = empty.insert_root('a');
+        empty.insert_right(a, 'b');
+        empty.insert_left(a, 'c');
+        check(&empty);
+        assert_eq!(empty.iter().collect::<String>(), "cab");
+    }
+}
@@ -0,0 +1,782 @@
+//! # 树堆模块
+//!
+//! 树堆（Treap）给每个节点一个随机优先级，按键满足二叉搜索树的性质，
+//! 按优先级满足大根堆的性质。优先级随机时树的形状与随机插入得到的二叉搜索树相同，
+//! 期望高度为 O(log n)。
+//!
+//! 所有操作都建立在两个基本操作上：
+//! - 分裂（split）：把一棵树拆成键小于给定键和不小于给定键的两棵树；
+//! - 合并（merge）：把所有键都小于右树的左树与右树合成一棵树。
+//!
+//! 本模块提供两种树堆：
+//! - [`Treap`]：按键排序的有序映射，支持 [`split_off`](Treap::split_off) 和 [`merge`](Treap::merge)；
+//! - [`ImplicitTreap`]：隐式键树堆，以元素在序列中的位置作为键（由子树大小算出，不显式存储），
+//!   可以在 O(log n) 内完成任意位置的插入、删除、切分、拼接和区间翻转，适合序列编辑。
+//!
+//! 优先级由带种子的伪随机数生成器产生，相同种子和相同操作序列得到形状相同的树。
+//!
+//! ## 示例
+//! ```
+//! use data_structure::non_linear::tree::treap::{ImplicitTreap, Treap};
+//!
+//! let mut treap: Treap<i32, char> = (0..10).zip('a'..).collect();
+//! let mut upper = treap.split_off(&6);
+//! assert_eq!(treap.len(), 6);
+//! assert_eq!(upper.min(), Some((&6, &'g')));
+//! treap.merge(&mut upper).unwrap();
+//! assert_eq!(treap.len(), 10);
+//! assert!(upper.is_empty());
+//!
+//! let mut text: ImplicitTreap<char> = "hello world".chars().collect();
+//! text.reverse(0..5);
+//! text.insert(5, '!');
+//! let edited: String = text.iter().collect();
+//! assert_eq!(edited, "olleh! world");
+//! ```
+
+use std::cmp::Ordering;
+use std::mem;
+use std::ops::RangeBounds;
+
+use super::BinaryNode;
+use super::search::{self, KeyValue};
+use crate::non_linear::segment_tree::to_range;
+
+/// 未指定种子时使用的默认种子
+const DEFAULT_SEED: u64 = 0x2545_F491_4F6C_DD1D;
+
+/// SplitMix64 伪随机数生成器的下一个数
+fn next_priority(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// 有序树堆节点中保存的数据
+#[derive(Clone, Debug)]
+struct Entry<K, V> {
+    key: K,
+    value: V,
+    priority: u64,
+    /// 以该节点为根的子树中的节点数
+    size: usize,
+}
+
+impl<K, V> KeyValue for Entry<K, V> {
+    type Key = K;
+    type Value = V;
+
+    fn key(&self) -> &K {
+        &self.key
+    }
+
+    fn value(&self) -> &V {
+        &self.value
+    }
+
+    fn value_mut(&mut self) -> &mut V {
+        &mut self.value
+    }
+}
+
+type Node<K, V> = BinaryNode<Entry<K, V>>;
+type Link<K, V> = Option<Box<Node<K, V>>>;
+
+fn size<K, V>(link: &Link<K, V>) -> usize {
+    link.as_ref().map_or(0, |node| node.data.size)
+}
+
+fn update<K, V>(node: &mut Node<K, V>) {
+    node.data.size = size(&node.left) + size(&node.right) + 1;
+}
+
+/// 分裂成键小于`key`和键不小于`key`的两棵树
+fn split<K: Ord, V>(link: Link<K, V>, key: &K) -> (Link<K, V>, Link<K, V>) {
+    let Some(mut node) = link else {
+        return (None, None);
+    };
+    if node.data.key < *key {
+        let (left, right) = split(node.right.take(), key);
+        node.right = left;
+        update(&mut node);
+        (Some(node), right)
+    } else {
+        let (left, right) = split(node.left.take(), key);
+        node.left = right;
+        update(&mut node);
+        (left, Some(node))
+    }
+}
+
+/// 合并两棵树，`left`中的键都小于`right`中的键
+fn merge<K, V>(left: Link<K, V>, right: Link<K, V>) -> Link<K, V> {
+    match (left, right) {
+        (None, link) | (link, None) => link,
+        (Some(mut left), Some(mut right)) => {
+            if left.data.priority > right.data.priority {
+                left.right = merge(left.right.take(), Some(right));
+                update(&mut left);
+                Some(left)
+            } else {
+                right.left = merge(Some(left), right.left.take());
+                update(&mut right);
+                Some(right)
+            }
+        }
+    }
+}
+
+/// 按键排序的树堆
+///
+/// 提供与有序映射相同的接口，不允许重复的键。
+#[derive(Clone, Debug)]
+pub struct Treap<K, V> {
+    root: Link<K, V>,
+    state: u64,
+}
+
+impl<K, V> Treap<K, V>
+where
+    K: Ord,
+{
+    /// 创建空树堆，使用默认种子
+    pub fn new() -> Self {
+        Self::with_seed(DEFAULT_SEED)
+    }
+
+    /// 创建空树堆，并指定生成优先级的随机种子
+    pub fn with_seed(seed: u64) -> Self {
+        Self {
+            root: None,
+            state: seed,
+        }
+    }
+
+    /// 获取键值对的数量
+    pub fn len(&self) -> usize {
+        size(&self.root)
+    }
+
+    /// 检查是否为空
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    /// 树的高度，空树为 0
+    pub fn height(&self) -> usize {
+        fn height<K, V>(link: &Link<K, V>) -> usize {
+            link.as_ref()
+                .map_or(0, |node| height(&node.left).max(height(&node.right)) + 1)
+        }
+        height(&self.root)
+    }
+
+    /// 插入键值对
+    ///
+    /// # 返回值
+    /// 键已存在时替换旧值并返回 `Some(旧值)`，否则返回 `None`
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        if let Some(old) = self.get_mut(&key) {
+            return Some(mem::replace(old, value));
+        }
+        let node = Box::new(BinaryNode {
+            data: Entry {
+                key,
+                value,
+                priority: next_priority(&mut self.state),
+                size: 1,
+            },
+            left: None,
+            right: None,
+        });
+        self.root = Some(Self::insert_at(self.root.take(), node));
+        None
+    }
+
+    /// 沿查找路径下降，直到新节点的优先级高于当前节点，再把当前子树分裂后挂在新节点两侧
+    fn insert_at(link: Link<K, V>, mut node: Box<Node<K, V>>) -> Box<Node<K, V>> {
+        let Some(mut current) = link else {
+            return node;
+        };
+        if node.data.priority > current.data.priority {
+            let (left, right) = split(Some(current), &node.data.key);
+            node.left = left;
+            node.right = right;
+            update(&mut node);
+            return node;
+        }
+        if node.data.key < current.data.key {
+            current.left = Some(Self::insert_at(current.left.take(), node));
+        } else {
+            current.right = Some(Self::insert_at(current.right.take(), node));
+        }
+        update(&mut current);
+        current
+    }
+
+    /// 查找键对应的值
+    pub fn get(&self, key: &K) -> Option<&V> {
+        search::get(&self.root, key)
+    }
+
+    /// 查找键对应的值的可变引用
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        search::get_mut(&mut self.root, key)
+    }
+
+    /// 检查是否包含指定的键
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// 删除键值对，被删节点的左右子树合并后接替它的位置
+    ///
+    /// # 返回值
+    /// 键存在时返回 `Some(值)`，否则返回 `None`
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let (root, removed) = Self::remove_at(self.root.take(), key);
+        self.root = root;
+        removed
+    }
+
+    fn remove_at(link: Link<K, V>, key: &K) -> (Link<K, V>, Option<V>) {
+        let Some(mut node) = link else {
+            return (None, None);
+        };
+        let removed = match key.cmp(&node.data.key) {
+            Ordering::Less => {
+                let (left, removed) = Self::remove_at(node.left.take(), key);
+                node.left = left;
+                removed
+            }
+            Ordering::Greater => {
+                let (right, removed) = Self::remove_at(node.right.take(), key);
+                node.right = right;
+                removed
+            }
+            Ordering::Equal => {
+                let BinaryNode { data, left, right } = *node;
+                return (merge(left, right), Some(data.value));
+            }
+        };
+        update(&mut node);
+        (Some(node), removed)
+    }
+
+    /// 把键不小于`key`的部分分裂出来
+    ///
+    /// # 返回值
+    /// 包含所有键不小于`key`的键值对的新树堆，原树堆只保留小于`key`的部分
+    pub fn split_off(&mut self, key: &K) -> Self {
+        let (left, right) = split(self.root.take(), key);
+        self.root = left;
+        Self {
+            root: right,
+            state: next_priority(&mut self.state),
+        }
+    }
+
+    /// 把另一棵树堆的所有键值对合并进来，`other`变为空
+    ///
+    /// # 参数
+    /// * `other` - 所有键都大于本树堆中的键的树堆
+    ///
+    /// # 返回值
+    /// 键的范围有重叠时返回错误，两棵树堆都保持不变
+    pub fn merge(&mut self, other: &mut Self) -> Result<(), &'static str> {
+        if let (Some((max, _)), Some((min, _))) = (self.max(), other.min())
+            && max >= min
+        {
+            return Err("右侧树堆的键必须都大于左侧树堆");
+        }
+        self.root = merge(self.root.take(), other.root.take());
+        Ok(())
+    }
+
+    /// 最小的键值对
+    pub fn min(&self) -> Option<(&K, &V)> {
+        search::min(&self.root)
+    }
+
+    /// 最大的键值对
+    pub fn max(&self) -> Option<(&K, &V)> {
+        search::max(&self.root)
+    }
+
+    /// 按键从小到大遍历所有键值对
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter(search::Range::all(&self.root))
+    }
+
+    /// 检查树堆的不变量
+    ///
+    /// 依次检查：键严格递增、父节点优先级不低于子节点、记录的子树大小正确。
+    ///
+    /// # 返回值
+    /// 全部满足时返回 `Ok(())`，否则返回描述第一个被破坏的不变量的错误
+    pub fn check(&self) -> Result<(), &'static str> {
+        let mut previous: Option<&K> = None;
+        for (key, _) in self.iter() {
+            if previous.is_some_and(|previous| previous >= key) {
+                return Err("键不是严格递增的");
+            }
+            previous = Some(key);
+        }
+        check_node(&self.root, |entry| (entry.priority, entry.size))
+    }
+}
+
+/// 递归检查堆序与子树大小
+fn check_node<D>(
+    link: &Option<Box<BinaryNode<D>>>,
+    meta: fn(&D) -> (u64, usize),
+) -> Result<(), &'static str> {
+    let Some(node) = link else {
+        return Ok(());
+    };
+    let (priority, size) = meta(&node.data);
+    let mut expected = 1;
+    for child in [&node.left, &node.right].into_iter().flatten() {
+        let (child_priority, child_size) = meta(&child.data);
+        if child_priority > priority {
+            return Err("子节点的优先级高于父节点");
+        }
+        expected += child_size;
+    }
+    if size != expected {
+        return Err("节点记录的子树大小不正确");
+    }
+    check_node(&node.left, meta)?;
+    check_node(&node.right, meta)
+}
+
+impl<K: Ord, V> Default for Treap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Ord, V> FromIterator<(K, V)> for Treap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut treap = Treap::new();
+        for (key, value) in iter {
+            treap.insert(key, value);
+        }
+        treap
+    }
+}
+
+/// 按键的顺序惰性遍历树堆的迭代器
+pub struct Iter<'a, K, V>(search::Range<'a, Entry<K, V>>);
+
+impl<'a, K: Ord, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+}
+
+impl<'a, K: Ord, V> IntoIterator for &'a Treap<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// 隐式键树堆节点中保存的数据
+#[derive(Clone, Debug)]
+struct Item<T> {
+    value: T,
+    priority: u64,
+    size: usize,
+    /// 懒标记：以该节点为根的子树需要翻转，但左右子节点尚未交换
+    reversed: bool,
+}
+
+type SeqNode<T> = BinaryNode<Item<T>>;
+type SeqLink<T> = Option<Box<SeqNode<T>>>;
+
+fn seq_size<T>(link: &SeqLink<T>) -> usize {
+    link.as_ref().map_or(0, |node| node.data.size)
+}
+
+fn seq_update<T>(node: &mut SeqNode<T>) {
+    node.data.size = seq_size(&node.left) + seq_size(&node.right) + 1;
+}
+
+/// 下传翻转标记：交换左右子节点，并把标记转交给子节点
+fn push_down<T>(node: &mut SeqNode<T>) {
+    if mem::take(&mut node.data.reversed) {
+        mem::swap(&mut node.left, &mut node.right);
+        for child in [&mut node.left, &mut node.right].into_iter().flatten() {
+            child.data.reversed ^= true;
+        }
+    }
+}
+
+/// 分裂成前`k`个元素和其余元素两棵树
+fn split_at<T>(link: SeqLink<T>, k: usize) -> (SeqLink<T>, SeqLink<T>) {
+    let Some(mut node) = link else {
+        return (None, None);
+    };
+    push_down(&mut node);
+    let left_size = seq_size(&node.left);
+    if k <= left_size {
+        let (left, right) = split_at(node.left.take(), k);
+        node.left = right;
+        seq_update(&mut node);
+        (left, Some(node))
+    } else {
+        let (left, right) = split_at(node.right.take(), k - left_size - 1);
+        node.right = left;
+        seq_update(&mut node);
+        (Some(node), right)
+    }
+}
+
+/// 把两个序列首尾相接
+fn concat<T>(left: SeqLink<T>, right: SeqLink<T>) -> SeqLink<T> {
+    match (left, right) {
+        (None, link) | (link, None) => link,
+        (Some(mut left), Some(mut right)) => {
+            if left.data.priority > right.data.priority {
+                push_down(&mut left);
+                left.right = concat(left.right.take(), Some(right));
+                seq_update(&mut left);
+                Some(left)
+            } else {
+                push_down(&mut right);
+                right.left = concat(Some(left), right.left.take());
+                seq_update(&mut right);
+                Some(right)
+            }
+        }
+    }
+}
+
+/// 隐式键树堆
+///
+/// 以下标访问的序列容器，元素的下标就是它在中序遍历中的位置。
+#[derive(Clone, Debug)]
+pub struct ImplicitTreap<T> {
+    root: SeqLink<T>,
+    state: u64,
+}
+
+impl<T> ImplicitTreap<T> {
+    /// 创建空序列，使用默认种子
+    pub fn new() -> Self {
+        Self::with_seed(DEFAULT_SEED)
+    }
+
+    /// 创建空序列，并指定生成优先级的随机种子
+    pub fn with_seed(seed: u64) -> Self {
+        Self {
+            root: None,
+            state: seed,
+        }
+    }
+
+    /// 元素个数
+    pub fn len(&self) -> usize {
+        seq_size(&self.root)
+    }
+
+    /// 检查是否为空
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    fn leaf(&mut self, value: T) -> SeqLink<T> {
+        Some(Box::new(BinaryNode {
+            data: Item {
+                value,
+                priority: next_priority(&mut self.state),
+                size: 1,
+                reversed: false,
+            },
+            left: None,
+            right: None,
+        }))
+    }
+
+    /// 获取第`index`个元素
+    pub fn get(&self, index: usize) -> Option<&T> {
+        let mut index = index;
+        let mut node = self.root.as_deref().filter(|_| index < self.len())?;
+        // 祖先上未下传的翻转标记的累积效果
+        let mut flipped = false;
+        loop {
+            flipped ^= node.data.reversed;
+            let (left, right) = if flipped {
+                (&node.right, &node.left)
+            } else {
+                (&node.left, &node.right)
+            };
+            let left_size = seq_size(left);
+            node = match index.cmp(&left_size) {
+                Ordering::Less => left.as_deref()?,
+                Ordering::Equal => return Some(&node.data.value),
+                Ordering::Greater => {
+                    index -= left_size + 1;
+                    right.as_deref()?
+                }
+            };
+        }
+    }
+
+    /// 获取第`index`个元素的可变引用
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        let mut index = index;
+        let len = self.len();
+        let mut node = self.root.as_deref_mut().filter(|_| index < len)?;
+        loop {
+            push_down(node);
+            let left_size = seq_size(&node.left);
+            node = match index.cmp(&left_size) {
+                Ordering::Less => node.left.as_deref_mut()?,
+                Ordering::Equal => return Some(&mut node.data.value),
+                Ordering::Greater => {
+                    index -= left_size + 1;
+                    node.right.as_deref_mut()?
+                }
+            };
+        }
+    }
+
+    /// 在第`index`个位置插入元素，其后的元素依次后移
+    ///
+    /// # Panics
+    /// `index`大于长度时panic
+    pub fn insert(&mut self, index: usize, value: T) {
+        assert!(index <= self.len(), "下标 {index} 超出长度 {}", self.len());
+        let node = self.leaf(value);
+        let (left, right) = split_at(self.root.take(), index);
+        self.root = concat(concat(left, node), right);
+    }
+
+    /// 在末尾追加元素
+    pub fn push(&mut self, value: T) {
+        let node = self.leaf(value);
+        self.root = concat(self.root.take(), node);
+    }
+
+    /// 删除并返回第`index`个元素，其后的元素依次前移
+    ///
+    /// # Panics
+    /// `index`越界时panic
+    pub fn remove(&mut self, index: usize) -> T {
+        assert!(index < self.len(), "下标 {index} 超出长度 {}", self.len());
+        let (left, rest) = split_at(self.root.take(), index);
+        let (node, right) = split_at(rest, 1);
+        self.root = concat(left, right);
+        node.unwrap().data.value
+    }
+
+    /// 在`at`处切分，返回后半部分
+    ///
+    /// # Panics
+    /// `at`大于长度时panic
+    pub fn split_off(&mut self, at: usize) -> Self {
+        assert!(at <= self.len(), "下标 {at} 超出长度 {}", self.len());
+        let (left, right) = split_at(self.root.take(), at);
+        self.root = left;
+        Self {
+            root: right,
+            state: next_priority(&mut self.state),
+        }
+    }
+
+    /// 把`other`的所有元素移到末尾，`other`变为空
+    pub fn append(&mut self, other: &mut Self) {
+        self.root = concat(self.root.take(), other.root.take());
+    }
+
+    /// 翻转区间内的元素
+    ///
+    /// 只在切出的子树根上打懒标记，O(log n)。
+    ///
+    /// # Panics
+    /// 区间越界时panic
+    pub fn reverse(&mut self, range: impl RangeBounds<usize>) {
+        let (start, end) = to_range(range, self.len());
+        let (rest, right) = split_at(self.root.take(), end);
+        let (left, mut middle) = split_at(rest, start);
+        if let Some(node) = middle.as_mut() {
+            node.data.reversed ^= true;
+        }
+        self.root = concat(concat(left, middle), right);
+    }
+
+    /// 按下标顺序遍历所有元素
+    pub fn iter(&self) -> SeqIter<'_, T> {
+        let mut iter = SeqIter {
+            stack: Vec::new(),
+            remaining: self.len(),
+        };
+        iter.push_left(self.root.as_deref(), false);
+        iter
+    }
+
+    /// 检查隐式键树堆的不变量：父节点优先级不低于子节点、记录的子树大小正确
+    pub fn check(&self) -> Result<(), &'static str> {
+        check_node(&self.root, |item| (item.priority, item.size))
+    }
+}
+
+impl<T> Default for ImplicitTreap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> FromIterator<T> for ImplicitTreap<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut treap = ImplicitTreap::new();
+        for value in iter {
+            treap.push(value);
+        }
+        treap
+    }
+}
+
+/// 按下标顺序惰性遍历隐式键树堆的迭代器
+///
+/// 不下传翻转标记，而是在栈中记录每个节点累积的翻转状态。
+pub struct SeqIter<'a, T> {
+    stack: Vec<(&'a SeqNode<T>, bool)>,
+    remaining: usize,
+}
+
+impl<'a, T> SeqIter<'a, T> {
+    fn push_left(&mut self, mut current: Option<&'a SeqNode<T>>, mut flipped: bool) {
+        while let Some(node) = current {
+            flipped ^= node.data.reversed;
+            self.stack.push((node, flipped));
+            current = if flipped {
+                node.right.as_deref()
+            } else {
+                node.left.as_deref()
+            };
+        }
+    }
+}
+
+impl<'a, T> Iterator for SeqIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (node, flipped) = self.stack.pop()?;
+        let next = if flipped { &node.left } else { &node.right };
+        self.push_left(next.as_deref(), flipped);
+        self.remaining -= 1;
+        Some(&node.data.value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for SeqIter<'_, T> {}
+
+impl<'a, T> IntoIterator for &'a ImplicitTreap<T> {
+    type Item = &'a T;
+    type IntoIter = SeqIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_treap() {
+        let mut treap = Treap::with_seed(42);
+        for i in 0..1000 {
+            assert_eq!(treap.insert(i, i * 2), None);
+        }
+        assert!(treap.check().is_ok());
+        // 有序插入时期望高度仍是对数级
+        assert!(treap.height() <= 40);
+        assert_eq!(treap.insert(10, 0), Some(20));
+        *treap.get_mut(&10).unwrap() += 1;
+        assert_eq!(treap.get(&10), Some(&1));
+        for key in (0..1000).filter(|key| key % 4 != 0) {
+            assert!(treap.remove(&key).is_some());
+        }
+        assert_eq!(treap.remove(&1), None);
+        assert!(treap.check().is_ok());
+        assert_eq!(treap.len(), 250);
+
+        let mut upper = treap.split_off(&500);
+        assert_eq!(treap.len(), 125);
+        assert_eq!(treap.max(), Some((&496, &992)));
+        assert_eq!(upper.min(), Some((&500, &1000)));
+        assert!(treap.check().is_ok() && upper.check().is_ok());
+        let mut overlapping: Treap<i32, i32> = [(0, 0), (2000, 1)].into_iter().collect();
+        assert!(treap.merge(&mut overlapping).is_err());
+        assert_eq!(overlapping.len(), 2);
+        assert_eq!(overlapping.get(&2000), Some(&1));
+        assert_eq!(treap.len(), 125);
+        treap.merge(&mut upper).unwrap();
+        assert!(upper.is_empty());
+        assert!(treap.check().is_ok());
+        let keys: Vec<i32> = treap.iter().map(|(k, _)| *k).collect();
+        assert_eq!(keys, (0..1000).step_by(4).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_implicit_treap() {
+        let mut seed = 3;
+        let mut random = |bound: usize| (next_priority(&mut seed) % bound as u64) as usize;
+        let mut treap: ImplicitTreap<usize> = (0..50).collect();
+        let mut naive: Vec<usize> = (0..50).collect();
+        for step in 0..2000 {
+            match random(5) {
+                0 => {
+                    let index = random(naive.len() + 1);
+                    treap.insert(index, 100 + step);
+                    naive.insert(index, 100 + step);
+                }
+                1 if !naive.is_empty() => {
+                    let index = random(naive.len());
+                    assert_eq!(treap.remove(index), naive.remove(index));
+                }
+                2 => {
+                    let (a, b) = (random(naive.len() + 1), random(naive.len() + 1));
+                    treap.reverse(a.min(b)..a.max(b));
+                    naive[a.min(b)..a.max(b)].reverse();
+                }
+                3 => {
+                    // 把前缀移到末尾
+                    let at = random(naive.len() + 1);
+                    let mut tail = treap.split_off(at);
+                    tail.append(&mut treap);
+                    treap = tail;
+                    naive.rotate_left(at);
+                }
+                _ if !naive.is_empty() => {
+                    let index = random(naive.len());
+                    *treap.get_mut(index).unwrap() += 1;
+                    naive[index] += 1;
+                    assert_eq!(treap.get(index), Some(&naive[index]));
+                }
+                _ => {}
+            }
+        }
+        assert!(treap.check().is_ok());
+        assert_eq!(treap.len(), naive.len());
+        assert_eq!(treap.get(naive.len()), None);
+        assert!(treap.iter().eq(naive.iter()));
+        assert!((0..naive.len()).all(|i| treap.get(i) == Some(&naive[i])));
+    }
+}
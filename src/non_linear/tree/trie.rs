@@ -0,0 +1,277 @@
+//! # 字典树模块
+//!
+//! 字典树（Trie）把键看作符号序列，每条边对应一个符号，从根到某个节点的路径就是一个键的前缀。
+//! 共享前缀的键共享路径，因此前缀查询只需沿前缀走到对应节点，再枚举其子树。
+//!
+//! 符号类型 `S` 通常是 `char` 或 `u8`。键可以是任何能视为 `[S]` 的类型：
+//! 字节键可以直接用 `&str`，字符键可以用 `&[char]`、`Vec<char>` 或 [`String`](crate::linear::string::String)。
+//! 每个节点记录子树中键的个数，前缀计数为 O(前缀长度)。
+//!
+//! ## 示例
+//! ```
+//! use data_structure::linear::string::String;
+//! use data_structure::non_linear::tree::trie::Trie;
+//!
+//! let mut trie = Trie::new();
+//! for (i, word) in ["tea", "ten", "to", "inn"].iter().enumerate() {
+//!     let chars: Vec<char> = word.chars().collect();
+//!     trie.insert(String::new(&chars), i);
+//! }
+//! let prefix = ['t', 'e'];
+//! assert_eq!(trie.count_prefix(String::new(&prefix)), 2);
+//! let words: Vec<std::string::String> = trie
+//!     .keys_with_prefix(&['t'])
+//!     .into_iter()
+//!     .map(|(key, _)| key.into_iter().collect())
+//!     .collect();
+//! assert_eq!(words, ["tea", "ten", "to"]);
+//! ```
+
+use std::collections::BTreeMap;
+
+#[derive(Clone, Debug)]
+struct Node<S, V> {
+    value: Option<V>,
+    /// 子树中（含自身）键的个数
+    count: usize,
+    children: BTreeMap<S, Node<S, V>>,
+}
+
+impl<S, V> Node<S, V> {
+    fn new() -> Self {
+        Self {
+            value: None,
+            count: 0,
+            children: BTreeMap::new(),
+        }
+    }
+}
+
+/// 字典树映射
+#[derive(Clone, Debug)]
+pub struct Trie<S, V> {
+    root: Node<S, V>,
+}
+
+impl<S: Ord + Clone, V> Trie<S, V> {
+    /// 创建空字典树
+    pub fn new() -> Self {
+        Self { root: Node::new() }
+    }
+
+    /// 键的个数
+    pub fn len(&self) -> usize {
+        self.root.count
+    }
+
+    /// 检查是否为空
+    pub fn is_empty(&self) -> bool {
+        self.root.count == 0
+    }
+
+    /// 节点个数（含根节点）
+    pub fn node_count(&self) -> usize {
+        let mut count = 0;
+        let mut stack = vec![&self.root];
+        while let Some(node) = stack.pop() {
+            count += 1;
+            stack.extend(node.children.values());
+        }
+        count
+    }
+
+    fn find(&self, key: &[S]) -> Option<&Node<S, V>> {
+        key.iter()
+            .try_fold(&self.root, |node, symbol| node.children.get(symbol))
+    }
+
+    fn find_mut(&mut self, key: &[S]) -> Option<&mut Node<S, V>> {
+        key.iter()
+            .try_fold(&mut self.root, |node, symbol| node.children.get_mut(symbol))
+    }
+
+    /// 插入键值对
+    ///
+    /// # 参数
+    /// * `key` - 键
+    /// * `value` - 值
+    ///
+    /// # 返回值
+    /// 键已存在时返回旧值，否则返回None
+    pub fn insert(&mut self, key: impl AsRef<[S]>, value: V) -> Option<V> {
+        let key = key.as_ref();
+        if let Some(node) = self.find_mut(key)
+            && node.value.is_some()
+        {
+            return node.value.replace(value);
+        }
+        // 新键：路径上每个节点的计数都加一
+        let mut node = &mut self.root;
+        node.count += 1;
+        for symbol in key {
+            node = node
+                .children
+                .entry(symbol.clone())
+                .or_insert_with(Node::new);
+            node.count += 1;
+        }
+        node.value = Some(value);
+        None
+    }
+
+    /// 查找键对应的值
+    pub fn get(&self, key: impl AsRef<[S]>) -> Option<&V> {
+        self.find(key.as_ref())?.value.as_ref()
+    }
+
+    /// 查找键对应的值的可变引用
+    pub fn get_mut(&mut self, key: impl AsRef<[S]>) -> Option<&mut V> {
+        self.find_mut(key.as_ref())?.value.as_mut()
+    }
+
+    /// 检查键是否存在
+    pub fn contains_key(&self, key: impl AsRef<[S]>) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// 删除键
+    ///
+    /// 删除后不再包含任何键的分支会被剪掉。
+    ///
+    /// # 参数
+    /// * `key` - 要删除的键
+    ///
+    /// # 返回值
+    /// 键存在时返回对应的值，否则返回None
+    pub fn remove(&mut self, key: impl AsRef<[S]>) -> Option<V> {
+        let key = key.as_ref();
+        let value = self.find_mut(key)?.value.take()?;
+        let mut node = &mut self.root;
+        node.count -= 1;
+        for symbol in key {
+            // 子树中只剩这一个键时，整条分支都可以剪掉
+            if node.children[symbol].count == 1 {
+                node.children.remove(symbol);
+                break;
+            }
+            node = node.children.get_mut(symbol).unwrap();
+            node.count -= 1;
+        }
+        Some(value)
+    }
+
+    /// 以`prefix`为前缀的键的个数
+    pub fn count_prefix(&self, prefix: impl AsRef<[S]>) -> usize {
+        self.find(prefix.as_ref()).map_or(0, |node| node.count)
+    }
+
+    /// 按字典序列出以`prefix`为前缀的所有键值对
+    ///
+    /// # 参数
+    /// * `prefix` - 前缀，空前缀列出全部键
+    ///
+    /// # 返回值
+    /// `(键, 值)`组成的向量
+    pub fn keys_with_prefix(&self, prefix: impl AsRef<[S]>) -> Vec<(Vec<S>, &V)> {
+        let prefix = prefix.as_ref();
+        let mut result = Vec::new();
+        let Some(start) = self.find(prefix) else {
+            return result;
+        };
+        let mut stack = vec![(prefix.to_vec(), start)];
+        while let Some((key, node)) = stack.pop() {
+            // 子节点逆序入栈，出栈时按符号升序
+            for (symbol, child) in node.children.iter().rev() {
+                let mut key = key.clone();
+                key.push(symbol.clone());
+                stack.push((key, child));
+            }
+            if let Some(value) = &node.value {
+                result.push((key, value));
+            }
+        }
+        result
+    }
+
+    /// 最长前缀匹配：在所有是`query`前缀的键中找最长的一个
+    ///
+    /// # 参数
+    /// * `query` - 被匹配的序列
+    ///
+    /// # 返回值
+    /// 匹配的键的长度及其值，没有任何键是`query`的前缀时返回None
+    pub fn longest_prefix_of(&self, query: impl AsRef<[S]>) -> Option<(usize, &V)> {
+        let mut node = &self.root;
+        let mut best = node.value.as_ref().map(|value| (0, value));
+        for (i, symbol) in query.as_ref().iter().enumerate() {
+            match node.children.get(symbol) {
+                Some(child) => node = child,
+                None => break,
+            }
+            if let Some(value) = &node.value {
+                best = Some((i + 1, value));
+            }
+        }
+        best
+    }
+}
+
+impl<S: Ord + Clone, V> Default for Trie<S, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S: Ord + Clone, V, K: AsRef<[S]>> FromIterator<(K, V)> for Trie<S, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut trie = Trie::new();
+        for (key, value) in iter {
+            trie.insert(key, value);
+        }
+        trie
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trie() {
+        let words = ["she", "sells", "sea", "shells", "by", "the", "sea", "shore"];
+        let mut trie: Trie<u8, usize> = Trie::new();
+        for (i, word) in words.iter().enumerate() {
+            trie.insert(word, i);
+        }
+        assert_eq!(trie.len(), 7);
+        assert_eq!(trie.get("sea"), Some(&6));
+        assert_eq!(trie.get("se"), None);
+        assert_eq!(trie.count_prefix("sh"), 3);
+        assert_eq!(trie.count_prefix(""), 7);
+        let keys: Vec<Vec<u8>> = trie
+            .keys_with_prefix("s")
+            .into_iter()
+            .map(|(key, _)| key)
+            .collect();
+        assert_eq!(keys, [&b"sea"[..], b"sells", b"she", b"shells", b"shore"]);
+        assert_eq!(trie.longest_prefix_of("shellsort"), Some((6, &3)));
+        assert_eq!(trie.longest_prefix_of("sh"), None);
+
+        let nodes = trie.node_count();
+        assert_eq!(trie.remove("shells"), Some(3));
+        assert_eq!(trie.remove("shells"), None);
+        assert_eq!(trie.node_count(), nodes - 3);
+        assert_eq!(trie.remove("she"), Some(0));
+        assert_eq!(trie.count_prefix("sh"), 1);
+        assert!(trie.contains_key("shore"));
+        for word in words {
+            trie.remove(word);
+        }
+        assert!(trie.is_empty());
+        assert_eq!(trie.node_count(), 1);
+
+        // 空键存放在根节点
+        trie.insert("", 42);
+        assert_eq!(trie.longest_prefix_of("abc"), Some((0, &42)));
+    }
+}